@@ -215,6 +215,21 @@ fn mat500_from_fn(bench: &mut criterion::Criterion) {
     });
 }
 
+fn expensive_element(i: usize, j: usize) -> f64 {
+    (0..1000).fold(0.0, |acc, k| acc + ((i * j + k) as f64).sin())
+}
+
+fn mat200_from_fn_expensive(bench: &mut criterion::Criterion) {
+    bench.bench_function("mat200_from_fn_expensive", move |bh| {
+        bh.iter(|| DMatrix::from_fn(200, 200, expensive_element))
+    });
+
+    #[cfg(feature = "rayon")]
+    bench.bench_function("mat200_from_fn_par_expensive", move |bh| {
+        bh.iter(|| DMatrix::from_fn_par(200, 200, expensive_element))
+    });
+}
+
 criterion_group!(
     matrix,
     mat2_mul_m,
@@ -267,4 +282,5 @@ criterion_group!(
     mat_mul_mat,
     mat100_from_fn,
     mat500_from_fn,
+    mat200_from_fn_expensive,
 );
@@ -0,0 +1,44 @@
+use na::{Isometry3, Matrix4, Similarity3};
+use rand::Rng;
+use rand_isaac::IsaacRng;
+
+#[path = "../common/macros.rs"]
+mod macros;
+
+bench_unop!(isometry3_to_homogeneous, Isometry3<f32>, to_homogeneous);
+bench_unop!(similarity3_to_homogeneous, Similarity3<f32>, to_homogeneous);
+
+macro_rules! bench_fill_homogeneous(
+    ($name: ident, $t: ty) => {
+        fn $name(bh: &mut criterion::Criterion) {
+            const LEN: usize = 1 << 13;
+
+            use rand::SeedableRng;
+            let mut rng = IsaacRng::seed_from_u64(0);
+
+            let elems: Vec<$t> = (0usize .. LEN).map(|_| rng.gen::<$t>()).collect();
+            let mut out = Matrix4::zeros();
+            let mut i = 0;
+
+            bh.bench_function(stringify!($name), move |bh| bh.iter(|| {
+                i = (i + 1) & (LEN - 1);
+
+                unsafe {
+                    elems.get_unchecked(i).fill_homogeneous(&mut out);
+                }
+                std::hint::black_box(&out);
+            }));
+        }
+    }
+);
+
+bench_fill_homogeneous!(isometry3_fill_homogeneous, Isometry3<f32>);
+bench_fill_homogeneous!(similarity3_fill_homogeneous, Similarity3<f32>);
+
+criterion_group!(
+    isometry,
+    isometry3_to_homogeneous,
+    isometry3_fill_homogeneous,
+    similarity3_to_homogeneous,
+    similarity3_fill_homogeneous
+);
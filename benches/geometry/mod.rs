@@ -1,3 +1,5 @@
+pub use self::isometry::isometry;
 pub use self::quaternion::quaternion;
 
+mod isometry;
 mod quaternion;
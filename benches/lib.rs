@@ -24,6 +24,7 @@ fn reproductible_dmatrix(nrows: usize, ncols: usize) -> DMatrix<f64> {
 criterion_main!(
     core::matrix,
     core::vector,
+    geometry::isometry,
     geometry::quaternion,
     linalg::bidiagonal,
     linalg::cholesky,
@@ -507,6 +507,21 @@ pub fn sign<T: Number, const D: usize>(x: &TVec<T, D>) -> TVec<T, D> {
 ///
 /// This is useful in cases where you would want a threshold function with a smooth transition.
 /// This is equivalent to: `let result = clamp((x - edge0) / (edge1 - edge0), 0, 1); return t * t * (3 - 2 * t);` Results are undefined if `edge0 >= edge1`.
+///
+/// # Examples:
+///
+/// ```
+/// # use nalgebra_glm as glm;
+/// assert_eq!(glm::smoothstep(1.0, 3.0, 0.0), 0.0);
+/// assert_eq!(glm::smoothstep(1.0, 3.0, 2.0), 0.5);
+/// assert_eq!(glm::smoothstep(1.0, 3.0, 1.5), 0.15625);
+/// assert_eq!(glm::smoothstep(1.0, 3.0, 4.0), 1.0);
+/// ```
+///
+/// # See also:
+///
+/// * [`smoothstep_vec`](fn.smoothstep_vec.html)
+/// * [`step_scalar`](fn.step_scalar.html)
 pub fn smoothstep<T: RealNumber>(edge0: T, edge1: T, x: T) -> T {
     let _3 = T::from_subset(&3.0f64);
     let _2 = T::from_subset(&2.0f64);
@@ -514,7 +529,43 @@ pub fn smoothstep<T: RealNumber>(edge0: T, edge1: T, x: T) -> T {
     t * t * (_3 - t * _2)
 }
 
+/// Component-wise smooth Hermite interpolation of `x` between the edges `edge0` and `edge1`.
+///
+/// Each component of the result is `smoothstep(edge0[i], edge1[i], x[i])`, i.e., `0.0` if
+/// `x[i] <= edge0[i]`, `1.0` if `x[i] >= edge1[i]`, and a smooth Hermite interpolation between
+/// 0 and 1 otherwise. Results are undefined for components where `edge0[i] >= edge1[i]`.
+///
+/// For edges shared by all the components, use `TVec::repeat` to build `edge0` and `edge1`.
+///
+/// # Examples:
+///
+/// ```
+/// # use nalgebra_glm as glm;
+/// let edge0 = glm::vec3(0.0, 0.0, 1.0);
+/// let edge1 = glm::vec3(1.0, 4.0, 2.0);
+/// let x = glm::vec3(0.5, 1.0, 0.0);
+/// assert_eq!(glm::smoothstep_vec(&edge0, &edge1, &x), glm::vec3(0.5, 0.15625, 0.0));
+/// ```
+///
+/// # See also:
+///
+/// * [`smoothstep`](fn.smoothstep.html)
+/// * [`step_vec`](fn.step_vec.html)
+pub fn smoothstep_vec<T: RealNumber, const D: usize>(
+    edge0: &TVec<T, D>,
+    edge1: &TVec<T, D>,
+    x: &TVec<T, D>,
+) -> TVec<T, D> {
+    TVec::from_fn(|i, _| smoothstep(edge0[i], edge1[i], x[i]))
+}
+
 /// Returns 0.0 if `x < edge`, otherwise it returns 1.0.
+///
+/// # See also:
+///
+/// * [`step`](fn.step.html)
+/// * [`step_vec`](fn.step_vec.html)
+/// * [`smoothstep`](fn.smoothstep.html)
 pub fn step_scalar<T: Number>(edge: T, x: T) -> T {
     if edge > x {
         T::zero()
@@ -524,11 +575,39 @@ pub fn step_scalar<T: Number>(edge: T, x: T) -> T {
 }
 
 /// Returns 0.0 if `x[i] < edge`, otherwise it returns 1.0.
+///
+/// # Examples:
+///
+/// ```
+/// # use nalgebra_glm as glm;
+/// let x = glm::vec3(-1.0, 0.5, 2.0);
+/// assert_eq!(glm::step(0.5, &x), glm::vec3(0.0, 1.0, 1.0));
+/// ```
+///
+/// # See also:
+///
+/// * [`step_scalar`](fn.step_scalar.html)
+/// * [`step_vec`](fn.step_vec.html)
 pub fn step<T: Number, const D: usize>(edge: T, x: &TVec<T, D>) -> TVec<T, D> {
     x.map(|x| step_scalar(edge, x))
 }
 
 /// Returns 0.0 if `x[i] < edge[i]`, otherwise it returns 1.0.
+///
+/// # Examples:
+///
+/// ```
+/// # use nalgebra_glm as glm;
+/// let edge = glm::vec3(0.0, 1.0, 2.0);
+/// let x = glm::vec3(-1.0, 1.0, 3.0);
+/// assert_eq!(glm::step_vec(&edge, &x), glm::vec3(0.0, 1.0, 1.0));
+/// ```
+///
+/// # See also:
+///
+/// * [`step_scalar`](fn.step_scalar.html)
+/// * [`step`](fn.step.html)
+/// * [`smoothstep_vec`](fn.smoothstep_vec.html)
 pub fn step_vec<T: Number, const D: usize>(edge: &TVec<T, D>, x: &TVec<T, D>) -> TVec<T, D> {
     edge.zip_map(x, step_scalar)
 }
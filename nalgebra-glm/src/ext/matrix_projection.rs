@@ -134,6 +134,7 @@ pub fn project_zo<T: RealNumber>(
 /// * [`project_zo`](fn.project_zo.html)
 /// * [`unproject_no`](fn.unproject_no.html)
 /// * [`unproject_zo`](fn.unproject_zo.html)
+/// * [`try_unproject`](fn.try_unproject.html)
 pub fn unproject<T: RealNumber>(
     win: &TVec3<T>,
     model: &TMat4<T>,
@@ -161,6 +162,7 @@ pub fn unproject<T: RealNumber>(
 /// * [`project_zo`](fn.project_zo.html)
 /// * [`unproject`](fn.unproject.html)
 /// * [`unproject_zo`](fn.unproject_zo.html)
+/// * [`try_unproject_no`](fn.try_unproject_no.html)
 pub fn unproject_no<T: RealNumber>(
     win: &TVec3<T>,
     model: &TMat4<T>,
@@ -198,6 +200,7 @@ pub fn unproject_no<T: RealNumber>(
 /// * [`project_zo`](fn.project_zo.html)
 /// * [`unproject`](fn.unproject.html)
 /// * [`unproject_no`](fn.unproject_no.html)
+/// * [`try_unproject_zo`](fn.try_unproject_zo.html)
 pub fn unproject_zo<T: RealNumber>(
     win: &TVec3<T>,
     model: &TMat4<T>,
@@ -216,3 +219,123 @@ pub fn unproject_zo<T: RealNumber>(
     let result = transform * pt;
     result.fixed_rows::<3>(0) / result.w
 }
+
+/// Map the specified window coordinates (win.x, win.y, win.z) into object coordinates using a
+/// depth range of -1 to 1, or returns `None` if they cannot be mapped.
+///
+/// This is the same as [`unproject`](fn.unproject.html), except that `None` is returned instead
+/// of non-finite coordinates when `proj * model` is not invertible, or when the window
+/// coordinates are mapped to a point at infinity.
+///
+/// # Parameters:
+///
+/// * `obj` - Specify the window coordinates to be mapped.
+/// * `model` - Specifies the current modelview matrix.
+/// * `proj` - Specifies the current projection matrix.
+/// * `viewport` - Specifies the current viewport.
+///
+/// # See also:
+///
+/// * [`try_unproject_no`](fn.try_unproject_no.html)
+/// * [`try_unproject_zo`](fn.try_unproject_zo.html)
+/// * [`unproject`](fn.unproject.html)
+pub fn try_unproject<T: RealNumber>(
+    win: &TVec3<T>,
+    model: &TMat4<T>,
+    proj: &TMat4<T>,
+    viewport: TVec4<T>,
+) -> Option<TVec3<T>> {
+    try_unproject_no(win, model, proj, viewport)
+}
+
+/// Map the specified window coordinates (win.x, win.y, win.z) into object coordinates, or
+/// returns `None` if they cannot be mapped.
+///
+/// The near and far clip planes correspond to z normalized device coordinates of -1 and +1 respectively. (OpenGL clip volume definition)
+///
+/// This is the same as [`unproject_no`](fn.unproject_no.html), except that `None` is returned
+/// instead of non-finite coordinates when `proj * model` is not invertible, or when the window
+/// coordinates are mapped to a point at infinity.
+///
+/// # Parameters:
+///
+/// * `obj` - Specify the window coordinates to be mapped.
+/// * `model` - Specifies the current modelview matrix.
+/// * `proj` - Specifies the current projection matrix.
+/// * `viewport` - Specifies the current viewport.
+///
+/// # See also:
+///
+/// * [`try_unproject`](fn.try_unproject.html)
+/// * [`try_unproject_zo`](fn.try_unproject_zo.html)
+/// * [`unproject_no`](fn.unproject_no.html)
+pub fn try_unproject_no<T: RealNumber>(
+    win: &TVec3<T>,
+    model: &TMat4<T>,
+    proj: &TMat4<T>,
+    viewport: TVec4<T>,
+) -> Option<TVec3<T>> {
+    let _2: T = na::convert(2.0);
+    let pt = TVec4::new(
+        _2 * (win.x - viewport.x) / viewport.z - T::one(),
+        _2 * (win.y - viewport.y) / viewport.w - T::one(),
+        _2 * win.z - T::one(),
+        T::one(),
+    );
+
+    try_unproject_ndc(&pt, model, proj)
+}
+
+/// Map the specified window coordinates (win.x, win.y, win.z) into object coordinates, or
+/// returns `None` if they cannot be mapped.
+///
+/// The near and far clip planes correspond to z normalized device coordinates of 0 and +1 respectively. (Direct3D clip volume definition)
+///
+/// This is the same as [`unproject_zo`](fn.unproject_zo.html), except that `None` is returned
+/// instead of non-finite coordinates when `proj * model` is not invertible, or when the window
+/// coordinates are mapped to a point at infinity.
+///
+/// # Parameters:
+///
+/// * `obj` - Specify the window coordinates to be mapped.
+/// * `model` - Specifies the current modelview matrix.
+/// * `proj` - Specifies the current projection matrix.
+/// * `viewport` - Specifies the current viewport.
+///
+/// # See also:
+///
+/// * [`try_unproject`](fn.try_unproject.html)
+/// * [`try_unproject_no`](fn.try_unproject_no.html)
+/// * [`unproject_zo`](fn.unproject_zo.html)
+pub fn try_unproject_zo<T: RealNumber>(
+    win: &TVec3<T>,
+    model: &TMat4<T>,
+    proj: &TMat4<T>,
+    viewport: TVec4<T>,
+) -> Option<TVec3<T>> {
+    let _2: T = na::convert(2.0);
+    let pt = TVec4::new(
+        _2 * (win.x - viewport.x) / viewport.z - T::one(),
+        _2 * (win.y - viewport.y) / viewport.w - T::one(),
+        win.z,
+        T::one(),
+    );
+
+    try_unproject_ndc(&pt, model, proj)
+}
+
+/// Maps homogeneous normalized device coordinates back to object coordinates.
+fn try_unproject_ndc<T: RealNumber>(
+    ndc: &TVec4<T>,
+    model: &TMat4<T>,
+    proj: &TMat4<T>,
+) -> Option<TVec3<T>> {
+    let transform = (proj * model).try_inverse()?;
+    let result = transform * ndc;
+
+    if result.w.is_zero() {
+        None
+    } else {
+        Some(result.fixed_rows::<3>(0) / result.w)
+    }
+}
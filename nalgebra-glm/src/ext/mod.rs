@@ -10,7 +10,8 @@ pub use self::matrix_clip_space::{
     reversed_infinite_perspective_rh_zo, reversed_perspective_rh_zo,
 };
 pub use self::matrix_projection::{
-    pick_matrix, project, project_no, project_zo, unproject, unproject_no, unproject_zo,
+    pick_matrix, project, project_no, project_zo, try_unproject, try_unproject_no,
+    try_unproject_zo, unproject, unproject_no, unproject_zo,
 };
 pub use self::matrix_relationnal::{
     equal_columns, equal_columns_eps, equal_columns_eps_vec, not_equal_columns,
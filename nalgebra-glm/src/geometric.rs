@@ -10,9 +10,21 @@ pub fn cross<T: Number>(x: &TVec3<T>, y: &TVec3<T>) -> TVec3<T> {
 
 /// The distance between two points.
 ///
+/// This is the Euclidean norm of `p1 - p0`.
+///
+/// # Examples:
+///
+/// ```
+/// # use nalgebra_glm as glm;
+/// let p0 = glm::vec3(1.0, 2.0, 3.0);
+/// let p1 = glm::vec3(4.0, 6.0, 3.0);
+/// assert_eq!(glm::distance(&p0, &p1), 5.0);
+/// ```
+///
 /// # See also:
 ///
 /// * [`distance2`](fn.distance2.html)
+/// * [`length`](fn.length.html)
 pub fn distance<T: RealNumber, const D: usize>(p0: &TVec<T, D>, p1: &TVec<T, D>) -> T {
     (p1 - p0).norm()
 }
@@ -23,6 +35,30 @@ pub fn dot<T: Number, const D: usize>(x: &TVec<T, D>, y: &TVec<T, D>) -> T {
 }
 
 /// If `dot(nref, i) < 0.0`, return `n`, otherwise, return `-n`.
+///
+/// This orients the normal `n` so that it faces away from the incident vector `i`, using the
+/// reference normal `nref` to decide on which side `i` comes from. If `nref` and `i` are
+/// orthogonal, `-n` is returned.
+///
+/// # Examples:
+///
+/// ```
+/// # use nalgebra_glm as glm;
+/// let n = glm::vec3(0.0, 1.0, 0.0);
+///
+/// // The incident vector points down, toward the surface: `n` already faces it.
+/// let i = glm::vec3(1.0, -1.0, 0.0);
+/// assert_eq!(glm::faceforward(&n, &i, &n), n);
+///
+/// // The incident vector points up, i.e., it comes from the other side of the surface.
+/// let i = glm::vec3(1.0, 1.0, 0.0);
+/// assert_eq!(glm::faceforward(&n, &i, &n), -n);
+/// ```
+///
+/// # See also:
+///
+/// * [`reflect_vec`](fn.reflect_vec.html)
+/// * [`refract_vec`](fn.refract_vec.html)
 pub fn faceforward<T: Number, const D: usize>(
     n: &TVec<T, D>,
     i: &TVec<T, D>,
@@ -39,8 +75,16 @@ pub fn faceforward<T: Number, const D: usize>(
 ///
 /// A synonym for [`magnitude`](fn.magnitude.html).
 ///
+/// # Examples:
+///
+/// ```
+/// # use nalgebra_glm as glm;
+/// assert_eq!(glm::length(&glm::vec2(3.0, -4.0)), 5.0);
+/// ```
+///
 /// # See also:
 ///
+/// * [`distance`](fn.distance.html)
 /// * [`length2`](fn.length2.html)
 /// * [`magnitude`](fn.magnitude.html)
 /// * [`magnitude2`](fn.magnitude2.html)
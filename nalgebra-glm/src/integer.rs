@@ -1,111 +1,287 @@
-use na::{DefaultAllocator, RealNumber, Scalar, U3};
+use core::mem;
+
+use na::Scalar;
+use num::PrimInt;
 
 use crate::aliases::TVec;
-use crate::traits::{Alloc, Dimension, Number};
 
-pub fn bitCount<T>(v: T) -> i32 {
-    unimplemented!()
+/// The number of bits of the integer type `T`.
+fn num_bits<T>() -> usize {
+    mem::size_of::<T>() * 8
 }
 
-pub fn bitCount2<T: Scalar, const D: usize>(v: &TVec<T, D>) -> TVec<i32, D>
-where
-    DefaultAllocator: Alloc<T, D>,
-{
-    unimplemented!()
+/// Checks that the bit range `[offset, offset + bits)` lies within an integer of type `T`.
+fn assert_bit_range<T>(offset: usize, bits: usize) {
+    assert!(
+        matches!(offset.checked_add(bits), Some(end) if end <= num_bits::<T>()),
+        "Bitfield out of range: offset + bits must not exceed the number of bits of the integer type."
+    );
 }
 
-pub fn bitfieldExtract<T: Scalar, const D: usize>(
-    Value: &TVec<T, D>,
-    Offset: i32,
-    Bits: i32,
-) -> TVec<T, D>
-where
-    DefaultAllocator: Alloc<T, D>,
-{
-    unimplemented!()
+/// Returns the number of bits set to 1 in each component of `v`.
+///
+/// # Examples:
+///
+/// ```
+/// # use nalgebra_glm as glm;
+/// assert_eq!(glm::bit_count(&glm::vec3(0u32, 0b1011, u32::MAX)), glm::vec3(0, 3, 32));
+/// assert_eq!(glm::bit_count(&glm::vec2(-1i32, 7)), glm::vec2(32, 3));
+/// ```
+///
+/// # See also:
+///
+/// * [`find_lsb`](fn.find_lsb.html)
+/// * [`find_msb`](fn.find_msb.html)
+pub fn bit_count<T: Scalar + PrimInt, const D: usize>(v: &TVec<T, D>) -> TVec<i32, D> {
+    v.map(|x| x.count_ones() as i32)
 }
 
-pub fn bitfieldInsert<T: Scalar, const D: usize>(
-    Base: &TVec<T, D>,
-    Insert: &TVec<T, D>,
-    Offset: i32,
-    Bits: i32,
-) -> TVec<T, D>
-where
-    DefaultAllocator: Alloc<T, D>,
-{
-    unimplemented!()
-}
+/// Extracts `bits` bits of each component of `v`, starting at the bit `offset`.
+///
+/// The extracted bits are stored in the least significant bits of the result. For signed
+/// integers, the most significant bit of the extracted field is used to fill the remaining bits
+/// (sign extension), while they are set to zero for unsigned integers. If `bits` is zero, the
+/// result is zero.
+///
+/// # Panics
+///
+/// Panics if `offset + bits` is greater than the number of bits of `T`.
+///
+/// # Examples:
+///
+/// ```
+/// # use nalgebra_glm as glm;
+/// assert_eq!(glm::bitfield_extract(&glm::vec2(0xabcdu32, 0xf0), 4, 8), glm::vec2(0xbc, 0x0f));
+/// assert_eq!(glm::bitfield_extract(&glm::vec2(0b0110i32, 0b1000), 1, 3), glm::vec2(3, -4));
+/// ```
+///
+/// # See also:
+///
+/// * [`bitfield_insert`](fn.bitfield_insert.html)
+pub fn bitfield_extract<T: Scalar + PrimInt, const D: usize>(
+    v: &TVec<T, D>,
+    offset: usize,
+    bits: usize,
+) -> TVec<T, D> {
+    assert_bit_range::<T>(offset, bits);
 
-pub fn bitfieldReverse<T: Scalar, const D: usize>(v: &TVec<T, D>) -> TVec<T, D>
-where
-    DefaultAllocator: Alloc<T, D>,
-{
-    unimplemented!()
-}
+    if bits == 0 {
+        return v.map(|_| T::zero());
+    }
 
-pub fn findLSB<IU>(x: IU) -> u32 {
-    unimplemented!()
+    // The right shift is arithmetic for signed integers, which performs the sign extension.
+    let n = num_bits::<T>();
+    v.map(|x| (x << (n - offset - bits)) >> (n - bits))
 }
 
-pub fn findLSB2<T: Scalar, const D: usize>(v: &TVec<T, D>) -> TVec<i32, D>
-where
-    DefaultAllocator: Alloc<T, D>,
-{
-    unimplemented!()
+/// Replaces `bits` bits of each component of `base`, starting at the bit `offset`, by the
+/// `bits` least significant bits of the corresponding component of `insert`.
+///
+/// If `bits` is zero, the result is `base`.
+///
+/// # Panics
+///
+/// Panics if `offset + bits` is greater than the number of bits of `T`.
+///
+/// # Examples:
+///
+/// ```
+/// # use nalgebra_glm as glm;
+/// let base = glm::vec2(0xffffu32, 0x0000);
+/// let insert = glm::vec2(0x0u32, 0xabc);
+/// assert_eq!(glm::bitfield_insert(&base, &insert, 4, 8), glm::vec2(0xf00f, 0x0bc0));
+/// ```
+///
+/// # See also:
+///
+/// * [`bitfield_extract`](fn.bitfield_extract.html)
+pub fn bitfield_insert<T: Scalar + PrimInt, const D: usize>(
+    base: &TVec<T, D>,
+    insert: &TVec<T, D>,
+    offset: usize,
+    bits: usize,
+) -> TVec<T, D> {
+    assert_bit_range::<T>(offset, bits);
+
+    if bits == 0 {
+        return *base;
+    }
+
+    let mask = (!T::zero()).unsigned_shr((num_bits::<T>() - bits) as u32) << offset;
+    base.zip_map(insert, |b, i| (b & !mask) | ((i << offset) & mask))
 }
 
-pub fn findMSB<IU>(x: IU) -> i32 {
-    unimplemented!()
+/// Reverses the order of the bits of each component of `v`.
+///
+/// The bit `n` of each component of the result is the bit `N - 1 - n` of the corresponding
+/// component of `v`, where `N` is the number of bits of `T`.
+///
+/// # Examples:
+///
+/// ```
+/// # use nalgebra_glm as glm;
+/// let v = glm::vec2(1u32, 0x0000_ff00);
+/// assert_eq!(glm::bitfield_reverse(&v), glm::vec2(0x8000_0000, 0x00ff_0000));
+/// ```
+pub fn bitfield_reverse<T: Scalar + PrimInt, const D: usize>(v: &TVec<T, D>) -> TVec<T, D> {
+    v.map(|x| x.reverse_bits())
 }
 
-pub fn findMSB2<T: Scalar, const D: usize>(v: &TVec<T, D>) -> TVec<i32, D>
-where
-    DefaultAllocator: Alloc<T, D>,
-{
-    unimplemented!()
+/// Returns the index of the least significant bit set to 1 in each component of `v`.
+///
+/// The result is `-1` for components equal to zero.
+///
+/// # Examples:
+///
+/// ```
+/// # use nalgebra_glm as glm;
+/// assert_eq!(glm::find_lsb(&glm::vec3(0u32, 1, 0b1100)), glm::vec3(-1, 0, 2));
+/// assert_eq!(glm::find_lsb(&glm::vec2(i32::MIN, -1)), glm::vec2(31, 0));
+/// ```
+///
+/// # See also:
+///
+/// * [`bit_count`](fn.bit_count.html)
+/// * [`find_msb`](fn.find_msb.html)
+pub fn find_lsb<T: Scalar + PrimInt, const D: usize>(v: &TVec<T, D>) -> TVec<i32, D> {
+    v.map(|x| {
+        if x.is_zero() {
+            -1
+        } else {
+            x.trailing_zeros() as i32
+        }
+    })
 }
 
-pub fn imulExtended<T: Scalar, const D: usize>(
-    x: &TVec<i32, D>,
-    y: &TVec<i32, D>,
-    msb: &TVec<i32, D>,
-    lsb: &TVec<i32, D>,
-) where
-    DefaultAllocator: Alloc<T, D>,
-{
-    unimplemented!()
+/// Returns the index of the most significant bit of each component of `v`.
+///
+/// For positive integers, this is the index of the most significant bit set to 1. For negative
+/// signed integers, this is the index of the most significant bit set to 0. The result is `-1`
+/// for components equal to zero, as well as for signed components equal to `-1`.
+///
+/// # Examples:
+///
+/// ```
+/// # use nalgebra_glm as glm;
+/// assert_eq!(glm::find_msb(&glm::vec3(0u32, 1, u32::MAX)), glm::vec3(-1, 0, 31));
+/// assert_eq!(glm::find_msb(&glm::vec3(-1i32, -2, i32::MIN)), glm::vec3(-1, 0, 30));
+/// ```
+///
+/// # See also:
+///
+/// * [`bit_count`](fn.bit_count.html)
+/// * [`find_lsb`](fn.find_lsb.html)
+pub fn find_msb<T: Scalar + PrimInt, const D: usize>(v: &TVec<T, D>) -> TVec<i32, D> {
+    v.map(|x| {
+        let x = if x < T::zero() { !x } else { x };
+        num_bits::<T>() as i32 - 1 - x.leading_zeros() as i32
+    })
 }
 
-pub fn uaddCarry<T: Scalar, const D: usize>(
+/// Adds the components of `x` and `y` modulo 2<sup>32</sup>.
+///
+/// Returns the sums, and the carries which are set to `1` for components where the sum
+/// overflowed, and to `0` otherwise.
+///
+/// # Examples:
+///
+/// ```
+/// # use nalgebra_glm as glm;
+/// let (sum, carry) = glm::uadd_carry(&glm::vec2(u32::MAX, 1), &glm::vec2(2, 2));
+/// assert_eq!(sum, glm::vec2(1, 3));
+/// assert_eq!(carry, glm::vec2(1, 0));
+/// ```
+///
+/// # See also:
+///
+/// * [`usub_borrow`](fn.usub_borrow.html)
+pub fn uadd_carry<const D: usize>(
     x: &TVec<u32, D>,
     y: &TVec<u32, D>,
-    carry: &TVec<u32, D>,
-) -> TVec<u32, D>
-where
-    DefaultAllocator: Alloc<T, D>,
-{
-    unimplemented!()
+) -> (TVec<u32, D>, TVec<u32, D>) {
+    (
+        x.zip_map(y, u32::wrapping_add),
+        x.zip_map(y, |a, b| a.overflowing_add(b).1 as u32),
+    )
+}
+
+/// Multiplies the components of `x` and `y`, producing 64-bit signed results.
+///
+/// Returns the 32 most significant bits and the 32 least significant bits of the products.
+///
+/// # Examples:
+///
+/// ```
+/// # use nalgebra_glm as glm;
+/// let (msb, lsb) = glm::imul_extended(&glm::vec2(i32::MAX, -3), &glm::vec2(4, 5));
+/// assert_eq!(msb, glm::vec2(1, -1));
+/// assert_eq!(lsb, glm::vec2(-4, -15));
+/// ```
+///
+/// # See also:
+///
+/// * [`umul_extended`](fn.umul_extended.html)
+pub fn imul_extended<const D: usize>(
+    x: &TVec<i32, D>,
+    y: &TVec<i32, D>,
+) -> (TVec<i32, D>, TVec<i32, D>) {
+    let products = x.zip_map(y, |a, b| a as i64 * b as i64);
+    (
+        products.map(|p| (p >> 32) as i32),
+        products.map(|p| p as i32),
+    )
 }
 
-pub fn umulExtended<T: Scalar, const D: usize>(
+/// Multiplies the components of `x` and `y`, producing 64-bit unsigned results.
+///
+/// Returns the 32 most significant bits and the 32 least significant bits of the products.
+///
+/// # Examples:
+///
+/// ```
+/// # use nalgebra_glm as glm;
+/// let (msb, lsb) = glm::umul_extended(&glm::vec2(u32::MAX, 3), &glm::vec2(u32::MAX, 5));
+/// assert_eq!(msb, glm::vec2(u32::MAX - 1, 0));
+/// assert_eq!(lsb, glm::vec2(1, 15));
+/// ```
+///
+/// # See also:
+///
+/// * [`imul_extended`](fn.imul_extended.html)
+pub fn umul_extended<const D: usize>(
     x: &TVec<u32, D>,
     y: &TVec<u32, D>,
-    msb: &TVec<u32, D>,
-    lsb: &TVec<u32, D>,
-) where
-    DefaultAllocator: Alloc<T, D>,
-{
-    unimplemented!()
+) -> (TVec<u32, D>, TVec<u32, D>) {
+    let products = x.zip_map(y, |a, b| a as u64 * b as u64);
+    (
+        products.map(|p| (p >> 32) as u32),
+        products.map(|p| p as u32),
+    )
 }
 
-pub fn usubBorrow<T: Scalar, const D: usize>(
+/// Subtracts the components of `y` from the components of `x` modulo 2<sup>32</sup>.
+///
+/// Returns the differences, and the borrows which are set to `1` for components where `x` is
+/// smaller than `y`, and to `0` otherwise.
+///
+/// # Examples:
+///
+/// ```
+/// # use nalgebra_glm as glm;
+/// let (diff, borrow) = glm::usub_borrow(&glm::vec2(1, 5), &glm::vec2(2, 2));
+/// assert_eq!(diff, glm::vec2(u32::MAX, 3));
+/// assert_eq!(borrow, glm::vec2(1, 0));
+/// ```
+///
+/// # See also:
+///
+/// * [`uadd_carry`](fn.uadd_carry.html)
+pub fn usub_borrow<const D: usize>(
     x: &TVec<u32, D>,
     y: &TVec<u32, D>,
-    borrow: &TVec<u32, D>,
-) -> TVec<u32, D>
-where
-    DefaultAllocator: Alloc<T, D>,
-{
-    unimplemented!()
+) -> (TVec<u32, D>, TVec<u32, D>) {
+    (
+        x.zip_map(y, u32::wrapping_sub),
+        x.zip_map(y, |a, b| (a < b) as u32),
+    )
 }
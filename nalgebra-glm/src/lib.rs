@@ -39,8 +39,8 @@
    * All type names use `CamelCase`, which is the Rust convention.
    * All function arguments, except for scalars, are all passed by-reference.
    * The most generic vector and matrix types are [`TMat`](type.TMat.html) and [`TVec`](type.TVec.html) instead of `mat` and `vec`.
-   * Some feature are not yet implemented and should be added in the future. In particular, no packing
-   functions are available.
+   * Some feature are not yet implemented and should be added in the future. In particular, the packing
+   and bitfield functions of the GTC extensions are not available.
    * A few features are not implemented and will never be. This includes functions related to color
    spaces, and closest points computations. Other crates should be used for those. For example, closest
    points computation can be handled by the [ncollide](https://ncollide.org) project.
@@ -134,15 +134,24 @@ pub use common::{
     abs, ceil, clamp, clamp_scalar, clamp_vec, float_bits_to_int, float_bits_to_int_vec,
     float_bits_to_uint, float_bits_to_uint_vec, floor, fract, int_bits_to_float,
     int_bits_to_float_vec, lerp, lerp_scalar, lerp_vec, mix, mix_scalar, mix_vec, modf, modf_vec,
-    round, sign, smoothstep, step, step_scalar, step_vec, trunc, uint_bits_to_float,
-    uint_bits_to_float_scalar,
+    round, sign, smoothstep, smoothstep_vec, step, step_scalar, step_vec, trunc,
+    uint_bits_to_float, uint_bits_to_float_scalar,
 };
 pub use constructors::*;
 pub use exponential::{exp, exp2, inversesqrt, log, log2, pow, sqrt};
 pub use geometric::{
     cross, distance, dot, faceforward, length, magnitude, normalize, reflect_vec, refract_vec,
 };
+pub use integer::{
+    bit_count, bitfield_extract, bitfield_insert, bitfield_reverse, find_lsb, find_msb,
+    imul_extended, uadd_carry, umul_extended, usub_borrow,
+};
 pub use matrix::{determinant, inverse, matrix_comp_mult, outer_product, transpose};
+pub use packing::{
+    pack_double2x32, pack_half2x16, pack_snorm2x16, pack_snorm4x8, pack_unorm2x16, pack_unorm4x8,
+    unpack_double2x32, unpack_half2x16, unpack_snorm2x16, unpack_snorm4x8, unpack_unorm2x16,
+    unpack_unorm4x8,
+};
 pub use trigonometric::{
     acos, acosh, asin, asinh, atan, atan2, atanh, cos, cosh, degrees, radians, sin, sinh, tan, tanh,
 };
@@ -165,7 +174,8 @@ pub use ext::{
     quat_dot, quat_equal, quat_equal_eps, quat_exp, quat_inverse, quat_length, quat_lerp, quat_log,
     quat_magnitude, quat_normalize, quat_not_equal, quat_not_equal_eps, quat_pow, quat_rotate,
     quat_slerp, reversed_infinite_perspective_rh_zo, reversed_perspective_rh_zo, rotate, rotate_x,
-    rotate_y, rotate_z, scale, translate, unproject, unproject_no, unproject_zo,
+    rotate_y, rotate_z, scale, translate, try_unproject, try_unproject_no, try_unproject_zo,
+    unproject, unproject_no, unproject_zo,
 };
 pub use gtc::{
     affine_inverse, column, e, euler, four_over_pi, golden_ratio, half_pi, inverse_transpose,
@@ -208,12 +218,12 @@ mod common;
 mod constructors;
 mod exponential;
 mod geometric;
+mod integer;
 mod matrix;
+mod packing;
 mod traits;
 mod trigonometric;
 mod vector_relational;
-//mod integer;
-//mod packing;
 
 mod ext;
 mod gtc;
@@ -1,51 +1,246 @@
-use na::Scalar;
+use num::float::FloatCore;
 
-use crate::aliases::{UVec2, Vec2, Vec4};
+use crate::aliases::{TVec, UVec2, Vec2, Vec4};
+use crate::common::clamp;
 
-pub fn packDouble2x32<T: Scalar>(v: &UVec2) -> f64 {
-    unimplemented!()
+/// Rounds the components of `v` to the nearest integers, each fitting in `bits` bits, and packs
+/// them into an unsigned integer.
+///
+/// The first component is stored in the least significant bits.
+fn pack_components<const D: usize>(v: &TVec<f32, D>, bits: u32) -> u32 {
+    v.iter().rev().fold(0, |acc, c| {
+        (acc << bits) | (FloatCore::round(*c) as i32 as u32 & ((1 << bits) - 1))
+    })
 }
 
-pub fn packHalf2x16<T: Scalar>(v: &Vec2) -> u32 {
-    unimplemented!()
+/// Extracts `D` components of `bits` bits from an unsigned integer, the first one being stored
+/// in the least significant bits.
+fn unpack_components<const D: usize>(p: u32, bits: u32) -> TVec<u32, D> {
+    TVec::<u32, D>::from_fn(|i, _| (p >> (bits * i as u32)) & ((1 << bits) - 1))
 }
 
-pub fn packSnorm2x16<T: Scalar>(v: &Vec2) -> u32 {
-    unimplemented!()
+/// Converts a single-precision floating-point value to the bits of a half-precision one.
+///
+/// The value is rounded to the nearest representable value (ties to even). Values that are too
+/// large are converted to infinities, and NaNs are preserved.
+fn f32_to_f16_bits(value: f32) -> u16 {
+    let x = value.to_bits();
+    let sign = (x >> 16) & 0x8000;
+    let exp = (x >> 23) & 0xff;
+    let man = x & 0x007f_ffff;
+
+    // Infinities and NaNs.
+    if exp == 0xff {
+        let nan = if man != 0 { 0x0200 | (man >> 13) } else { 0 };
+        return (sign | 0x7c00 | nan) as u16;
+    }
+
+    let half_exp = exp as i32 - 127 + 15;
+
+    // Overflow to infinity.
+    if half_exp >= 0x1f {
+        return (sign | 0x7c00) as u16;
+    }
+
+    // Subnormal half-precision numbers, or underflow to zero.
+    if half_exp <= 0 {
+        if half_exp < -10 {
+            return sign as u16;
+        }
+
+        let man = man | 0x0080_0000;
+        let shift = (14 - half_exp) as u32;
+        let half_man = man >> shift;
+        let round_bit = 1 << (shift - 1);
+        let round_up = (man & round_bit) != 0 && (man & (3 * round_bit - 1)) != 0;
+        return (sign | half_man) as u16 + round_up as u16;
+    }
+
+    // Normal numbers. Rounding may carry into the exponent, which is the expected behavior.
+    let half = sign | ((half_exp as u32) << 10) | (man >> 13);
+    let round_up = (man & 0x1000) != 0 && (man & 0x2fff) != 0;
+    (half + round_up as u32) as u16
+}
+
+/// Converts the bits of a half-precision floating-point value to a single-precision one.
+fn f16_bits_to_f32(bits: u16) -> f32 {
+    let bits = bits as u32;
+    let sign = (bits & 0x8000) << 16;
+    let exp = (bits >> 10) & 0x1f;
+    let man = bits & 0x03ff;
+
+    let result = match (exp, man) {
+        (0, 0) => sign,
+        // Subnormal numbers are normalized.
+        (0, _) => {
+            let shift = man.leading_zeros() - 21;
+            let man = (man << shift) & 0x03ff;
+            sign | ((127 - 15 + 1 - shift) << 23) | (man << 13)
+        }
+        (0x1f, _) => sign | 0x7f80_0000 | (man << 13),
+        _ => sign | ((exp + 127 - 15) << 23) | (man << 13),
+    };
+
+    f32::from_bits(result)
+}
+
+/// Returns a double-precision value obtained by packing the components of `v` into a 64-bit
+/// value.
+///
+/// The first component of `v` specifies the 32 least significant bits, and the second one the
+/// 32 most significant bits.
+///
+/// # See also:
+///
+/// * [`unpack_double2x32`](fn.unpack_double2x32.html)
+pub fn pack_double2x32(v: &UVec2) -> f64 {
+    f64::from_bits(((v.y as u64) << 32) | v.x as u64)
+}
+
+/// Packs two floating-point values into an unsigned integer, as half-precision floating-point
+/// values.
+///
+/// The first component of `v` specifies the 16 least significant bits of the result, and the
+/// second one the 16 most significant bits. Values are rounded to the nearest half-precision
+/// value, values too large to be represented become infinities.
+///
+/// # Examples:
+///
+/// ```
+/// # use nalgebra_glm as glm;
+/// let packed = glm::pack_half2x16(&glm::vec2(1.0, -2.5));
+/// assert_eq!(packed, 0xc100_3c00);
+/// assert_eq!(glm::unpack_half2x16(packed), glm::vec2(1.0, -2.5));
+/// ```
+///
+/// # See also:
+///
+/// * [`unpack_half2x16`](fn.unpack_half2x16.html)
+pub fn pack_half2x16(v: &Vec2) -> u32 {
+    ((f32_to_f16_bits(v.y) as u32) << 16) | f32_to_f16_bits(v.x) as u32
+}
+
+/// Converts each component of `v` into a 16-bit signed integer after clamping it to `[-1, 1]`
+/// and scaling it by `32767`, then packs them into an unsigned integer.
+///
+/// The first component of `v` specifies the 16 least significant bits of the result.
+///
+/// # See also:
+///
+/// * [`unpack_snorm2x16`](fn.unpack_snorm2x16.html)
+pub fn pack_snorm2x16(v: &Vec2) -> u32 {
+    pack_components(&(clamp(v, -1.0, 1.0) * 32767.0), 16)
 }
 
-pub fn packSnorm4x8<T: Scalar>(v: &Vec4) -> u32 {
-    unimplemented!()
+/// Converts each component of `v` into an 8-bit signed integer after clamping it to `[-1, 1]`
+/// and scaling it by `127`, then packs them into an unsigned integer.
+///
+/// The first component of `v` specifies the 8 least significant bits of the result.
+///
+/// # See also:
+///
+/// * [`unpack_snorm4x8`](fn.unpack_snorm4x8.html)
+pub fn pack_snorm4x8(v: &Vec4) -> u32 {
+    pack_components(&(clamp(v, -1.0, 1.0) * 127.0), 8)
 }
 
-pub fn packUnorm2x16<T: Scalar>(v: &Vec2) -> u32 {
-    unimplemented!()
+/// Converts each component of `v` into a 16-bit unsigned integer after clamping it to `[0, 1]`
+/// and scaling it by `65535`, then packs them into an unsigned integer.
+///
+/// The first component of `v` specifies the 16 least significant bits of the result.
+///
+/// # See also:
+///
+/// * [`unpack_unorm2x16`](fn.unpack_unorm2x16.html)
+pub fn pack_unorm2x16(v: &Vec2) -> u32 {
+    pack_components(&(clamp(v, 0.0, 1.0) * 65535.0), 16)
 }
 
-pub fn packUnorm4x8<T: Scalar>(v: &Vec4) -> u32 {
-    unimplemented!()
+/// Converts each component of `v` into an 8-bit unsigned integer after clamping it to `[0, 1]`
+/// and scaling it by `255`, then packs them into an unsigned integer.
+///
+/// The first component of `v` specifies the 8 least significant bits of the result. This is
+/// typically used to pack RGBA colors.
+///
+/// # Examples:
+///
+/// ```
+/// # use nalgebra_glm as glm;
+/// let packed = glm::pack_unorm4x8(&glm::vec4(1.0, 0.5, 0.0, 2.0));
+/// assert_eq!(packed, 0xff00_80ff);
+/// assert_eq!(glm::unpack_unorm4x8(packed), glm::vec4(1.0, 128.0 / 255.0, 0.0, 1.0));
+/// ```
+///
+/// # See also:
+///
+/// * [`unpack_unorm4x8`](fn.unpack_unorm4x8.html)
+pub fn pack_unorm4x8(v: &Vec4) -> u32 {
+    pack_components(&(clamp(v, 0.0, 1.0) * 255.0), 8)
 }
 
-pub fn unpackDouble2x32<T: Scalar>(v: f64) -> UVec2 {
-    unimplemented!()
+/// Returns a two-component unsigned integer vector representation of the bits of `v`.
+///
+/// The first component contains the 32 least significant bits, and the second one the 32 most
+/// significant bits.
+///
+/// # See also:
+///
+/// * [`pack_double2x32`](fn.pack_double2x32.html)
+pub fn unpack_double2x32(v: f64) -> UVec2 {
+    let bits = v.to_bits();
+    UVec2::new(bits as u32, (bits >> 32) as u32)
 }
 
-pub fn unpackHalf2x16<T: Scalar>(v: u32) -> Vec2 {
-    unimplemented!()
+/// Unpacks two half-precision floating-point values from an unsigned integer.
+///
+/// The first component of the result is obtained from the 16 least significant bits of `p`,
+/// and the second one from the 16 most significant bits.
+///
+/// # See also:
+///
+/// * [`pack_half2x16`](fn.pack_half2x16.html)
+pub fn unpack_half2x16(p: u32) -> Vec2 {
+    Vec2::new(f16_bits_to_f32(p as u16), f16_bits_to_f32((p >> 16) as u16))
 }
 
-pub fn unpackSnorm2x16<T: Scalar>(p: u32) -> Vec2 {
-    unimplemented!()
+/// Unpacks two 16-bit signed integers from an unsigned integer, and converts them to normalized
+/// floating-point values in `[-1, 1]`.
+///
+/// # See also:
+///
+/// * [`pack_snorm2x16`](fn.pack_snorm2x16.html)
+pub fn unpack_snorm2x16(p: u32) -> Vec2 {
+    let v = unpack_components::<2>(p, 16).map(|c| c as u16 as i16 as f32 / 32767.0);
+    clamp(&v, -1.0, 1.0)
 }
 
-pub fn unpackSnorm4x8<T: Scalar>(p: u32) -> Vec4 {
-    unimplemented!()
+/// Unpacks four 8-bit signed integers from an unsigned integer, and converts them to normalized
+/// floating-point values in `[-1, 1]`.
+///
+/// # See also:
+///
+/// * [`pack_snorm4x8`](fn.pack_snorm4x8.html)
+pub fn unpack_snorm4x8(p: u32) -> Vec4 {
+    let v = unpack_components::<4>(p, 8).map(|c| c as u8 as i8 as f32 / 127.0);
+    clamp(&v, -1.0, 1.0)
 }
 
-pub fn unpackUnorm2x16<T: Scalar>(p: u32) -> Vec2 {
-    unimplemented!()
+/// Unpacks two 16-bit unsigned integers from an unsigned integer, and converts them to
+/// normalized floating-point values in `[0, 1]`.
+///
+/// # See also:
+///
+/// * [`pack_unorm2x16`](fn.pack_unorm2x16.html)
+pub fn unpack_unorm2x16(p: u32) -> Vec2 {
+    unpack_components::<2>(p, 16).map(|c| c as f32 / 65535.0)
 }
 
-pub fn unpackUnorm4x8<T: Scalar>(p: u32) -> Vec4 {
-    unimplemented!()
+/// Unpacks four 8-bit unsigned integers from an unsigned integer, and converts them to
+/// normalized floating-point values in `[0, 1]`.
+///
+/// # See also:
+///
+/// * [`pack_unorm4x8`](fn.pack_unorm4x8.html)
+pub fn unpack_unorm4x8(p: u32) -> Vec4 {
+    unpack_components::<4>(p, 8).map(|c| c as f32 / 255.0)
 }
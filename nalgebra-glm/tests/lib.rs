@@ -53,3 +53,272 @@ pub fn perspective_glm_nalgebra_project_same() {
     assert_eq!(na_mat, gl_mat);
     assert_eq!(na_pt, gl_pt);
 }
+
+#[test]
+pub fn project_unproject_roundtrip() {
+    let model = glm::rotate_y(&glm::translation(&glm::vec3(1.0, -2.0, 0.5)), 0.3);
+    let view = glm::look_at(
+        &glm::vec3(3.0, 4.0, 10.0),
+        &glm::vec3(0.0, 0.0, 0.0),
+        &glm::vec3(0.0, 1.0, 0.0),
+    );
+    let model_view = view * model;
+    let viewport = glm::vec4(10.0, 20.0, 800.0, 600.0);
+    let proj_no = glm::perspective_rh_no(4.0 / 3.0, 1.0, 0.1, 100.0);
+    let proj_zo = glm::perspective_rh_zo(4.0 / 3.0, 1.0, 0.1, 100.0);
+
+    for p in [
+        glm::vec3(0.0, 0.0, 0.0),
+        glm::vec3(1.0, 2.0, -3.0),
+        glm::vec3(-2.5, 0.5, 1.0),
+    ] {
+        // OpenGL depth range.
+        let win = glm::project_no(&p, &model_view, &proj_no, viewport);
+        assert!(win.z >= 0.0 && win.z <= 1.0);
+        let unprojected = glm::unproject_no(&win, &model_view, &proj_no, viewport);
+        assert!(glm::distance(&unprojected, &p) < 1.0e-3);
+        let unprojected = glm::try_unproject_no(&win, &model_view, &proj_no, viewport).unwrap();
+        assert!(glm::distance(&unprojected, &p) < 1.0e-3);
+
+        // Vulkan/Direct3D depth range.
+        let win = glm::project_zo(&p, &model_view, &proj_zo, viewport);
+        assert!(win.z >= 0.0 && win.z <= 1.0);
+        let unprojected = glm::unproject_zo(&win, &model_view, &proj_zo, viewport);
+        assert!(glm::distance(&unprojected, &p) < 1.0e-3);
+        let unprojected = glm::try_unproject_zo(&win, &model_view, &proj_zo, viewport).unwrap();
+        assert!(glm::distance(&unprojected, &p) < 1.0e-3);
+    }
+}
+
+#[test]
+pub fn try_unproject_singular() {
+    let viewport = glm::vec4(0.0, 0.0, 800.0, 600.0);
+    let win = glm::vec3(400.0, 300.0, 0.5);
+    let proj = glm::perspective(4.0 / 3.0, 1.0, 0.1, 100.0);
+    let singular = glm::scaling(&glm::vec3(1.0, 0.0, 1.0));
+
+    assert!(glm::try_unproject(&win, &singular, &proj, viewport).is_none());
+    assert!(glm::try_unproject_no(&win, &singular, &proj, viewport).is_none());
+    assert!(glm::try_unproject_zo(&win, &singular, &proj, viewport).is_none());
+    assert!(glm::try_unproject(&win, &Mat4::identity(), &proj, viewport).is_some());
+}
+
+#[test]
+pub fn pack_half2x16_rounding() {
+    let pack = |x: f32| glm::pack_half2x16(&glm::vec2(x, 0.0));
+
+    assert_eq!(pack(0.0), 0x0000);
+    assert_eq!(pack(-0.0), 0x8000);
+    assert_eq!(pack(65504.0), 0x7bff);
+    assert_eq!(pack(1.0e6), 0x7c00);
+    assert_eq!(pack(f32::NEG_INFINITY), 0xfc00);
+    assert_eq!(pack(f32::NAN) & 0x7c00, 0x7c00);
+    assert_ne!(pack(f32::NAN) & 0x03ff, 0);
+    // Smallest subnormal and underflow.
+    assert_eq!(pack(2.0f32.powi(-24)), 0x0001);
+    assert_eq!(pack(2.0f32.powi(-26)), 0x0000);
+    // Round to nearest, ties to even.
+    assert_eq!(pack(1.0 + 2.0f32.powi(-11)), 0x3c00);
+    assert_eq!(pack(1.0 + 3.0 * 2.0f32.powi(-11)), 0x3c02);
+    assert_eq!(pack(1.0 + 1.1 * 2.0f32.powi(-11)), 0x3c01);
+    assert_eq!(pack(65519.0), 0x7bff);
+    assert_eq!(pack(65520.0), 0x7c00);
+}
+
+#[test]
+pub fn unpack_half2x16_roundtrip() {
+    for bits in 0..=0xffffu32 {
+        let v = glm::unpack_half2x16(bits | (bits << 16));
+        if v.x.is_nan() {
+            assert!(v.y.is_nan() && (bits & 0x7c00) == 0x7c00);
+        } else {
+            assert_eq!(glm::pack_half2x16(&v), bits | (bits << 16));
+        }
+    }
+
+    assert_eq!(glm::unpack_half2x16(0x0001_3555).y, 2.0f32.powi(-24));
+    assert_eq!(glm::unpack_half2x16(0x0000_3555).x, 0.333_251_95);
+}
+
+#[test]
+pub fn pack_unorm_snorm() {
+    let color = glm::vec4(0.2, 0.4, 0.6, 0.8);
+    let packed = glm::pack_unorm4x8(&color);
+    assert_eq!(packed, 0xcc99_6633);
+    assert!(
+        glm::equal_eps(&glm::unpack_unorm4x8(packed), &color, 0.5 / 255.0)
+            == glm::TVec4::repeat(true)
+    );
+
+    assert_eq!(glm::pack_unorm2x16(&glm::vec2(-1.0, 0.5)), 0x8000_0000);
+    assert_eq!(glm::unpack_unorm2x16(0xffff_0000), glm::vec2(0.0, 1.0));
+
+    let packed = glm::pack_snorm4x8(&glm::vec4(-1.0, 1.0, -2.0, 0.5));
+    assert_eq!(packed, 0x4081_7f81);
+    assert_eq!(
+        glm::unpack_snorm4x8(packed),
+        glm::vec4(-1.0, 1.0, -1.0, 64.0 / 127.0)
+    );
+    // -128 is clamped to -1.
+    assert_eq!(glm::unpack_snorm4x8(0x80), glm::vec4(-1.0, 0.0, 0.0, 0.0));
+
+    let packed = glm::pack_snorm2x16(&glm::vec2(-0.5, 1.0));
+    assert_eq!(packed, 0x7fff_c000);
+    assert_eq!(
+        glm::unpack_snorm2x16(packed),
+        glm::vec2(-16384.0 / 32767.0, 1.0)
+    );
+}
+
+#[test]
+pub fn pack_double2x32_roundtrip() {
+    let v = glm::UVec2::new(0x5444_2d18, 0x4009_21fb);
+    assert_eq!(glm::pack_double2x32(&v), std::f64::consts::PI);
+    assert_eq!(glm::unpack_double2x32(std::f64::consts::PI), v);
+}
+
+#[test]
+pub fn find_lsb_msb_edge_cases() {
+    assert_eq!(glm::find_lsb(&glm::vec1(0i32)), glm::vec1(-1));
+    assert_eq!(glm::find_msb(&glm::vec1(0u32)), glm::vec1(-1));
+    assert_eq!(glm::find_msb(&glm::vec1(0i32)), glm::vec1(-1));
+    assert_eq!(
+        glm::find_msb(&glm::vec4(i32::MAX, 1 << 20, -1 << 20, -(1 << 20) - 1)),
+        glm::vec4(30, 20, 19, 20)
+    );
+    assert_eq!(
+        glm::find_lsb(&glm::vec4(u32::MAX, 1 << 31, 6, 0)),
+        glm::vec4(0, 31, 1, -1)
+    );
+}
+
+#[test]
+pub fn bit_count_and_reverse() {
+    let v = glm::vec4(0u32, 1, 0xf0f0_0001, u32::MAX);
+    assert_eq!(glm::bit_count(&v), glm::vec4(0, 1, 9, 32));
+    assert_eq!(
+        glm::bitfield_reverse(&v),
+        glm::vec4(0, 0x8000_0000, 0x8000_0f0f, u32::MAX)
+    );
+    assert_eq!(glm::bitfield_reverse(&glm::bitfield_reverse(&v)), v);
+
+    let v = glm::vec3(1i32, -1, i32::MIN);
+    assert_eq!(glm::bit_count(&v), glm::vec3(1, 32, 1));
+    assert_eq!(glm::bitfield_reverse(&v), glm::vec3(i32::MIN, -1, 1));
+}
+
+#[test]
+pub fn bitfield_extract_insert() {
+    let v = glm::vec2(0xdead_beefu32, 0x1234_5678);
+    assert_eq!(glm::bitfield_extract(&v, 0, 32), v);
+    assert_eq!(glm::bitfield_extract(&v, 3, 0), glm::vec2(0, 0));
+    assert_eq!(glm::bitfield_extract(&v, 28, 4), glm::vec2(0xd, 0x1));
+
+    // Signed fields are sign-extended.
+    let v = glm::vec2(-1i32, 0x0000_0f00);
+    assert_eq!(glm::bitfield_extract(&v, 8, 4), glm::vec2(-1, -1));
+    assert_eq!(glm::bitfield_extract(&v, 8, 5), glm::vec2(-1, 15));
+
+    let base = glm::vec2(0u32, u32::MAX);
+    let insert = glm::vec2(u32::MAX, 0);
+    assert_eq!(
+        glm::bitfield_insert(&base, &insert, 0, 32),
+        glm::vec2(u32::MAX, 0)
+    );
+    assert_eq!(glm::bitfield_insert(&base, &insert, 4, 0), base);
+    assert_eq!(
+        glm::bitfield_insert(&base, &insert, 28, 4),
+        glm::vec2(0xf000_0000, 0x0fff_ffff)
+    );
+    assert_eq!(
+        glm::bitfield_extract(&glm::bitfield_insert(&base, &insert, 5, 7), 5, 7),
+        glm::vec2(0x7f, 0)
+    );
+}
+
+#[test]
+#[should_panic]
+pub fn bitfield_extract_out_of_range() {
+    let _ = glm::bitfield_extract(&glm::vec1(0u32), 30, 4);
+}
+
+#[test]
+#[should_panic(expected = "Bitfield out of range")]
+pub fn bitfield_extract_overflowing_range() {
+    let _ = glm::bitfield_extract(&glm::vec1(0u32), usize::MAX, 2);
+}
+
+#[test]
+pub fn extended_arithmetic() {
+    let x = glm::vec3(u32::MAX, 0, 0x8000_0000);
+    let y = glm::vec3(1, 0, 0x8000_0000);
+    assert_eq!(
+        glm::uadd_carry(&x, &y),
+        (glm::vec3(0, 0, 0), glm::vec3(1, 0, 1))
+    );
+    assert_eq!(
+        glm::usub_borrow(&y, &x),
+        (glm::vec3(2, 0, 0), glm::vec3(1, 0, 0))
+    );
+    assert_eq!(
+        glm::umul_extended(&x, &y),
+        (glm::vec3(0, 0, 0x4000_0000), glm::vec3(u32::MAX, 0, 0))
+    );
+    assert_eq!(
+        glm::imul_extended(&glm::vec2(i32::MIN, -1), &glm::vec2(i32::MIN, 1)),
+        (glm::vec2(0x4000_0000, -1), glm::vec2(0, -1))
+    );
+}
+
+#[test]
+pub fn step_smoothstep_mix() {
+    let x = glm::vec4(-1.0, 0.0, 0.25, 2.0);
+
+    assert_eq!(glm::step(0.0, &x), glm::vec4(0.0, 1.0, 1.0, 1.0));
+    assert_eq!(
+        glm::step_vec(&glm::vec4(-2.0, 1.0, 0.25, 3.0), &x),
+        glm::vec4(1.0, 0.0, 1.0, 0.0)
+    );
+
+    // smoothstep clamps outside of the edges and follows the Hermite polynomial in-between.
+    let edge0 = glm::Vec4::repeat(0.0);
+    let edge1 = glm::Vec4::repeat(1.0);
+    assert_eq!(
+        glm::smoothstep_vec(&edge0, &edge1, &x),
+        glm::vec4(0.0, 0.0, 0.15625, 1.0)
+    );
+    for i in 0..4 {
+        assert_eq!(
+            glm::smoothstep_vec(&edge0, &edge1, &x)[i],
+            glm::smoothstep(0.0, 1.0, x[i])
+        );
+    }
+
+    let a = glm::vec3(0.0, 10.0, -4.0);
+    let b = glm::vec3(1.0, 20.0, 4.0);
+    assert_eq!(glm::mix(&a, &b, 0.25), glm::vec3(0.25, 12.5, -2.0));
+    assert_eq!(
+        glm::mix_vec(&a, &b, &glm::vec3(0.0, 0.5, 1.0)),
+        glm::vec3(0.0, 15.0, 4.0)
+    );
+    assert_eq!(glm::mix_scalar(2.0, 4.0, 1.5), 5.0);
+}
+
+#[test]
+pub fn geometric_distance_length_faceforward() {
+    let p0 = glm::vec4(1.0, -1.0, 2.0, 0.0);
+    let p1 = glm::vec4(3.0, 0.0, 0.0, 4.0);
+    assert_eq!(glm::distance(&p0, &p1), 5.0);
+    assert_eq!(glm::distance(&p0, &p1), glm::distance(&p1, &p0));
+    assert_eq!(glm::distance(&p0, &p1), glm::length(&(p1 - p0)));
+    assert_eq!(glm::length(&glm::vec3(2.0, 3.0, 6.0)), 7.0);
+    assert_eq!(glm::length(&glm::vec3(0.0, 0.0, 0.0)), 0.0);
+
+    // `faceforward` keeps `n` only if `i` and `nref` point in opposite directions.
+    let n = glm::vec3(0.0, 0.0, 2.0);
+    let nref = glm::vec3(0.0, 0.0, 1.0);
+    assert_eq!(glm::faceforward(&n, &glm::vec3(1.0, 0.0, -1.0), &nref), n);
+    assert_eq!(glm::faceforward(&n, &glm::vec3(1.0, 0.0, 1.0), &nref), -n);
+    assert_eq!(glm::faceforward(&n, &glm::vec3(1.0, 0.0, 0.0), &nref), -n);
+    assert_eq!(glm::faceforward(&n, &glm::vec3(1.0, 0.0, 1.0), &-nref), n);
+}
@@ -0,0 +1,268 @@
+//! Implements the approximate equality traits of the `approx` crate for sparse matrices.
+//!
+//! Two sparse matrices are considered approximately equal if they have the same dimensions,
+//! the same explicitly stored entries (i.e. the same sparsity pattern, or the same triplets
+//! in the same order for COO matrices), and if the values of these entries are approximately
+//! equal. In particular, an explicitly stored zero is *not* considered equal to an implicit zero.
+//!
+//! CSR and CSC matrices with different sparsity patterns can still be compared with
+//! `relative_eq_ignoring_pattern`, which treats entries that are not explicitly stored as zeros.
+use crate::coo::CooMatrix;
+use crate::csc::CscMatrix;
+use crate::csr::CsrMatrix;
+use crate::pattern::SparsityPattern;
+use approx::{AbsDiffEq, RelativeEq, UlpsEq};
+use num_traits::Zero;
+
+/// Compares the values of two compressed matrices at the union of their sparsity patterns,
+/// treating missing entries as zeros.
+fn cs_relative_eq_ignoring_pattern<T>(
+    (pattern_a, values_a): (&SparsityPattern, &[T]),
+    (pattern_b, values_b): (&SparsityPattern, &[T]),
+    epsilon: T::Epsilon,
+    max_relative: T::Epsilon,
+) -> bool
+where
+    T: RelativeEq + Zero,
+    T::Epsilon: Clone,
+{
+    if pattern_a.major_dim() != pattern_b.major_dim()
+        || pattern_a.minor_dim() != pattern_b.minor_dim()
+    {
+        return false;
+    }
+
+    let zero = T::zero();
+    let eq = |a: &T, b: &T| a.relative_eq(b, epsilon.clone(), max_relative.clone());
+    let lane_entries = |pattern: &'_ SparsityPattern, i: usize| {
+        let offsets = pattern.major_offsets();
+        (offsets[i], offsets[i + 1])
+    };
+
+    (0..pattern_a.major_dim()).all(|i| {
+        let (begin_a, end_a) = lane_entries(pattern_a, i);
+        let (begin_b, end_b) = lane_entries(pattern_b, i);
+        let mut entries_a = pattern_a.lane(i).iter().zip(&values_a[begin_a..end_a]);
+        let mut entries_b = pattern_b.lane(i).iter().zip(&values_b[begin_b..end_b]);
+        let mut a = entries_a.next();
+        let mut b = entries_b.next();
+
+        // Both lanes are sorted by minor index, so we can merge them.
+        loop {
+            match (a, b) {
+                (Some((ja, va)), Some((jb, vb))) if ja == jb => {
+                    if !eq(va, vb) {
+                        return false;
+                    }
+                    a = entries_a.next();
+                    b = entries_b.next();
+                }
+                (Some((ja, va)), Some((jb, _))) if ja < jb => {
+                    if !eq(va, &zero) {
+                        return false;
+                    }
+                    a = entries_a.next();
+                }
+                (Some((_, va)), None) => {
+                    if !eq(va, &zero) {
+                        return false;
+                    }
+                    a = entries_a.next();
+                }
+                (_, Some((_, vb))) => {
+                    if !eq(&zero, vb) {
+                        return false;
+                    }
+                    b = entries_b.next();
+                }
+                (None, None) => return true,
+            }
+        }
+    })
+}
+
+macro_rules! impl_approx_eq_for_csr_csc {
+    ($MatrixType:ident) => {
+        impl<T> $MatrixType<T> {
+            /// Compares `self` and `other` entry-wise with the given absolute and relative
+            /// tolerances, treating entries that are not explicitly stored as zeros.
+            ///
+            /// Unlike the `RelativeEq` implementation, which requires both matrices to have the
+            /// same sparsity pattern, this compares the values at the union of both patterns.
+            /// Two matrices of the same dimensions are thus approximately equal if all their
+            /// entries (implicit or not) are approximately equal, even if an explicit zero of
+            /// one of them is not stored in the other. Returns `false` if the dimensions differ.
+            pub fn relative_eq_ignoring_pattern(
+                &self,
+                other: &Self,
+                epsilon: T::Epsilon,
+                max_relative: T::Epsilon,
+            ) -> bool
+            where
+                T: RelativeEq + Zero,
+                T::Epsilon: Clone,
+            {
+                cs_relative_eq_ignoring_pattern(
+                    (self.pattern(), self.values()),
+                    (other.pattern(), other.values()),
+                    epsilon,
+                    max_relative,
+                )
+            }
+        }
+
+        impl<T> AbsDiffEq for $MatrixType<T>
+        where
+            T: AbsDiffEq,
+            T::Epsilon: Clone,
+        {
+            type Epsilon = T::Epsilon;
+
+            #[inline]
+            fn default_epsilon() -> Self::Epsilon {
+                T::default_epsilon()
+            }
+
+            #[inline]
+            fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+                self.pattern() == other.pattern()
+                    && self
+                        .values()
+                        .iter()
+                        .zip(other.values())
+                        .all(|(a, b)| a.abs_diff_eq(b, epsilon.clone()))
+            }
+        }
+
+        impl<T> RelativeEq for $MatrixType<T>
+        where
+            T: RelativeEq,
+            T::Epsilon: Clone,
+        {
+            #[inline]
+            fn default_max_relative() -> Self::Epsilon {
+                T::default_max_relative()
+            }
+
+            #[inline]
+            fn relative_eq(
+                &self,
+                other: &Self,
+                epsilon: Self::Epsilon,
+                max_relative: Self::Epsilon,
+            ) -> bool {
+                self.pattern() == other.pattern()
+                    && self
+                        .values()
+                        .iter()
+                        .zip(other.values())
+                        .all(|(a, b)| a.relative_eq(b, epsilon.clone(), max_relative.clone()))
+            }
+        }
+
+        impl<T> UlpsEq for $MatrixType<T>
+        where
+            T: UlpsEq,
+            T::Epsilon: Clone,
+        {
+            #[inline]
+            fn default_max_ulps() -> u32 {
+                T::default_max_ulps()
+            }
+
+            #[inline]
+            fn ulps_eq(&self, other: &Self, epsilon: Self::Epsilon, max_ulps: u32) -> bool {
+                self.pattern() == other.pattern()
+                    && self
+                        .values()
+                        .iter()
+                        .zip(other.values())
+                        .all(|(a, b)| a.ulps_eq(b, epsilon.clone(), max_ulps))
+            }
+        }
+    };
+}
+
+impl_approx_eq_for_csr_csc!(CsrMatrix);
+impl_approx_eq_for_csr_csc!(CscMatrix);
+
+impl<T> CooMatrix<T> {
+    /// Whether `self` and `other` have the same dimensions and store their entries at the same
+    /// positions, in the same order.
+    fn has_same_triplet_indices(&self, other: &Self) -> bool {
+        self.nrows() == other.nrows()
+            && self.ncols() == other.ncols()
+            && self.row_indices() == other.row_indices()
+            && self.col_indices() == other.col_indices()
+    }
+}
+
+impl<T> AbsDiffEq for CooMatrix<T>
+where
+    T: AbsDiffEq,
+    T::Epsilon: Clone,
+{
+    type Epsilon = T::Epsilon;
+
+    #[inline]
+    fn default_epsilon() -> Self::Epsilon {
+        T::default_epsilon()
+    }
+
+    #[inline]
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        self.has_same_triplet_indices(other)
+            && self
+                .values()
+                .iter()
+                .zip(other.values())
+                .all(|(a, b)| a.abs_diff_eq(b, epsilon.clone()))
+    }
+}
+
+impl<T> RelativeEq for CooMatrix<T>
+where
+    T: RelativeEq,
+    T::Epsilon: Clone,
+{
+    #[inline]
+    fn default_max_relative() -> Self::Epsilon {
+        T::default_max_relative()
+    }
+
+    #[inline]
+    fn relative_eq(
+        &self,
+        other: &Self,
+        epsilon: Self::Epsilon,
+        max_relative: Self::Epsilon,
+    ) -> bool {
+        self.has_same_triplet_indices(other)
+            && self
+                .values()
+                .iter()
+                .zip(other.values())
+                .all(|(a, b)| a.relative_eq(b, epsilon.clone(), max_relative.clone()))
+    }
+}
+
+impl<T> UlpsEq for CooMatrix<T>
+where
+    T: UlpsEq,
+    T::Epsilon: Clone,
+{
+    #[inline]
+    fn default_max_ulps() -> u32 {
+        T::default_max_ulps()
+    }
+
+    #[inline]
+    fn ulps_eq(&self, other: &Self, epsilon: Self::Epsilon, max_ulps: u32) -> bool {
+        self.has_same_triplet_indices(other)
+            && self
+                .values()
+                .iter()
+                .zip(other.values())
+                .all(|(a, b)| a.ulps_eq(b, epsilon.clone(), max_ulps))
+    }
+}
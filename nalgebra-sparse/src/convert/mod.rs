@@ -28,6 +28,25 @@
 //! // CSC <-> COO
 //! let _ = CooMatrix::from(&csc);
 //! let _ = CscMatrix::from(&coo);
+//!
+//! // Conversion to dense
+//! let _ = DMatrix::from(&csr);
+//! let _ = DMatrix::from(&csc);
+//! let _ = DMatrix::from(&coo);
+//! ```
+//!
+//! Conversion from dense matrices with `From` keeps every non-zero entry. To avoid bloating the
+//! sparsity pattern with near-zero noise, use `from_dense_with_tol` instead, which drops all
+//! entries whose absolute value does not exceed the given tolerance:
+//!
+//! ```
+//! use nalgebra_sparse::{csr::CsrMatrix, csc::CscMatrix, coo::CooMatrix};
+//! use nalgebra::DMatrix;
+//!
+//! let dense = DMatrix::from_row_slice(2, 2, &[1.0, 1.0e-14, -1.0e-13, 2.0]);
+//! assert_eq!(CsrMatrix::from_dense_with_tol(&dense, 1.0e-10).nnz(), 2);
+//! assert_eq!(CscMatrix::from_dense_with_tol(&dense, 1.0e-10).nnz(), 2);
+//! assert_eq!(CooMatrix::from_dense_with_tol(&dense, 1.0e-10).nnz(), 2);
 //! ```
 //!
 //! The routines available here are able to provide more specialized APIs, giving
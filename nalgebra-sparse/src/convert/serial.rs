@@ -8,7 +8,7 @@ use std::ops::Add;
 use num_traits::Zero;
 
 use nalgebra::storage::RawStorage;
-use nalgebra::{ClosedAdd, DMatrix, Dim, Matrix, Scalar};
+use nalgebra::{ClosedAdd, DMatrix, Dim, Matrix, RealField, Scalar};
 
 use crate::coo::CooMatrix;
 use crate::cs;
@@ -23,11 +23,38 @@ where
     R: Dim,
     C: Dim,
     S: RawStorage<T, R, C>,
+{
+    convert_dense_coo_filtered(dense, |v| v != &T::zero())
+}
+
+/// Converts a dense matrix to a [`CooMatrix`], dropping entries whose absolute value is not
+/// strictly greater than `tol`.
+///
+/// With `tol = 0`, all non-zero entries are kept.
+pub fn convert_dense_coo_with_tol<T, R, C, S>(dense: &Matrix<T, R, C, S>, tol: T) -> CooMatrix<T>
+where
+    T: RealField,
+    R: Dim,
+    C: Dim,
+    S: RawStorage<T, R, C>,
+{
+    convert_dense_coo_filtered(dense, |v| v.clone().abs() > tol)
+}
+
+fn convert_dense_coo_filtered<T, R, C, S>(
+    dense: &Matrix<T, R, C, S>,
+    keep: impl Fn(&T) -> bool,
+) -> CooMatrix<T>
+where
+    T: Scalar,
+    R: Dim,
+    C: Dim,
+    S: RawStorage<T, R, C>,
 {
     let mut coo = CooMatrix::new(dense.nrows(), dense.ncols());
 
     for (index, v) in dense.iter().enumerate() {
-        if v != &T::zero() {
+        if keep(v) {
             // We use the fact that matrix iteration is guaranteed to be column-major
             let i = index % dense.nrows();
             let j = index / dense.nrows();
@@ -98,6 +125,33 @@ where
     R: Dim,
     C: Dim,
     S: RawStorage<T, R, C>,
+{
+    convert_dense_csr_filtered(dense, |v| v != &T::zero())
+}
+
+/// Converts a dense matrix to a [`CsrMatrix`], dropping entries whose absolute value is not
+/// strictly greater than `tol`.
+///
+/// With `tol = 0`, all non-zero entries are kept.
+pub fn convert_dense_csr_with_tol<T, R, C, S>(dense: &Matrix<T, R, C, S>, tol: T) -> CsrMatrix<T>
+where
+    T: RealField,
+    R: Dim,
+    C: Dim,
+    S: RawStorage<T, R, C>,
+{
+    convert_dense_csr_filtered(dense, |v| v.clone().abs() > tol)
+}
+
+fn convert_dense_csr_filtered<T, R, C, S>(
+    dense: &Matrix<T, R, C, S>,
+    keep: impl Fn(&T) -> bool,
+) -> CsrMatrix<T>
+where
+    T: Scalar,
+    R: Dim,
+    C: Dim,
+    S: RawStorage<T, R, C>,
 {
     let mut row_offsets = Vec::with_capacity(dense.nrows() + 1);
     let mut col_idx = Vec::new();
@@ -110,7 +164,7 @@ where
     for i in 0..dense.nrows() {
         for j in 0..dense.ncols() {
             let v = dense.index((i, j));
-            if v != &T::zero() {
+            if keep(v) {
                 col_idx.push(j);
                 values.push(v.clone());
             }
@@ -175,6 +229,33 @@ where
     R: Dim,
     C: Dim,
     S: RawStorage<T, R, C>,
+{
+    convert_dense_csc_filtered(dense, |v| v != &T::zero())
+}
+
+/// Converts a dense matrix to a [`CscMatrix`], dropping entries whose absolute value is not
+/// strictly greater than `tol`.
+///
+/// With `tol = 0`, all non-zero entries are kept.
+pub fn convert_dense_csc_with_tol<T, R, C, S>(dense: &Matrix<T, R, C, S>, tol: T) -> CscMatrix<T>
+where
+    T: RealField,
+    R: Dim,
+    C: Dim,
+    S: RawStorage<T, R, C>,
+{
+    convert_dense_csc_filtered(dense, |v| v.clone().abs() > tol)
+}
+
+fn convert_dense_csc_filtered<T, R, C, S>(
+    dense: &Matrix<T, R, C, S>,
+    keep: impl Fn(&T) -> bool,
+) -> CscMatrix<T>
+where
+    T: Scalar,
+    R: Dim,
+    C: Dim,
+    S: RawStorage<T, R, C>,
 {
     let mut col_offsets = Vec::with_capacity(dense.ncols() + 1);
     let mut row_idx = Vec::new();
@@ -184,7 +265,7 @@ where
     for j in 0..dense.ncols() {
         for i in 0..dense.nrows() {
             let v = dense.index((i, j));
-            if v != &T::zero() {
+            if keep(v) {
                 row_idx.push(i);
                 values.push(v.clone());
             }
@@ -3,7 +3,10 @@
 #[cfg(feature = "serde-serialize")]
 mod coo_serde;
 
+use crate::convert::serial::convert_dense_coo_with_tol;
 use crate::SparseFormatError;
+use nalgebra::storage::RawStorage;
+use nalgebra::{Dim, Matrix, RealField};
 
 /// A COO representation of a sparse matrix.
 ///
@@ -83,6 +86,72 @@ impl<T: na::Scalar> CooMatrix<T> {
             }
         }
     }
+
+    /// Pushes a dense matrix into the sparse one, scattering its entries to the given global
+    /// row and column indices.
+    ///
+    /// The entry `m[(i, j)]` is added at position `(rows[i], cols[j])` of the sparse matrix.
+    /// This is the core operation of finite element assembly, where a dense element matrix is
+    /// added to the global matrix according to a map from local to global degrees of freedom.
+    /// The indices need not be sorted nor contiguous.
+    ///
+    /// Panics
+    /// ------
+    ///
+    /// Panics if the length of `rows` (resp. `cols`) does not match the number of rows
+    /// (resp. columns) of `m`, or if any of the indices is out of bounds.
+    ///
+    /// Examples
+    /// --------
+    ///
+    /// ```
+    /// # use nalgebra::{DMatrix, Matrix2};
+    /// # use nalgebra_sparse::coo::CooMatrix;
+    /// let mut coo = CooMatrix::new(4, 4);
+    /// let element = Matrix2::new(1.0, -1.0,
+    ///                            -1.0, 1.0);
+    /// coo.push_matrix_scattered(&[0, 3], &[0, 3], &element);
+    /// coo.push_matrix_scattered(&[3, 2], &[3, 2], &element);
+    ///
+    /// let dense = DMatrix::from(&coo);
+    /// assert_eq!(dense[(3, 3)], 2.0);
+    /// assert_eq!(dense[(0, 3)], -1.0);
+    /// assert_eq!(dense[(2, 3)], -1.0);
+    /// ```
+    #[inline]
+    pub fn push_matrix_scattered<
+        R: na::Dim,
+        C: na::Dim,
+        S: nalgebra::storage::RawStorage<T, R, C>,
+    >(
+        &mut self,
+        rows: &[usize],
+        cols: &[usize],
+        m: &na::Matrix<T, R, C, S>,
+    ) {
+        assert_eq!(
+            rows.len(),
+            m.nrows(),
+            "The number of row indices must match the number of rows of the block."
+        );
+        assert_eq!(
+            cols.len(),
+            m.ncols(),
+            "The number of column indices must match the number of columns of the block."
+        );
+        assert!(rows.iter().all(|&i| i < self.nrows));
+        assert!(cols.iter().all(|&j| j < self.ncols));
+
+        self.reserve(m.nrows() * m.ncols());
+
+        for (col, &j) in m.column_iter().zip(cols) {
+            for (v, &i) in col.iter().zip(rows) {
+                self.row_indices.push(i);
+                self.col_indices.push(j);
+                self.values.push(v.clone());
+            }
+        }
+    }
 }
 
 impl<T> CooMatrix<T> {
@@ -110,6 +179,33 @@ impl<T> CooMatrix<T> {
         Self::new(nrows, ncols)
     }
 
+    /// Constructs a COO matrix from a dense matrix, keeping only the entries whose absolute value
+    /// is strictly greater than `tol`.
+    ///
+    /// With `tol = 0`, all non-zero entries are kept. Note that NaN entries are always dropped,
+    /// since they do not compare greater than `tol`, whereas converting with [`From`] keeps them.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use nalgebra::DMatrix;
+    /// use nalgebra_sparse::coo::CooMatrix;
+    ///
+    /// let dense = DMatrix::from_row_slice(2, 2, &[1.0, 1.0e-12, -1.0e-9, 2.0]);
+    /// let sparse = CooMatrix::from_dense_with_tol(&dense, 1.0e-6);
+    /// assert_eq!(sparse.nnz(), 2);
+    /// assert_eq!(DMatrix::from(&sparse), DMatrix::from_row_slice(2, 2, &[1.0, 0.0, 0.0, 2.0]));
+    /// ```
+    pub fn from_dense_with_tol<R, C, S>(dense: &Matrix<T, R, C, S>, tol: T) -> Self
+    where
+        T: RealField,
+        R: Dim,
+        C: Dim,
+        S: RawStorage<T, R, C>,
+    {
+        convert_dense_coo_with_tol(dense, tol)
+    }
+
     /// Try to construct a COO matrix from the given dimensions and a collection of
     /// (i, j, v) triplets.
     ///
@@ -215,6 +215,61 @@ impl<T> CsMatrix<T> {
         Self::from_pattern_and_values(new_pattern, new_values)
     }
 
+    /// Returns the block of the matrix with major and minor indices in the given ranges,
+    /// re-indexed so that the block starts at `(0, 0)`.
+    ///
+    /// Panics if either range is decreasing or out of bounds.
+    #[must_use]
+    pub fn submatrix(&self, major_range: Range<usize>, minor_range: Range<usize>) -> Self
+    where
+        T: Clone,
+    {
+        let (major_dim, minor_dim) = (self.pattern().major_dim(), self.pattern().minor_dim());
+        assert!(
+            major_range.start <= major_range.end && major_range.end <= major_dim,
+            "Major range is out of bounds."
+        );
+        assert!(
+            minor_range.start <= minor_range.end && minor_range.end <= minor_dim,
+            "Minor range is out of bounds."
+        );
+
+        let mut new_offsets = Vec::with_capacity(major_range.len() + 1);
+        let mut new_indices = Vec::new();
+        let mut new_values = Vec::new();
+
+        new_offsets.push(0);
+        for i in major_range {
+            let lane = self.get_lane(i).unwrap();
+            // Minor indices are sorted within each lane, so the entries in range are contiguous
+            let begin = lane
+                .minor_indices()
+                .partition_point(|&j| j < minor_range.start);
+            let end = lane
+                .minor_indices()
+                .partition_point(|&j| j < minor_range.end);
+
+            new_indices.extend(
+                lane.minor_indices()[begin..end]
+                    .iter()
+                    .map(|j| j - minor_range.start),
+            );
+            new_values.extend_from_slice(&lane.values()[begin..end]);
+            new_offsets.push(new_indices.len());
+        }
+
+        // TODO: Avoid checks here
+        let new_pattern = SparsityPattern::try_from_offsets_and_indices(
+            new_offsets.len() - 1,
+            minor_range.len(),
+            new_offsets,
+            new_indices,
+        )
+        .expect("Internal error: Sparsity pattern must always be valid.");
+
+        Self::from_pattern_and_values(new_pattern, new_values)
+    }
+
     /// Returns the diagonal of the matrix as a sparse matrix.
     #[must_use]
     pub fn diagonal_as_matrix(&self) -> Self
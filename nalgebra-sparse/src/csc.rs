@@ -6,14 +6,17 @@
 #[cfg(feature = "serde-serialize")]
 mod csc_serde;
 
+use crate::convert::serial::convert_dense_csc_with_tol;
 use crate::cs;
 use crate::cs::{CsLane, CsLaneIter, CsLaneIterMut, CsLaneMut, CsMatrix};
 use crate::csr::CsrMatrix;
 use crate::pattern::{SparsityPattern, SparsityPatternFormatError, SparsityPatternIter};
 use crate::{SparseEntry, SparseEntryMut, SparseFormatError, SparseFormatErrorKind};
 
-use nalgebra::Scalar;
+use nalgebra::storage::RawStorage;
+use nalgebra::{Dim, Matrix, RealField, Scalar};
 use num_traits::One;
+use std::ops::Range;
 use std::slice::{Iter, IterMut};
 
 /// A CSC representation of a sparse matrix.
@@ -149,6 +152,34 @@ impl<T> CscMatrix<T> {
         }
     }
 
+    /// Constructs a CSC matrix from a dense matrix, keeping only the entries whose absolute value
+    /// is strictly greater than `tol`.
+    ///
+    /// This is useful to build a sparse approximation of a dense matrix with many tiny entries.
+    /// With `tol = 0`, all non-zero entries are kept. Note that NaN entries are always dropped,
+    /// since they do not compare greater than `tol`, whereas converting with [`From`] keeps them.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use nalgebra::DMatrix;
+    /// use nalgebra_sparse::csc::CscMatrix;
+    ///
+    /// let dense = DMatrix::from_row_slice(2, 2, &[1.0, 1.0e-12, -1.0e-9, 2.0]);
+    /// let sparse = CscMatrix::from_dense_with_tol(&dense, 1.0e-6);
+    /// assert_eq!(sparse.nnz(), 2);
+    /// assert_eq!(DMatrix::from(&sparse), DMatrix::from_row_slice(2, 2, &[1.0, 0.0, 0.0, 2.0]));
+    /// ```
+    pub fn from_dense_with_tol<R, C, S>(dense: &Matrix<T, R, C, S>, tol: T) -> Self
+    where
+        T: RealField,
+        R: Dim,
+        C: Dim,
+        S: RawStorage<T, R, C>,
+    {
+        convert_dense_csc_with_tol(dense, tol)
+    }
+
     /// Try to construct a CSC matrix from raw CSC data.
     ///
     /// It is assumed that each column contains unique and sorted row indices that are in
@@ -501,6 +532,23 @@ impl<T> CscMatrix<T> {
     }
 
     /// Returns a triplet of slices `(col_offsets, row_indices, values)` that make up the CSC data.
+    ///
+    /// These are the arrays commonly used to exchange CSC matrices with other libraries,
+    /// e.g. the `(indptr, indices, data)` arrays of SciPy. Use
+    /// [`disassemble`](Self::disassemble) to take ownership of them instead, and
+    /// [`try_from_csc_data`](Self::try_from_csc_data) to construct a matrix from them.
+    ///
+    /// Examples
+    /// --------
+    ///
+    /// ```
+    /// # use nalgebra_sparse::csc::CscMatrix;
+    /// let csc = CscMatrix::try_from_csc_data(4, 3, vec![0, 2, 3, 4], vec![0, 2, 1, 0], vec![1, 3, 2, 4]).unwrap();
+    /// let (col_offsets, row_indices, values) = csc.csc_data();
+    /// assert_eq!(col_offsets, &[0, 2, 3, 4]);
+    /// assert_eq!(row_indices, &[0, 2, 1, 0]);
+    /// assert_eq!(values, &[1, 3, 2, 4]);
+    /// ```
     #[must_use]
     pub fn csc_data(&self) -> (&[usize], &[usize], &[T]) {
         self.cs.cs_data()
@@ -551,6 +599,75 @@ impl<T> CscMatrix<T> {
         self.filter(|i, j, _| i >= j)
     }
 
+    /// Returns a new matrix representing the strictly upper triangular part of this matrix.
+    ///
+    /// Unlike [`upper_triangle`](Self::upper_triangle), the result excludes the diagonal of the
+    /// matrix.
+    #[must_use]
+    pub fn strictly_upper_triangle(&self) -> Self
+    where
+        T: Clone,
+    {
+        self.filter(|i, j, _| i < j)
+    }
+
+    /// Returns a new matrix representing the strictly lower triangular part of this matrix.
+    ///
+    /// Unlike [`lower_triangle`](Self::lower_triangle), the result excludes the diagonal of the
+    /// matrix.
+    #[must_use]
+    pub fn strictly_lower_triangle(&self) -> Self
+    where
+        T: Clone,
+    {
+        self.filter(|i, j, _| i > j)
+    }
+
+    /// Extracts the block of the matrix with rows in `rows` and columns in `cols`.
+    ///
+    /// The explicitly stored entries falling inside the block are copied into a new
+    /// `rows.len() x cols.len()` matrix, with indices shifted so that the entry at
+    /// `(rows.start, cols.start)` of this matrix becomes the entry at `(0, 0)` of the block.
+    /// Empty ranges produce a matrix with zero rows and/or columns.
+    ///
+    /// Panics
+    /// ------
+    /// Panics if `rows` or `cols` is out of bounds, or if its start exceeds its end.
+    ///
+    /// Examples
+    /// --------
+    ///
+    /// ```
+    /// # use nalgebra::DMatrix;
+    /// # use nalgebra_sparse::csc::CscMatrix;
+    /// let dense = DMatrix::from_row_slice(3, 4, &[
+    ///     1.0, 0.0, 2.0, 0.0,
+    ///     0.0, 3.0, 0.0, 4.0,
+    ///     5.0, 0.0, 6.0, 0.0,
+    /// ]);
+    /// let csc = CscMatrix::from(&dense);
+    /// let block = csc.submatrix(1..3, 1..4);
+    /// assert_eq!(DMatrix::from(&block), dense.slice((1, 1), (2, 3)));
+    /// ```
+    #[must_use]
+    pub fn submatrix(&self, rows: Range<usize>, cols: Range<usize>) -> Self
+    where
+        T: Clone,
+    {
+        assert!(
+            rows.start <= rows.end && rows.end <= self.nrows(),
+            "Row range is out of bounds."
+        );
+        assert!(
+            cols.start <= cols.end && cols.end <= self.ncols(),
+            "Column range is out of bounds."
+        );
+
+        Self {
+            cs: self.cs.submatrix(cols, rows),
+        }
+    }
+
     /// Returns the diagonal of the matrix as a sparse matrix.
     #[must_use]
     pub fn diagonal_as_csc(&self) -> Self
@@ -78,11 +78,19 @@ pub struct CscCholesky<T> {
 pub enum CholeskyError {
     /// The matrix is not positive definite.
     NotPositiveDefinite,
+    /// The sparsity pattern of the matrix differs from the one that was symbolically factored.
+    PatternMismatch,
 }
 
 impl Display for CholeskyError {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        write!(f, "Matrix is not positive definite")
+        match self {
+            CholeskyError::NotPositiveDefinite => write!(f, "Matrix is not positive definite"),
+            CholeskyError::PatternMismatch => write!(
+                f,
+                "Matrix sparsity pattern does not match the factored sparsity pattern"
+            ),
+        }
     }
 }
 
@@ -171,6 +179,27 @@ impl<T: RealField> CscCholesky<T> {
         self.decompose_left_looking(values)
     }
 
+    /// Re-computes the factorization for a matrix with the same sparsity pattern as the one
+    /// initially factored, but with different values.
+    ///
+    /// The symbolic factorization (i.e. the sparsity pattern of `L`) is reused, and only the
+    /// numerical factor is recomputed. This is useful when the same kind of system is solved
+    /// repeatedly with changing values, e.g. in Newton iterations.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CholeskyError::PatternMismatch`] if the sparsity pattern of `matrix` differs from
+    /// the pattern that was symbolically factored, in which case `self` is left unchanged.
+    /// Otherwise, returns an error if the numerical factorization fails. This can occur if the
+    /// matrix is not symmetric positive definite.
+    pub fn factor_numeric(&mut self, matrix: &CscMatrix<T>) -> Result<(), CholeskyError> {
+        if matrix.pattern() != &self.m_pattern {
+            return Err(CholeskyError::PatternMismatch);
+        }
+
+        self.refactor(matrix.values())
+    }
+
     /// Returns a reference to the Cholesky factor `L`.
     #[must_use]
     pub fn l(&self) -> &CscMatrix<T> {
@@ -182,6 +211,42 @@ impl<T: RealField> CscCholesky<T> {
         self.l_factor
     }
 
+    /// Computes the determinant of the factored matrix.
+    ///
+    /// Since `A = L L^T`, this is the product of the squared diagonal entries of `L`. The result
+    /// is only meaningful if the last (re-)factorization succeeded.
+    ///
+    /// For large matrices, the determinant easily overflows or underflows. Consider using
+    /// [`ln_determinant`](Self::ln_determinant) instead.
+    #[must_use]
+    pub fn determinant(&self) -> T {
+        let det = self
+            .l_diagonal()
+            .fold(T::one(), |acc, l_kk| acc * l_kk.clone());
+        det.clone() * det
+    }
+
+    /// Computes the natural logarithm of the determinant of the factored matrix.
+    ///
+    /// Since `A = L L^T`, this is twice the sum of the logarithms of the diagonal entries of
+    /// `L`. The result is only meaningful if the last (re-)factorization succeeded.
+    #[must_use]
+    pub fn ln_determinant(&self) -> T {
+        let sum = self
+            .l_diagonal()
+            .fold(T::zero(), |acc, l_kk| acc + l_kk.clone().ln());
+        sum.clone() + sum
+    }
+
+    /// Iterates through the diagonal entries of `L`.
+    fn l_diagonal(&self) -> impl Iterator<Item = &T> {
+        // The diagonal entry is always the first explicitly stored entry of each column of `L`
+        let (offsets, _, values) = self.l_factor.csc_data();
+        offsets[..offsets.len() - 1]
+            .iter()
+            .map(move |&o| &values[o])
+    }
+
     /// Perform a numerical left-looking cholesky decomposition of a matrix with the same structure as the
     /// one used to initialize `self`, but with different non-zero values provided by `values`.
     fn decompose_left_looking(&mut self, values: &[T]) -> Result<(), CholeskyError> {
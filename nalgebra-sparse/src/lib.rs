@@ -24,6 +24,8 @@
 //!   `proptest-support` is enabled.
 //! - [matrixcompare support](https://crates.io/crates/matrixcompare) for effortless
 //!   (approximate) comparison of matrices in test code (requires the `compare` feature).
+//! - Implementations of the [approx](https://crates.io/crates/approx) traits, so that sparse
+//!   matrices with the same sparsity pattern can be compared with e.g. `assert_relative_eq!`.
 //!
 //! ## Current state
 //!
@@ -166,6 +168,8 @@ pub mod proptest;
 #[cfg(feature = "compare")]
 mod matrixcompare;
 
+mod approx_eq;
+
 use num_traits::Zero;
 use std::error::Error;
 use std::fmt;
@@ -11,7 +11,7 @@ use nalgebra::base::storage::RawStorage;
 use nalgebra::constraint::{DimEq, ShapeConstraint};
 use nalgebra::{
     ClosedAdd, ClosedDiv, ClosedMul, ClosedSub, DefaultAllocator, Dim, Dynamic, Matrix, OMatrix,
-    Scalar, U1,
+    Scalar,
 };
 use num_traits::{One, Zero};
 use std::ops::{Add, Div, DivAssign, Mul, MulAssign, Neg, Sub};
@@ -306,11 +306,11 @@ macro_rules! impl_spmm_cs_dense {
             // TODO: Is it possible to simplify these bounds?
             ShapeConstraint:
                 // Bounds so that we can turn OMatrix<T, Dynamic, C> into a DMatrixSliceMut
-                  DimEq<U1, <<DefaultAllocator as Allocator<T, Dynamic, C>>::Buffer as RawStorage<T, Dynamic, C>>::RStride>
+                  DimEq<Dynamic, <<DefaultAllocator as Allocator<T, Dynamic, C>>::Buffer as RawStorage<T, Dynamic, C>>::RStride>
                 + DimEq<C, Dynamic>
                 + DimEq<Dynamic, <<DefaultAllocator as Allocator<T, Dynamic, C>>::Buffer as RawStorage<T, Dynamic, C>>::CStride>
-                // Bounds so that we can turn &Matrix<T, R, C, S> into a DMatrixSlice
-                + DimEq<U1, S::RStride>
+                // Bounds so that we can turn &Matrix<T, R, C, S> into a (possibly strided) DMatrixSlice
+                + DimEq<Dynamic, S::RStride>
                 + DimEq<R, Dynamic>
                 + DimEq<Dynamic, S::CStride>
         {
@@ -1,9 +1,11 @@
 use crate::cs::CsMatrix;
-use crate::ops::serial::{OperationError, OperationErrorKind};
+use crate::ops::serial::{spadd_pattern, OperationError, OperationErrorKind};
 use crate::ops::Op;
+use crate::pattern::SparsityPattern;
 use crate::SparseEntryMut;
-use nalgebra::{ClosedAdd, ClosedMul, DMatrixSlice, DMatrixSliceMut, Scalar};
+use nalgebra::{ClosedAdd, ClosedMul, DMatrixSlice, DMatrixSliceMut, Dynamic, Scalar};
 use num_traits::{One, Zero};
+use std::borrow::Cow;
 
 fn spmm_cs_unexpected_entry() -> OperationError {
     OperationError::from_kind_and_message(
@@ -111,6 +113,20 @@ fn spadd_cs_unexpected_entry() -> OperationError {
     )
 }
 
+/// Helper functionality for implementing allocating CSR/CSC SPADD.
+///
+/// Builds the pattern of `op(A) + op(B)`.
+pub fn spadd_cs_pattern<T>(a: Op<&CsMatrix<T>>, b: Op<&CsMatrix<T>>) -> SparsityPattern {
+    fn op_pattern<T>(m: Op<&CsMatrix<T>>) -> Cow<'_, SparsityPattern> {
+        match m {
+            Op::NoOp(m) => Cow::Borrowed(m.pattern()),
+            Op::Transpose(m) => Cow::Owned(m.pattern().transpose()),
+        }
+    }
+
+    spadd_pattern(&op_pattern(a), &op_pattern(b))
+}
+
 /// Helper functionality for implementing CSR/CSC SPADD.
 pub fn spadd_cs_prealloc<T>(
     beta: T,
@@ -176,10 +192,10 @@ where
 /// the transposed operation must be specified for the CSC matrix.
 pub fn spmm_cs_dense<T>(
     beta: T,
-    mut c: DMatrixSliceMut<'_, T>,
+    mut c: DMatrixSliceMut<'_, T, Dynamic, Dynamic>,
     alpha: T,
     a: Op<&CsMatrix<T>>,
-    b: Op<DMatrixSlice<'_, T>>,
+    b: Op<DMatrixSlice<'_, T, Dynamic, Dynamic>>,
 ) where
     T: Scalar + ClosedAdd + ClosedMul + Zero + One,
 {
@@ -1,25 +1,30 @@
 use crate::csc::CscMatrix;
 use crate::ops::serial::cs::{
-    spadd_cs_prealloc, spmm_cs_dense, spmm_cs_prealloc, spmm_cs_prealloc_unchecked,
+    spadd_cs_pattern, spadd_cs_prealloc, spmm_cs_dense, spmm_cs_prealloc,
+    spmm_cs_prealloc_unchecked,
 };
 use crate::ops::serial::{OperationError, OperationErrorKind};
 use crate::ops::Op;
-use nalgebra::{ClosedAdd, ClosedMul, DMatrixSlice, DMatrixSliceMut, RealField, Scalar};
+use nalgebra::{ClosedAdd, ClosedMul, DMatrixSlice, DMatrixSliceMut, Dynamic, RealField, Scalar};
 use num_traits::{One, Zero};
 
 use std::borrow::Cow;
 
 /// Sparse-dense matrix-matrix multiplication `C <- beta * C + alpha * op(A) * op(B)`.
 ///
+/// The dense matrices `B` and `C` may be views with arbitrary strides, e.g. a column of a larger
+/// matrix or a slice with steps. In particular, this can be used for sparse matrix-vector
+/// products without copying the vectors out of their storage.
+///
 /// # Panics
 ///
 /// Panics if the dimensions of the matrices involved are not compatible with the expression.
 pub fn spmm_csc_dense<'a, T>(
     beta: T,
-    c: impl Into<DMatrixSliceMut<'a, T>>,
+    c: impl Into<DMatrixSliceMut<'a, T, Dynamic, Dynamic>>,
     alpha: T,
     a: Op<&CscMatrix<T>>,
-    b: Op<impl Into<DMatrixSlice<'a, T>>>,
+    b: Op<impl Into<DMatrixSlice<'a, T, Dynamic, Dynamic>>>,
 ) where
     T: Scalar + ClosedAdd + ClosedMul + Zero + One,
 {
@@ -29,10 +34,10 @@ pub fn spmm_csc_dense<'a, T>(
 
 fn spmm_csc_dense_<T>(
     beta: T,
-    c: DMatrixSliceMut<'_, T>,
+    c: DMatrixSliceMut<'_, T, Dynamic, Dynamic>,
     alpha: T,
     a: Op<&CscMatrix<T>>,
-    b: Op<DMatrixSlice<'_, T>>,
+    b: Op<DMatrixSlice<'_, T, Dynamic, Dynamic>>,
 ) where
     T: Scalar + ClosedAdd + ClosedMul + Zero + One,
 {
@@ -63,6 +68,29 @@ where
     spadd_cs_prealloc(beta, &mut c.cs, alpha, a.map_same_op(|a| &a.cs))
 }
 
+/// Sparse matrix addition `C = alpha * op(A) + beta * op(B)`.
+///
+/// Unlike [`spadd_csc_prealloc`], the sparsity pattern of the result is computed on the fly, as
+/// the union of the patterns of `op(A)` and `op(B)`. Entries that cancel out are kept in the
+/// pattern as explicit zeros.
+///
+/// # Panics
+///
+/// Panics if `op(A)` and `op(B)` do not have the same dimensions.
+pub fn spadd_csc<T>(alpha: T, a: Op<&CscMatrix<T>>, beta: T, b: Op<&CscMatrix<T>>) -> CscMatrix<T>
+where
+    T: Scalar + ClosedAdd + ClosedMul + Zero + One,
+{
+    let pattern = spadd_cs_pattern(a.map_same_op(|a| &a.cs), b.map_same_op(|b| &b.cs));
+    let values = vec![T::zero(); pattern.nnz()];
+    // The pattern is valid by construction and the values have the right length
+    let mut c = CscMatrix::try_from_pattern_and_values(pattern, values).unwrap();
+    // The pattern of `c` accommodates both `op(a)` and `op(b)`, so these cannot fail
+    spadd_csc_prealloc(T::zero(), &mut c, alpha, a).unwrap();
+    spadd_csc_prealloc(T::one(), &mut c, beta, b).unwrap();
+    c
+}
+
 /// Sparse-sparse matrix multiplication, `C <- beta * C + alpha * op(A) * op(B)`.
 ///
 /// # Errors
@@ -98,6 +98,82 @@ impl SparsityPattern {
         self.minor_indices.len()
     }
 
+    /// The number of explicitly stored entries in each major lane.
+    ///
+    /// For the sparsity pattern of a CSR matrix, this is the number of non-zeros in each row.
+    /// For a CSC matrix, it is the number of non-zeros in each column.
+    ///
+    /// Examples
+    /// --------
+    ///
+    /// ```
+    /// # use nalgebra_sparse::pattern::SparsityPattern;
+    /// let offsets = vec![0, 2, 3, 3];
+    /// let minor_indices = vec![0, 2, 1];
+    /// let pattern = SparsityPattern::try_from_offsets_and_indices(3, 4, offsets, minor_indices)
+    ///     .unwrap();
+    /// assert_eq!(pattern.nnz_per_lane(), vec![2, 1, 0]);
+    /// ```
+    #[must_use]
+    pub fn nnz_per_lane(&self) -> Vec<usize> {
+        self.major_offsets
+            .windows(2)
+            .map(|offsets| offsets[1] - offsets[0])
+            .collect()
+    }
+
+    /// The largest number of explicitly stored entries in a single major lane.
+    ///
+    /// Returns zero if the pattern has no major lanes.
+    #[must_use]
+    pub fn max_nnz_in_lane(&self) -> usize {
+        self.major_offsets
+            .windows(2)
+            .map(|offsets| offsets[1] - offsets[0])
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// The bandwidth of the pattern, i.e. the largest value of `|i - j|` over all explicitly
+    /// stored entries `(i, j)`.
+    ///
+    /// The bandwidth is invariant under transposition, so it is the same regardless of whether
+    /// the pattern is interpreted as that of a CSR or a CSC matrix. Returns zero if the pattern
+    /// has no explicitly stored entries.
+    ///
+    /// Examples
+    /// --------
+    ///
+    /// ```
+    /// # use nalgebra_sparse::pattern::SparsityPattern;
+    /// // A tridiagonal pattern has bandwidth 1
+    /// let offsets = vec![0, 2, 5, 7];
+    /// let minor_indices = vec![0, 1, 0, 1, 2, 1, 2];
+    /// let pattern = SparsityPattern::try_from_offsets_and_indices(3, 3, offsets, minor_indices)
+    ///     .unwrap();
+    /// assert_eq!(pattern.bandwidth(), 1);
+    /// ```
+    #[must_use]
+    pub fn bandwidth(&self) -> usize {
+        self.entries()
+            .map(|(i, j)| i.abs_diff(j))
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// The fraction of explicitly stored entries, i.e. `nnz / (major_dim * minor_dim)`.
+    ///
+    /// Returns zero if either dimension of the pattern is zero.
+    #[must_use]
+    pub fn density(&self) -> f64 {
+        let capacity = self.major_dim() * self.minor_dim();
+        if capacity == 0 {
+            0.0
+        } else {
+            self.nnz() as f64 / capacity as f64
+        }
+    }
+
     /// Get the lane at the given index.
     ///
     /// Panics
@@ -1,7 +1,7 @@
 #![cfg_attr(rustfmt, rustfmt_skip)]
 use crate::common::{value_strategy, PROPTEST_MATRIX_DIM, PROPTEST_MAX_NNZ};
 use nalgebra_sparse::csc::CscMatrix;
-use nalgebra_sparse::factorization::{CscCholesky};
+use nalgebra_sparse::factorization::{CscCholesky, CholeskyError};
 use nalgebra_sparse::proptest::csc;
 use nalgebra::{Matrix5, Vector5, Cholesky, DMatrix};
 use nalgebra::proptest::matrix;
@@ -60,6 +60,54 @@ proptest! {
         }
     }
 
+    #[test]
+    fn cholesky_determinant_matches_dense(matrix in positive_definite()) {
+        let cholesky = CscCholesky::factor(&matrix).unwrap();
+        let dense_det = DMatrix::from(&matrix).determinant();
+
+        prop_assert!((cholesky.determinant() - dense_det).abs() <= 1e-8 * dense_det.abs());
+        prop_assert!((cholesky.ln_determinant() - dense_det.ln()).abs() <= 1e-8 * dense_det.ln().abs().max(1.0));
+    }
+
+    #[test]
+    fn cholesky_factor_numeric_reuses_pattern(
+        (matrix, scale) in (positive_definite(), 0.5 ..= 4.0)
+    ) {
+        let mut cholesky = CscCholesky::factor(&matrix).unwrap();
+
+        // Scaling the values preserves both the sparsity pattern and positive definiteness
+        let mut scaled = matrix.clone();
+        scaled.values_mut().iter_mut().for_each(|v| *v *= scale);
+        prop_assert!(cholesky.factor_numeric(&scaled).is_ok());
+
+        let from_scratch = CscCholesky::factor(&scaled).unwrap();
+        prop_assert_eq!(cholesky.l().pattern(), from_scratch.l().pattern());
+        prop_assert_matrix_eq!(cholesky.l(), from_scratch.l(), comp = abs, tol = 1e-12);
+
+        // Refactoring with the original values gives back the original factorization
+        prop_assert!(cholesky.factor_numeric(&matrix).is_ok());
+        let original = CscCholesky::factor(&matrix).unwrap();
+        prop_assert_matrix_eq!(cholesky.l(), original.l(), comp = abs, tol = 1e-12);
+    }
+}
+
+#[test]
+fn cholesky_factor_numeric_pattern_mismatch() {
+    let matrix = CscMatrix::from(&Matrix5::from_diagonal(&Vector5::new(4.0, 3.0, 2.0, 1.0, 5.0)));
+    let mut cholesky = CscCholesky::factor(&matrix).unwrap();
+    let l = cholesky.l().clone();
+
+    let mut a = Matrix5::from_diagonal(&Vector5::new(4.0, 3.0, 2.0, 1.0, 5.0));
+    a[(3, 0)] = 0.5;
+    a[(0, 3)] = 0.5;
+    let other = CscMatrix::from(&a);
+
+    assert_eq!(cholesky.factor_numeric(&other), Err(CholeskyError::PatternMismatch));
+    // The factorization is left untouched
+    assert_eq!(cholesky.l(), &l);
+
+    assert_eq!(cholesky.factor_numeric(&(&matrix * 2.0)), Ok(()));
+    assert_matrix_eq!(cholesky.l(), &l * 2.0f64.sqrt(), comp = abs, tol = 1e-12);
 }
 
 // This is a test ported from nalgebra's "sparse" module, for the original CsCholesky impl
@@ -114,4 +162,10 @@ fn test_cholesky(a: Matrix5<f64>) {
     let l = DMatrix::from_iterator(l.nrows(), l.ncols(), l.iter().cloned());
     let cs_l_mat = DMatrix::from(&cs_l);
     assert_matrix_eq!(l, cs_l_mat, comp = abs, tol = 1e-12);
+
+    let chol_cs_a = CscCholesky::factor(&cs_a).unwrap();
+    let det = a.determinant();
+    assert!((chol_cs_a.determinant() - det).abs() <= 1e-10 * det.abs().max(1.0));
+    let ln_det = det.ln();
+    assert!((chol_cs_a.ln_determinant() - ln_det).abs() <= 1e-10 * ln_det.abs().max(1.0));
 }
\ No newline at end of file
@@ -4,7 +4,8 @@ use nalgebra::DMatrix;
 use nalgebra_sparse::convert::serial::{
     convert_coo_csc, convert_coo_csr, convert_coo_dense, convert_csc_coo, convert_csc_csr,
     convert_csc_dense, convert_csr_coo, convert_csr_csc, convert_csr_dense, convert_dense_coo,
-    convert_dense_csc, convert_dense_csr,
+    convert_dense_coo_with_tol, convert_dense_csc, convert_dense_csc_with_tol, convert_dense_csr,
+    convert_dense_csr_with_tol,
 };
 use nalgebra_sparse::coo::CooMatrix;
 use nalgebra_sparse::csc::CscMatrix;
@@ -277,6 +278,59 @@ fn test_convert_csc_dense_bidirectional() {
     assert_eq!(convert_dense_csc(&dense), csc);
 }
 
+#[test]
+fn test_convert_dense_cs_with_tol() {
+    #[rustfmt::skip]
+    let dense = DMatrix::from_row_slice(3, 4, &[
+        0.0,  5.0,   1e-9, 2.0,
+        2.0,  -1e-8, 0.0,  0.0,
+        1e-3, 1.0,   0.0,  -4.0
+    ]);
+    #[rustfmt::skip]
+    let expected_dense = DMatrix::from_row_slice(3, 4, &[
+        0.0, 5.0, 0.0, 2.0,
+        2.0, 0.0, 0.0, 0.0,
+        0.0, 1.0, 0.0, -4.0
+    ]);
+
+    let csr = convert_dense_csr_with_tol(&dense, 1e-3);
+    let csc = convert_dense_csc_with_tol(&dense, 1e-3);
+    let coo = convert_dense_coo_with_tol(&dense, 1e-3);
+    assert_eq!(csr.nnz(), 5);
+    assert_eq!(csc.nnz(), 5);
+    assert_eq!(coo.nnz(), 5);
+    assert_eq!(convert_csr_dense(&csr), expected_dense);
+    assert_eq!(convert_csc_dense(&csc), expected_dense);
+    assert_eq!(convert_coo_dense(&coo), expected_dense);
+    assert_eq!(CsrMatrix::from_dense_with_tol(&dense, 1e-3), csr);
+    assert_eq!(CscMatrix::from_dense_with_tol(&dense, 1e-3), csc);
+    assert_eq!(CooMatrix::from_dense_with_tol(&dense, 1e-3), coo);
+
+    // A zero tolerance keeps all non-zero entries
+    assert_eq!(
+        convert_dense_csr_with_tol(&dense, 0.0),
+        convert_dense_csr(&dense)
+    );
+    assert_eq!(
+        convert_dense_csc_with_tol(&dense, 0.0),
+        convert_dense_csc(&dense)
+    );
+    assert_eq!(
+        convert_dense_coo_with_tol(&dense, 0.0),
+        convert_dense_coo(&dense)
+    );
+    assert_eq!(convert_dense_csr_with_tol(&dense, 0.0).nnz(), 8);
+
+    // NaN entries are always dropped, unlike with the conversions without tolerance
+    let dense = DMatrix::from_row_slice(2, 2, &[1.0, f64::NAN, 0.0, -1.0]);
+    assert_eq!(CsrMatrix::from_dense_with_tol(&dense, 0.0).nnz(), 2);
+    assert_eq!(CscMatrix::from_dense_with_tol(&dense, 0.0).nnz(), 2);
+    assert_eq!(convert_dense_csr(&dense).nnz(), 3);
+    assert_eq!(convert_dense_csc(&dense).nnz(), 3);
+    assert_eq!(CooMatrix::from_dense_with_tol(&dense, 0.0).nnz(), 2);
+    assert_eq!(convert_dense_coo(&dense).nnz(), 3);
+}
+
 fn coo_strategy() -> impl Strategy<Value = CooMatrix<i32>> {
     coo_with_duplicates(-5..=5, 0..=6usize, 0..=6usize, 40, 2)
 }
@@ -449,4 +503,25 @@ proptest! {
     fn csr_from_csc_roundtrip(csc in csc_strategy()) {
         prop_assert_eq!(&csc, &CscMatrix::from(&CsrMatrix::from(&csc)));
     }
+
+    #[test]
+    fn convert_dense_cs_with_tol_drops_small_entries(
+        dense in matrix(-5..=5, 0..=6, 0..=6),
+        tol in 0..=5
+    ) {
+        let dense = dense.map(|v| v as f64);
+        let tol = tol as f64;
+        let expected = dense.map(|v| if v.abs() > tol { v } else { 0.0 });
+        let expected_nnz = expected.iter().filter(|v| **v != 0.0).count();
+
+        let csr = CsrMatrix::from_dense_with_tol(&dense, tol);
+        let csc = CscMatrix::from_dense_with_tol(&dense, tol);
+        let coo = CooMatrix::from_dense_with_tol(&dense, tol);
+        prop_assert_eq!(csr.nnz(), expected_nnz);
+        prop_assert_eq!(csc.nnz(), expected_nnz);
+        prop_assert_eq!(coo.nnz(), expected_nnz);
+        prop_assert_eq!(convert_csr_dense(&csr), expected.clone());
+        prop_assert_eq!(convert_csc_dense(&csc), expected.clone());
+        prop_assert_eq!(convert_coo_dense(&coo), expected);
+    }
 }
@@ -344,3 +344,72 @@ fn coo_push_matrix_out_of_bounds_entries() {
         assert_panics!(CooMatrix::new(3, 3).push_matrix(2, 2, &inserted));
     }
 }
+
+#[test]
+fn coo_push_matrix_scattered_valid_entries() {
+    let mut coo = CooMatrix::new(4, 5);
+
+    // new is row-major...
+    let inserted = nalgebra::SMatrix::<i32, 2, 3>::new(1, 2, 3, 4, 5, 6);
+    coo.push_matrix_scattered(&[3, 0], &[4, 1, 2], &inserted);
+
+    // insert happens column-major
+    assert_eq!(
+        coo.triplet_iter().collect::<Vec<_>>(),
+        vec![
+            (3, 4, &1),
+            (0, 4, &4),
+            (3, 1, &2),
+            (0, 1, &5),
+            (3, 2, &3),
+            (0, 2, &6)
+        ]
+    );
+
+    // Overlapping blocks are summed upon conversion, as in finite element assembly
+    let element = DMatrix::from_row_slice(2, 2, &[1, -1, -1, 1]);
+    let mut coo = CooMatrix::new(3, 3);
+    coo.push_matrix_scattered(&[0, 1], &[0, 1], &element);
+    coo.push_matrix_scattered(&[2, 1], &[2, 1], &element);
+    #[rustfmt::skip]
+    let expected = DMatrix::from_row_slice(3, 3, &[
+        1, -1,  0,
+       -1,  2, -1,
+        0, -1,  1,
+    ]);
+    assert_eq!(DMatrix::from(&coo), expected);
+
+    // Empty blocks push nothing
+    let mut coo = CooMatrix::<i32>::new(2, 2);
+    coo.push_matrix_scattered(&[], &[1], &DMatrix::zeros(0, 1));
+    assert_eq!(coo.nnz(), 0);
+}
+
+#[test]
+fn coo_push_matrix_scattered_invalid_entries() {
+    let inserted = nalgebra::SMatrix::<i32, 2, 2>::repeat(1);
+
+    // Mismatched index lengths
+    assert_panics!(CooMatrix::new(3, 3).push_matrix_scattered(&[0], &[0, 1], &inserted));
+    assert_panics!(CooMatrix::new(3, 3).push_matrix_scattered(&[0, 1], &[0, 1, 2], &inserted));
+
+    // Out of bounds indices
+    assert_panics!(CooMatrix::new(3, 3).push_matrix_scattered(&[0, 3], &[0, 1], &inserted));
+    assert_panics!(CooMatrix::new(3, 3).push_matrix_scattered(&[0, 1], &[3, 1], &inserted));
+}
+
+#[test]
+fn coo_approx_eq() {
+    use approx::{assert_relative_eq, relative_eq};
+
+    let a = CooMatrix::try_from_triplets(3, 3, vec![0, 2], vec![1, 0], vec![1.0, -2.0]).unwrap();
+    let b = CooMatrix::try_from_triplets(3, 3, vec![0, 2], vec![1, 0], vec![1.0, -2.0 + 1.0e-12])
+        .unwrap();
+    assert_relative_eq!(a, b, epsilon = 1.0e-10);
+
+    // Different dimensions or triplet positions are never approximately equal.
+    let c = CooMatrix::try_from_triplets(3, 4, vec![0, 2], vec![1, 0], vec![1.0, -2.0]).unwrap();
+    let d = CooMatrix::try_from_triplets(3, 3, vec![2, 0], vec![0, 1], vec![-2.0, 1.0]).unwrap();
+    assert!(!relative_eq!(a, c, epsilon = 1.0e-10));
+    assert!(!relative_eq!(a, d, epsilon = 1.0e-10));
+}
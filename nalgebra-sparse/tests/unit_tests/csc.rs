@@ -646,6 +646,28 @@ fn csc_matrix_col_iter() {
     }
 }
 
+#[test]
+fn csc_submatrix_edge_cases() {
+    let csc = CscMatrix::from(&DMatrix::from_row_slice(2, 3, &[1, 0, 2, 0, 3, 4]));
+
+    // Empty ranges produce matrices with zero rows and/or columns
+    assert_eq!(csc.submatrix(1..1, 0..3), CscMatrix::zeros(0, 3));
+    assert_eq!(csc.submatrix(0..2, 2..2), CscMatrix::zeros(2, 0));
+    assert_eq!(csc.submatrix(2..2, 3..3), CscMatrix::zeros(0, 0));
+
+    // The full range reproduces the matrix
+    assert_eq!(csc.submatrix(0..2, 0..3), csc);
+
+    // Out-of-bounds or decreasing ranges panic
+    assert_panics!(csc.submatrix(0..3, 0..3));
+    assert_panics!(csc.submatrix(0..2, 1..4));
+    #[allow(clippy::reversed_empty_ranges)]
+    {
+        assert_panics!(csc.submatrix(2..1, 0..3));
+        assert_panics!(csc.submatrix(0..2, 3..2));
+    }
+}
+
 proptest! {
     #[test]
     fn csc_double_transpose_is_identity(csc in csc_strategy()) {
@@ -701,6 +723,22 @@ proptest! {
         prop_assert!(csc_upper_triangle.nnz() <= csc.nnz());
     }
 
+    #[test]
+    fn csc_strictly_upper_triangle_agrees_with_dense(csc in csc_strategy()) {
+        let csc_strictly_upper = csc.strictly_upper_triangle();
+        prop_assert_eq!(DMatrix::from(&csc_strictly_upper),
+                        DMatrix::from(&csc).upper_triangle_with_offset(1));
+        prop_assert!(csc_strictly_upper.triplet_iter().all(|(i, j, _)| i < j));
+    }
+
+    #[test]
+    fn csc_strictly_lower_triangle_agrees_with_dense(csc in csc_strategy()) {
+        let csc_strictly_lower = csc.strictly_lower_triangle();
+        prop_assert_eq!(DMatrix::from(&csc_strictly_lower),
+                        DMatrix::from(&csc).lower_triangle_with_offset(-1));
+        prop_assert!(csc_strictly_lower.triplet_iter().all(|(i, j, _)| i > j));
+    }
+
     #[test]
     fn csc_diagonal_as_csc(csc in csc_strategy()) {
         let d = csc.diagonal_as_csc();
@@ -714,6 +752,25 @@ proptest! {
         prop_assert_eq!(d_entries, csc_diagonal_entries);
     }
 
+    #[test]
+    fn csc_submatrix_agrees_with_dense(
+        (csc, rows, cols) in csc_strategy()
+            .prop_flat_map(|matrix| {
+                let (nrows, ncols) = (matrix.nrows(), matrix.ncols());
+                let rows = (0 ..= nrows).prop_flat_map(move |start| (Just(start), start ..= nrows));
+                let cols = (0 ..= ncols).prop_flat_map(move |start| (Just(start), start ..= ncols));
+                (Just(matrix), rows, cols)
+            }))
+    {
+        let (rows, cols) = (rows.0 .. rows.1, cols.0 .. cols.1);
+        let submatrix = csc.submatrix(rows.clone(), cols.clone());
+        let dense = DMatrix::from(&csc);
+        let dense_submatrix = dense.slice((rows.start, cols.start), (rows.len(), cols.len()));
+
+        prop_assert_eq!(DMatrix::from(&submatrix), dense_submatrix);
+        prop_assert!(submatrix.nnz() <= csc.nnz());
+    }
+
     #[test]
     fn csc_identity(n in 0 ..= 6usize) {
         let csc = CscMatrix::<i32>::identity(n);
@@ -721,3 +778,27 @@ proptest! {
         prop_assert_eq!(DMatrix::from(&csc), DMatrix::identity(n, n));
     }
 }
+
+#[test]
+fn csc_matrix_relative_eq_ignoring_pattern() {
+    let dense = DMatrix::from_row_slice(3, 2, &[1.0, 0.0, 0.0, 2.0, 3.0, 0.0]);
+    let a = CscMatrix::from(&dense);
+
+    // The same matrix with an explicit zero in each column.
+    let b = CscMatrix::try_from_csc_data(
+        3,
+        2,
+        vec![0, 3, 5],
+        vec![0, 1, 2, 0, 1],
+        vec![1.0, 0.0, 3.0, 0.0, 2.0],
+    )
+    .unwrap();
+    assert_ne!(a.pattern(), b.pattern());
+    assert!(a.relative_eq_ignoring_pattern(&b, 1.0e-12, 1.0e-12));
+    assert!(b.relative_eq_ignoring_pattern(&a, 1.0e-12, 1.0e-12));
+
+    let mut c = b.clone();
+    c.values_mut()[3] = 1.0e-3;
+    assert!(!a.relative_eq_ignoring_pattern(&c, 1.0e-12, 1.0e-12));
+    assert!(a.relative_eq_ignoring_pattern(&c, 1.0e-2, 1.0e-12));
+}
@@ -640,6 +640,110 @@ fn csr_matrix_row_iter() {
     }
 }
 
+#[test]
+fn csr_matrix_approx_eq() {
+    use approx::{assert_relative_eq, relative_eq, ulps_eq};
+
+    let offsets = vec![0, 2, 3];
+    let indices = vec![0, 2, 1];
+    let a =
+        CsrMatrix::try_from_csr_data(2, 3, offsets.clone(), indices.clone(), vec![1.0, 2.0, 3.0])
+            .unwrap();
+    let b = CsrMatrix::try_from_csr_data(2, 3, offsets, indices, vec![1.0 + 1.0e-12, 2.0, 3.0])
+        .unwrap();
+
+    assert_ne!(a, b);
+    assert_relative_eq!(a, b, epsilon = 1.0e-10);
+    assert!(ulps_eq!(a, b, max_ulps = 10_000));
+    assert!(!relative_eq!(
+        a,
+        b,
+        epsilon = 1.0e-14,
+        max_relative = 1.0e-14
+    ));
+
+    // An explicitly stored zero is not approximately equal to an implicit zero.
+    let c = CsrMatrix::try_from_csr_data(
+        2,
+        3,
+        vec![0, 3, 4],
+        vec![0, 1, 2, 1],
+        vec![1.0, 0.0, 2.0, 3.0],
+    )
+    .unwrap();
+    assert!(!relative_eq!(a, c, epsilon = 1.0e-10));
+}
+
+#[test]
+fn csr_matrix_relative_eq_ignoring_pattern() {
+    let a = CsrMatrix::try_from_csr_data(2, 3, vec![0, 2, 3], vec![0, 2, 1], vec![1.0, 2.0, 3.0])
+        .unwrap();
+    assert!(a.relative_eq_ignoring_pattern(&a, 0.0, 0.0));
+
+    // Explicit zeros (and tiny values) compare equal to implicit zeros.
+    let b = CsrMatrix::try_from_csr_data(
+        2,
+        3,
+        vec![0, 3, 5],
+        vec![0, 1, 2, 0, 1],
+        vec![1.0 + 1.0e-12, 0.0, 2.0, 1.0e-12, 3.0],
+    )
+    .unwrap();
+    assert!(a.relative_eq_ignoring_pattern(&b, 1.0e-10, 1.0e-10));
+    assert!(b.relative_eq_ignoring_pattern(&a, 1.0e-10, 1.0e-10));
+    assert!(!a.relative_eq_ignoring_pattern(&b, 1.0e-14, 1.0e-14));
+
+    // A value missing from the other pattern is detected, in either order.
+    let c = CsrMatrix::try_from_csr_data(
+        2,
+        3,
+        vec![0, 2, 4],
+        vec![0, 2, 0, 1],
+        vec![1.0, 2.0, 0.5, 3.0],
+    )
+    .unwrap();
+    assert!(!a.relative_eq_ignoring_pattern(&c, 1.0e-10, 1.0e-10));
+    assert!(!c.relative_eq_ignoring_pattern(&a, 1.0e-10, 1.0e-10));
+
+    // A differing value at a common position is detected.
+    let d = CsrMatrix::try_from_csr_data(2, 3, vec![0, 1, 2], vec![2, 1], vec![2.0, 3.5]).unwrap();
+    assert!(!a.relative_eq_ignoring_pattern(&d, 1.0e-10, 1.0e-10));
+
+    // Matrices with different dimensions are never equal.
+    assert!(!CsrMatrix::<f64>::zeros(2, 3).relative_eq_ignoring_pattern(
+        &CsrMatrix::zeros(3, 2),
+        1.0,
+        1.0
+    ));
+    assert!(CsrMatrix::<f64>::zeros(2, 3).relative_eq_ignoring_pattern(
+        &CsrMatrix::zeros(2, 3),
+        0.0,
+        0.0
+    ));
+}
+
+#[test]
+fn csr_submatrix_edge_cases() {
+    let csr = CsrMatrix::from(&DMatrix::from_row_slice(2, 3, &[1, 0, 2, 0, 3, 4]));
+
+    // Empty ranges produce matrices with zero rows and/or columns
+    assert_eq!(csr.submatrix(1..1, 0..3), CsrMatrix::zeros(0, 3));
+    assert_eq!(csr.submatrix(0..2, 2..2), CsrMatrix::zeros(2, 0));
+    assert_eq!(csr.submatrix(2..2, 3..3), CsrMatrix::zeros(0, 0));
+
+    // The full range reproduces the matrix
+    assert_eq!(csr.submatrix(0..2, 0..3), csr);
+
+    // Out-of-bounds or decreasing ranges panic
+    assert_panics!(csr.submatrix(0..3, 0..3));
+    assert_panics!(csr.submatrix(0..2, 1..4));
+    #[allow(clippy::reversed_empty_ranges)]
+    {
+        assert_panics!(csr.submatrix(2..1, 0..3));
+        assert_panics!(csr.submatrix(0..2, 3..2));
+    }
+}
+
 proptest! {
     #[test]
     fn csr_double_transpose_is_identity(csr in csr_strategy()) {
@@ -695,6 +799,22 @@ proptest! {
         prop_assert!(csr_upper_triangle.nnz() <= csr.nnz());
     }
 
+    #[test]
+    fn csr_strictly_upper_triangle_agrees_with_dense(csr in csr_strategy()) {
+        let csr_strictly_upper = csr.strictly_upper_triangle();
+        prop_assert_eq!(DMatrix::from(&csr_strictly_upper),
+                        DMatrix::from(&csr).upper_triangle_with_offset(1));
+        prop_assert!(csr_strictly_upper.triplet_iter().all(|(i, j, _)| i < j));
+    }
+
+    #[test]
+    fn csr_strictly_lower_triangle_agrees_with_dense(csr in csr_strategy()) {
+        let csr_strictly_lower = csr.strictly_lower_triangle();
+        prop_assert_eq!(DMatrix::from(&csr_strictly_lower),
+                        DMatrix::from(&csr).lower_triangle_with_offset(-1));
+        prop_assert!(csr_strictly_lower.triplet_iter().all(|(i, j, _)| i > j));
+    }
+
     #[test]
     fn csr_diagonal_as_csr(csr in csr_strategy()) {
         let d = csr.diagonal_as_csr();
@@ -708,6 +828,25 @@ proptest! {
         prop_assert_eq!(d_entries, csr_diagonal_entries);
     }
 
+    #[test]
+    fn csr_submatrix_agrees_with_dense(
+        (csr, rows, cols) in csr_strategy()
+            .prop_flat_map(|matrix| {
+                let (nrows, ncols) = (matrix.nrows(), matrix.ncols());
+                let rows = (0 ..= nrows).prop_flat_map(move |start| (Just(start), start ..= nrows));
+                let cols = (0 ..= ncols).prop_flat_map(move |start| (Just(start), start ..= ncols));
+                (Just(matrix), rows, cols)
+            }))
+    {
+        let (rows, cols) = (rows.0 .. rows.1, cols.0 .. cols.1);
+        let submatrix = csr.submatrix(rows.clone(), cols.clone());
+        let dense = DMatrix::from(&csr);
+        let dense_submatrix = dense.slice((rows.start, cols.start), (rows.len(), cols.len()));
+
+        prop_assert_eq!(DMatrix::from(&submatrix), dense_submatrix);
+        prop_assert!(submatrix.nnz() <= csr.nnz());
+    }
+
     #[test]
     fn csr_identity(n in 0 ..= 6usize) {
         let csr = CsrMatrix::<i32>::identity(n);
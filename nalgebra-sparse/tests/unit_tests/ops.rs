@@ -5,9 +5,9 @@ use crate::common::{
 use nalgebra_sparse::csc::CscMatrix;
 use nalgebra_sparse::csr::CsrMatrix;
 use nalgebra_sparse::ops::serial::{
-    spadd_csc_prealloc, spadd_csr_prealloc, spadd_pattern, spmm_csc_dense, spmm_csc_prealloc,
-    spmm_csc_prealloc_unchecked, spmm_csr_dense, spmm_csr_pattern, spmm_csr_prealloc,
-    spmm_csr_prealloc_unchecked, spsolve_csc_lower_triangular,
+    spadd_csc, spadd_csc_prealloc, spadd_csr, spadd_csr_prealloc, spadd_pattern, spmm_csc_dense,
+    spmm_csc_prealloc, spmm_csc_prealloc_unchecked, spmm_csr_dense, spmm_csr_pattern,
+    spmm_csr_prealloc, spmm_csr_prealloc_unchecked, spsolve_csc_lower_triangular,
 };
 use nalgebra_sparse::ops::Op;
 use nalgebra_sparse::pattern::SparsityPattern;
@@ -183,6 +183,64 @@ fn spadd_csc_prealloc_args_strategy() -> impl Strategy<Value = SpaddCscArgs<i32>
     })
 }
 
+#[derive(Debug)]
+struct SpaddArgs<M> {
+    alpha: i32,
+    a: Op<M>,
+    beta: i32,
+    b: Op<M>,
+}
+
+/// Constructs arguments for `alpha * op(A) + beta * op(B)`, where `op(A)` and `op(B)` have the
+/// same dimensions.
+fn spadd_csr_args_strategy() -> impl Strategy<Value = SpaddArgs<CsrMatrix<i32>>> {
+    let value_strategy = PROPTEST_I32_VALUE_STRATEGY;
+
+    spadd_pattern_strategy()
+        .prop_flat_map(move |(a_pattern, b_pattern)| {
+            let a_values = vec![value_strategy.clone(); a_pattern.nnz()];
+            let b_values = vec![value_strategy.clone(); b_pattern.nnz()];
+            (
+                Just(a_pattern),
+                Just(b_pattern),
+                a_values,
+                b_values,
+                value_strategy.clone(),
+                value_strategy.clone(),
+                trans_strategy(),
+                trans_strategy(),
+            )
+        })
+        .prop_map(
+            |(a_pattern, b_pattern, a_values, b_values, alpha, beta, trans_a, trans_b)| {
+                let a = CsrMatrix::try_from_pattern_and_values(a_pattern, a_values).unwrap();
+                let b = CsrMatrix::try_from_pattern_and_values(b_pattern, b_values).unwrap();
+                let op = |m: CsrMatrix<i32>, trans| {
+                    if trans {
+                        Op::Transpose(m.transpose())
+                    } else {
+                        Op::NoOp(m)
+                    }
+                };
+                SpaddArgs {
+                    alpha,
+                    a: op(a, trans_a),
+                    beta,
+                    b: op(b, trans_b),
+                }
+            },
+        )
+}
+
+fn spadd_csc_args_strategy() -> impl Strategy<Value = SpaddArgs<CscMatrix<i32>>> {
+    spadd_csr_args_strategy().prop_map(|args| SpaddArgs {
+        alpha: args.alpha,
+        a: args.a.map_same_op(|a| CscMatrix::from(&a)),
+        beta: args.beta,
+        b: args.b.map_same_op(|b| CscMatrix::from(&b)),
+    })
+}
+
 fn dense_strategy() -> impl Strategy<Value = DMatrix<i32>> {
     matrix(
         PROPTEST_I32_VALUE_STRATEGY,
@@ -453,6 +511,19 @@ proptest! {
         prop_assert_eq!(&DMatrix::from(&c_sparse), &c_dense);
     }
 
+    #[test]
+    fn spadd_csr_test(SpaddArgs { alpha, a, beta, b } in spadd_csr_args_strategy()) {
+        let c = spadd_csr(alpha, a.as_ref(), beta, b.as_ref());
+
+        let op_dense = |m: Op<CsrMatrix<i32>>| match m {
+            Op::NoOp(m) => DMatrix::from(&m),
+            Op::Transpose(m) => DMatrix::from(&m).transpose(),
+        };
+        let c_dense = alpha * op_dense(a) + beta * op_dense(b);
+
+        prop_assert_eq!(&DMatrix::from(&c), &c_dense);
+    }
+
     #[test]
     fn csr_add_csr(
         // a and b have the same dimensions
@@ -902,6 +973,19 @@ proptest! {
             "The SPMM kernel executed successfully despite mismatch dimensions");
     }
 
+    #[test]
+    fn spadd_csc_test(SpaddArgs { alpha, a, beta, b } in spadd_csc_args_strategy()) {
+        let c = spadd_csc(alpha, a.as_ref(), beta, b.as_ref());
+
+        let op_dense = |m: Op<CscMatrix<i32>>| match m {
+            Op::NoOp(m) => DMatrix::from(&m),
+            Op::Transpose(m) => DMatrix::from(&m).transpose(),
+        };
+        let c_dense = alpha * op_dense(a) + beta * op_dense(b);
+
+        prop_assert_eq!(&DMatrix::from(&c), &c_dense);
+    }
+
     #[test]
     fn spadd_csc_prealloc_test(SpaddCscArgs { c, beta, alpha, a } in spadd_csc_prealloc_args_strategy()) {
         // Test that we get the expected result by comparing to an equivalent dense operation
@@ -1241,6 +1325,68 @@ proptest! {
         prop_assert_eq!(a.clone() * b.clone(), expected.clone());
     }
 
+    #[test]
+    fn csr_mul_dense_vector_views(
+        // a and b have dimensions compatible for multiplication
+        (a, b)
+        in csr_strategy()
+            .prop_flat_map(|a| {
+                let cols = PROPTEST_MATRIX_DIM;
+                let b = matrix(PROPTEST_I32_VALUE_STRATEGY, a.ncols(), cols);
+                (Just(a), b)
+            }))
+    {
+        let expected = DMatrix::from(&a) * &b;
+        // Interleave the rows of `b` with zero rows so that we can take non-contiguous views.
+        let interleaved = DMatrix::from_fn(2 * b.nrows(), b.ncols(), |i, j| if i % 2 == 0 { b[(i / 2, j)] } else { 0 });
+
+        for j in 0..b.ncols() {
+            let column = b.column(j);
+            prop_assert_eq!(&a * column, expected.column(j));
+            prop_assert_eq!(&a * column, &a * column.clone_owned());
+
+            let strided = interleaved.slice_with_steps((0, j), (b.nrows(), 1), (1, 0));
+            prop_assert_eq!(&a * strided, expected.column(j));
+
+            let mut c = DMatrix::zeros(2 * a.nrows(), 1);
+            let c_view = c.slice_with_steps_mut((1, 0), (a.nrows(), 1), (1, 0));
+            spmm_csr_dense(0, c_view, 1, Op::NoOp(&a), Op::NoOp(strided));
+            prop_assert_eq!(c.rows_with_step(1, a.nrows(), 1), expected.column(j));
+            prop_assert!(c.rows_with_step(0, a.nrows(), 1).iter().all(|c_i| *c_i == 0));
+        }
+    }
+
+    #[test]
+    fn csc_mul_dense_vector_views(
+        // a and b have dimensions compatible for multiplication
+        (a, b)
+        in csc_strategy()
+            .prop_flat_map(|a| {
+                let cols = PROPTEST_MATRIX_DIM;
+                let b = matrix(PROPTEST_I32_VALUE_STRATEGY, a.ncols(), cols);
+                (Just(a), b)
+            }))
+    {
+        let expected = DMatrix::from(&a) * &b;
+        // Interleave the rows of `b` with zero rows so that we can take non-contiguous views.
+        let interleaved = DMatrix::from_fn(2 * b.nrows(), b.ncols(), |i, j| if i % 2 == 0 { b[(i / 2, j)] } else { 0 });
+
+        for j in 0..b.ncols() {
+            let column = b.column(j);
+            prop_assert_eq!(&a * column, expected.column(j));
+            prop_assert_eq!(&a * column, &a * column.clone_owned());
+
+            let strided = interleaved.slice_with_steps((0, j), (b.nrows(), 1), (1, 0));
+            prop_assert_eq!(&a * strided, expected.column(j));
+
+            let mut c = DMatrix::zeros(2 * a.nrows(), 1);
+            let c_view = c.slice_with_steps_mut((1, 0), (a.nrows(), 1), (1, 0));
+            spmm_csc_dense(0, c_view, 1, Op::NoOp(&a), Op::NoOp(strided));
+            prop_assert_eq!(c.rows_with_step(1, a.nrows(), 1), expected.column(j));
+            prop_assert!(c.rows_with_step(0, a.nrows(), 1).iter().all(|c_i| *c_i == 0));
+        }
+    }
+
     #[test]
     fn csc_solve_lower_triangular_no_transpose(
         // A CSC matrix `a` and a dimensionally compatible dense matrix `b`
@@ -1280,3 +1426,58 @@ proptest! {
     }
 
 }
+
+#[test]
+fn spadd_csr_partial_overlap_and_cancellation() {
+    // a = [1 2 0]    b = [0 -2 3]
+    //     [0 0 4]        [5  0 0]
+    let a =
+        CsrMatrix::try_from_csr_data(2, 3, vec![0, 2, 3], vec![0, 1, 2], vec![1, 2, 4]).unwrap();
+    let b =
+        CsrMatrix::try_from_csr_data(2, 3, vec![0, 2, 3], vec![1, 2, 0], vec![-2, 3, 5]).unwrap();
+
+    let c = spadd_csr(1, Op::NoOp(&a), 1, Op::NoOp(&b));
+    assert_eq!(
+        DMatrix::from(&c),
+        DMatrix::from_row_slice(2, 3, &[1, 0, 3, 5, 0, 4])
+    );
+    // The cancelled entry is kept as an explicit zero
+    assert_eq!(c.nnz(), 5);
+    assert_eq!(c.get_entry(0, 1).unwrap().into_value(), 0);
+
+    let c = spadd_csc(
+        2,
+        Op::NoOp(&CscMatrix::from(&a)),
+        -1,
+        Op::NoOp(&CscMatrix::from(&b)),
+    );
+    assert_eq!(
+        DMatrix::from(&c),
+        DMatrix::from_row_slice(2, 3, &[2, 6, -3, -5, 0, 8])
+    );
+}
+
+#[test]
+fn spadd_disjoint_patterns() {
+    // a = [1 0]    b = [0 3]ᵀ = [0 0]
+    //     [0 2]        [0 0]     [3 0]
+    let a = CsrMatrix::try_from_csr_data(2, 2, vec![0, 1, 2], vec![0, 1], vec![1, 2]).unwrap();
+    let b = CsrMatrix::try_from_csr_data(2, 2, vec![0, 1, 1], vec![1], vec![3]).unwrap();
+    let expected = DMatrix::from_row_slice(2, 2, &[1, 0, 3, 2]);
+
+    let c = spadd_csr(1, Op::NoOp(&a), 1, Op::Transpose(&b));
+    assert_eq!(c.nnz(), 3);
+    assert_eq!(DMatrix::from(&c), expected);
+
+    let (a, b) = (CscMatrix::from(&a), CscMatrix::from(&b));
+    let c = spadd_csc(1, Op::NoOp(&a), 1, Op::Transpose(&b));
+    assert_eq!(c.nnz(), 3);
+    assert_eq!(DMatrix::from(&c), expected);
+}
+
+#[test]
+#[should_panic]
+fn spadd_csr_panics_on_dim_mismatch() {
+    let a = CsrMatrix::<i32>::zeros(2, 3);
+    let _ = spadd_csr(1, Op::NoOp(&a), 1, Op::NoOp(&a.transpose()));
+}
@@ -55,6 +55,54 @@ fn sparsity_pattern_valid_data() {
     }
 }
 
+#[test]
+fn sparsity_pattern_statistics() {
+    {
+        // Empty pattern
+        let pattern = SparsityPattern::zeros(0, 0);
+        assert_eq!(pattern.nnz_per_lane(), Vec::<usize>::new());
+        assert_eq!(pattern.max_nnz_in_lane(), 0);
+        assert_eq!(pattern.bandwidth(), 0);
+        assert_eq!(pattern.density(), 0.0);
+    }
+
+    {
+        // Pattern without explicitly stored entries
+        let pattern = SparsityPattern::zeros(3, 2);
+        assert_eq!(pattern.nnz_per_lane(), vec![0, 0, 0]);
+        assert_eq!(pattern.max_nnz_in_lane(), 0);
+        assert_eq!(pattern.bandwidth(), 0);
+        assert_eq!(pattern.density(), 0.0);
+    }
+
+    {
+        // Arbitrary pattern
+        let offsets = vec![0, 2, 2, 5];
+        let indices = vec![0, 5, 1, 2, 3];
+        let pattern =
+            SparsityPattern::try_from_offsets_and_indices(3, 6, offsets, indices).unwrap();
+        assert_eq!(pattern.nnz_per_lane(), vec![2, 0, 3]);
+        assert_eq!(pattern.max_nnz_in_lane(), 3);
+        assert_eq!(pattern.bandwidth(), 5);
+        assert_eq!(pattern.density(), 5.0 / 18.0);
+
+        // Bandwidth is invariant under transposition
+        assert_eq!(pattern.transpose().bandwidth(), 5);
+        assert_eq!(pattern.transpose().nnz_per_lane(), vec![1, 1, 1, 1, 0, 1]);
+    }
+
+    {
+        // Diagonal pattern
+        let pattern =
+            SparsityPattern::try_from_offsets_and_indices(3, 3, vec![0, 1, 2, 3], vec![0, 1, 2])
+                .unwrap();
+        assert_eq!(pattern.nnz_per_lane(), vec![1, 1, 1]);
+        assert_eq!(pattern.max_nnz_in_lane(), 1);
+        assert_eq!(pattern.bandwidth(), 0);
+        assert_eq!(pattern.density(), 1.0 / 3.0);
+    }
+}
+
 #[test]
 fn sparsity_pattern_try_from_invalid_data() {
     {
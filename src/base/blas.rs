@@ -972,6 +972,56 @@ where
         self.xxgerx(alpha, x, y, beta, |e| e)
     }
 
+    /// Computes `self = alpha * x * x.transpose() + beta * self`, where `self` is a **symmetric**
+    /// matrix.
+    ///
+    /// This is a rank-k update of `self`, `k` being the number of columns of `x`. Because the
+    /// result is symmetric, only the lower-triangular (including the diagonal) part of `self` is
+    /// computed: it is the only part that is read/written. If `beta` is zero, `self` is never read.
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::{Matrix2x3, Matrix2};
+    /// let mut mat = Matrix2::identity();
+    /// let x = Matrix2x3::new(1.0, 2.0, 3.0,
+    ///                        4.0, 5.0, 6.0);
+    /// let expected = x * x.transpose() * 10.0 + mat * 5.0;
+    /// mat.m12 = 99999.99999; // This component is on the upper-triangular part and will not be read/written.
+    ///
+    /// mat.syrk(10.0, &x, 5.0);
+    /// assert_relative_eq!(mat.lower_triangle(), expected.lower_triangle());
+    /// assert_eq!(mat.m12, 99999.99999); // This was untouched.
+    /// ```
+    pub fn syrk<R2: Dim, C2: Dim, SB>(&mut self, alpha: T, x: &Matrix<T, R2, C2, SB>, beta: T)
+    where
+        T: One,
+        SB: Storage<T, R2, C2>,
+        ShapeConstraint: DimEq<R1, R2> + DimEq<C1, R2>,
+    {
+        let dim1 = self.nrows();
+        let dim2 = x.nrows();
+
+        assert!(
+            self.is_square(),
+            "Symmetric rank-k update: the input matrix must be square."
+        );
+        assert!(dim1 == dim2, "syrk: dimensions mismatch.");
+
+        for j in 0..dim1 {
+            for i in j..dim1 {
+                let val = alpha.clone() * x.row(i).dot(&x.row(j));
+
+                let e = &mut self[(i, j)];
+                *e = if beta.is_zero() {
+                    val
+                } else {
+                    val + beta.clone() * e.clone()
+                };
+            }
+        }
+    }
+
     /// Computes `self = alpha * x * y.adjoint() + beta * self`, where `self` is an **hermitian**
     /// matrix.
     ///
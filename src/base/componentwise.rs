@@ -3,7 +3,7 @@
 use num::{Signed, Zero};
 use std::ops::{Add, Mul};
 
-use simba::scalar::{ClosedDiv, ClosedMul};
+use simba::scalar::{ClosedDiv, ClosedMul, ComplexField};
 use simba::simd::SimdPartialOrd;
 
 use crate::base::allocator::{Allocator, SameShapeAllocator};
@@ -42,8 +42,94 @@ impl<T: Scalar, R: Dim, C: Dim, S: Storage<T, R, C>> Matrix<T, R, C, S> {
 
         res
     }
+}
+
+/// # Componentwise elementary functions
+///
+/// These functions are applied to each component independently, like `.map(|e| e.exp())`. They
+/// must not be confused with the matrix functions, e.g., `Matrix::exp` computes the matrix
+/// exponential which differs from `Matrix::exp_elementwise` for any non-diagonal matrix.
+impl<T: ComplexField, R: Dim, C: Dim, S: Storage<T, R, C>> Matrix<T, R, C, S>
+where
+    DefaultAllocator: Allocator<T, R, C>,
+{
+    /// Computes the exponential of each component of this matrix.
+    ///
+    /// This is **not** the matrix exponential `Matrix::exp`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::Matrix2;
+    /// let a = Matrix2::new(0.0, 1.0,
+    ///                      2.0, -1.0);
+    /// let expected = Matrix2::new(1.0, 1.0f64.exp(),
+    ///                             2.0f64.exp(), (-1.0f64).exp());
+    /// assert_relative_eq!(a.exp_elementwise(), expected);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn exp_elementwise(&self) -> OMatrix<T, R, C> {
+        self.map(|e| e.exp())
+    }
+
+    /// Computes the natural logarithm of each component of this matrix.
+    ///
+    /// This is **not** the matrix logarithm.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::Vector3;
+    /// let v = Vector3::new(1.0, 10.0, 0.5);
+    /// let expected = Vector3::new(0.0, 10.0f64.ln(), 0.5f64.ln());
+    /// assert_relative_eq!(v.ln_elementwise(), expected);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn ln_elementwise(&self) -> OMatrix<T, R, C> {
+        self.map(|e| e.ln())
+    }
+
+    /// Computes the square root of each component of this matrix.
+    ///
+    /// This is **not** the matrix square root.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use nalgebra::Matrix2;
+    /// let a = Matrix2::new(4.0, 9.0,
+    ///                      0.25, 0.0);
+    /// assert_eq!(a.sqrt_elementwise(), Matrix2::new(2.0, 3.0, 0.5, 0.0));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn sqrt_elementwise(&self) -> OMatrix<T, R, C> {
+        self.map(|e| e.sqrt())
+    }
 
-    // TODO: add other operators like component_ln, component_pow, etc. ?
+    /// Raises each component of this matrix to the floating-point power `n`.
+    ///
+    /// This is the Hadamard (componentwise) power, **not** the matrix power `Matrix::pow`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::Matrix2;
+    /// let a = Matrix2::new(1.0, 2.0,
+    ///                      3.0, 4.0);
+    /// assert_relative_eq!(a.powf_elementwise(2.0), a.component_mul(&a), epsilon = 1.0e-10);
+    /// assert_relative_eq!(a.powf_elementwise(0.5), a.sqrt_elementwise(), epsilon = 1.0e-10);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn powf_elementwise(&self, n: T::RealField) -> OMatrix<T, R, C> {
+        self.map(|e| e.powf(n.clone()))
+    }
 }
 
 macro_rules! component_binop_impl(
@@ -305,6 +391,135 @@ impl<T: Scalar, R1: Dim, C1: Dim, SA: Storage<T, R1, C1>> Matrix<T, R1, C1, SA>
         (self.inf(other), self.sup(other))
     }
 
+    /// Clamps each component of `self` between the corresponding components of `min` and `max`.
+    ///
+    /// The bounds are not checked: whenever a component of `min` is greater than the
+    /// corresponding component of `max`, the result is that component of `max`.
+    ///
+    /// NaN components of `self` are replaced by the corresponding components of `min`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use nalgebra::Matrix2;
+    /// let u = Matrix2::new(4.0, 2.0, 1.0, -2.0);
+    /// let min = Matrix2::new(0.0, 0.0, 2.0, -3.0);
+    /// let max = Matrix2::new(3.0, 3.0, 4.0, -1.0);
+    /// let expected = Matrix2::new(3.0, 2.0, 2.0, -2.0);
+    /// assert_eq!(u.clamp(&min, &max), expected)
+    /// ```
+    #[inline]
+    #[must_use = "Did you mean to use clamp_mut()?"]
+    pub fn clamp(&self, min: &Self, max: &Self) -> OMatrix<T, R1, C1>
+    where
+        T: SimdPartialOrd,
+        DefaultAllocator: Allocator<T, R1, C1>,
+    {
+        self.zip_zip_map(min, max, |a, min, max| a.simd_max(min).simd_min(max))
+    }
+
+    /// Clamps in-place each component of `self` between the corresponding components of `min`
+    /// and `max`.
+    ///
+    /// See [`Matrix::clamp`] for the behavior when the bounds are inverted, or when components
+    /// are NaN.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use nalgebra::Matrix2;
+    /// let mut u = Matrix2::new(4.0, 2.0, 1.0, -2.0);
+    /// let min = Matrix2::new(0.0, 0.0, 2.0, -3.0);
+    /// let max = Matrix2::new(3.0, 3.0, 4.0, -1.0);
+    /// u.clamp_mut(&min, &max);
+    /// let expected = Matrix2::new(3.0, 2.0, 2.0, -2.0);
+    /// assert_eq!(u, expected)
+    /// ```
+    #[inline]
+    pub fn clamp_mut<R2, C2, SB, R3, C3, SC>(
+        &mut self,
+        min: &Matrix<T, R2, C2, SB>,
+        max: &Matrix<T, R3, C3, SC>,
+    ) where
+        T: SimdPartialOrd,
+        SA: StorageMut<T, R1, C1>,
+        R2: Dim,
+        C2: Dim,
+        SB: Storage<T, R2, C2>,
+        R3: Dim,
+        C3: Dim,
+        SC: Storage<T, R3, C3>,
+        ShapeConstraint: SameNumberOfRows<R1, R2>
+            + SameNumberOfColumns<C1, C2>
+            + SameNumberOfRows<R1, R3>
+            + SameNumberOfColumns<C1, C3>,
+    {
+        assert_eq!(
+            self.shape(),
+            max.shape(),
+            "Matrix clamp: dimension mismatch."
+        );
+        self.zip_zip_apply(min, max, |a, min, max| {
+            *a = a.clone().simd_max(min).simd_min(max)
+        })
+    }
+
+    /// Clamps each component of `self` between the scalars `min` and `max`.
+    ///
+    /// The bounds are not checked: if `min` is greater than `max`, all the components of the
+    /// result are equal to `max`.
+    ///
+    /// Because NaN is not comparable to any bound, NaN components are replaced by `min`. This
+    /// makes it possible to sanitize the output of a diverging computation, but may also hide
+    /// its failure: use [`Matrix::contains_nan`] first if NaNs must be detected.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use nalgebra::Matrix2;
+    /// let u = Matrix2::new(4.0, 2.0, 1.0, -2.0);
+    /// let expected = Matrix2::new(3.0, 2.0, 1.0, 0.0);
+    /// assert_eq!(u.clamp_scalar(0.0, 3.0), expected);
+    ///
+    /// // NaN components are replaced by the lower bound.
+    /// let v = Matrix2::new(f64::NAN, 2.0, f64::INFINITY, f64::NEG_INFINITY);
+    /// assert_eq!(v.clamp_scalar(-1.0, 1.0), Matrix2::new(-1.0, 1.0, 1.0, -1.0));
+    /// ```
+    #[inline]
+    #[must_use = "Did you mean to use clamp_scalar_mut()?"]
+    pub fn clamp_scalar(&self, min: T, max: T) -> OMatrix<T, R1, C1>
+    where
+        T: SimdPartialOrd,
+        DefaultAllocator: Allocator<T, R1, C1>,
+    {
+        self.map(|a| a.simd_max(min.clone()).simd_min(max.clone()))
+    }
+
+    /// Clamps in-place each component of `self` between the scalars `min` and `max`.
+    ///
+    /// See [`Matrix::clamp_scalar`] for the behavior when the bounds are inverted, or when
+    /// components are NaN.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use nalgebra::Matrix2;
+    /// let mut u = Matrix2::new(4.0, 2.0, 1.0, -2.0);
+    /// u.clamp_scalar_mut(0.0, 3.0);
+    /// let expected = Matrix2::new(3.0, 2.0, 1.0, 0.0);
+    /// assert_eq!(u, expected)
+    /// ```
+    #[inline]
+    pub fn clamp_scalar_mut(&mut self, min: T, max: T)
+    where
+        T: SimdPartialOrd,
+        SA: StorageMut<T, R1, C1>,
+    {
+        for e in self.iter_mut() {
+            *e = e.clone().simd_max(min.clone()).simd_min(max.clone())
+        }
+    }
+
     /// Adds a scalar to `self`.
     ///
     /// # Example
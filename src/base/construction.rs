@@ -13,6 +13,8 @@ use rand::{
     Rng,
 };
 
+#[cfg(any(feature = "std", feature = "alloc"))]
+use std::alloc::Layout;
 use std::iter;
 use typenum::{self, Cmp, Greater};
 
@@ -688,6 +690,327 @@ where
                    nrows, ncols);
 }
 
+#[cfg(feature = "rayon")]
+impl<T: Scalar + Send> OMatrix<T, Dynamic, Dynamic>
+where
+    DefaultAllocator: Allocator<T, Dynamic, Dynamic>,
+{
+    /// Creates a matrix filled with the results of a function applied to each of its component
+    /// coordinates, evaluating the columns in parallel.
+    ///
+    /// The result is identical to `Self::from_fn(nrows, ncols, f)`, but `f` may be called from
+    /// several threads, in any order. This is worthwhile only if `f` is expensive to evaluate.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::DMatrix;
+    /// let dm = DMatrix::from_fn_par(2, 3, |i, j| i * 3 + j);
+    ///
+    /// assert_eq!(dm, DMatrix::from_fn(2, 3, |i, j| i * 3 + j));
+    /// ```
+    pub fn from_fn_par<F>(nrows: usize, ncols: usize, f: F) -> Self
+    where
+        F: Fn(usize, usize) -> T + Sync,
+    {
+        use rayon::prelude::*;
+
+        let data: Vec<T> = (0..ncols)
+            .into_par_iter()
+            .flat_map_iter(|j| (0..nrows).map(move |i| (i, j)))
+            .map(|(i, j)| f(i, j))
+            .collect();
+
+        Self::from_vec(nrows, ncols, data)
+    }
+}
+
+/// # Block matrix constructors
+#[cfg(any(feature = "std", feature = "alloc"))]
+impl<T: Scalar> OMatrix<T, Dynamic, Dynamic>
+where
+    DefaultAllocator: Allocator<T, Dynamic, Dynamic>,
+{
+    /// Concatenates the given matrices horizontally, i.e., side by side from left to right.
+    ///
+    /// Concatenating an empty list of matrices results in a `0x0` matrix.
+    ///
+    /// # Panics
+    /// Panics if the matrices do not all have the same number of rows.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::DMatrix;
+    /// let a = DMatrix::from_row_slice(2, 1, &[1, 4]);
+    /// let b = DMatrix::from_row_slice(2, 2, &[2, 3, 5, 6]);
+    ///
+    /// assert_eq!(DMatrix::hstack(&[a, b]), DMatrix::from_row_slice(2, 3, &[1, 2, 3, 4, 5, 6]));
+    /// ```
+    pub fn hstack(blocks: &[Self]) -> Self {
+        let nrows = blocks.first().map_or(0, |b| b.nrows());
+        let ncols = blocks.iter().map(|b| b.ncols()).sum();
+        let mut data = Vec::with_capacity(nrows * ncols);
+
+        for (k, block) in blocks.iter().enumerate() {
+            assert_eq!(
+                block.nrows(),
+                nrows,
+                "hstack: the block {} has {} rows instead of {}.",
+                k,
+                block.nrows(),
+                nrows
+            );
+            // Both the blocks and the result are stored in column-major order.
+            data.extend_from_slice(block.as_slice());
+        }
+
+        Self::from_vec(nrows, ncols, data)
+    }
+
+    /// Concatenates the given matrices vertically, i.e., on top of each other from top to
+    /// bottom.
+    ///
+    /// Concatenating an empty list of matrices results in a `0x0` matrix.
+    ///
+    /// # Panics
+    /// Panics if the matrices do not all have the same number of columns.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::DMatrix;
+    /// let a = DMatrix::from_row_slice(1, 2, &[1, 2]);
+    /// let b = DMatrix::from_row_slice(2, 2, &[3, 4, 5, 6]);
+    ///
+    /// assert_eq!(DMatrix::vstack(&[a, b]), DMatrix::from_row_slice(3, 2, &[1, 2, 3, 4, 5, 6]));
+    /// ```
+    pub fn vstack(blocks: &[Self]) -> Self {
+        let ncols = blocks.first().map_or(0, |b| b.ncols());
+        let nrows = blocks.iter().map(|b| b.nrows()).sum();
+        let mut data = Vec::with_capacity(nrows * ncols);
+
+        for (k, block) in blocks.iter().enumerate() {
+            assert_eq!(
+                block.ncols(),
+                ncols,
+                "vstack: the block {} has {} columns instead of {}.",
+                k,
+                block.ncols(),
+                ncols
+            );
+        }
+
+        for j in 0..ncols {
+            for block in blocks {
+                data.extend(block.column(j).iter().cloned());
+            }
+        }
+
+        Self::from_vec(nrows, ncols, data)
+    }
+
+    /// Assembles a matrix from a two-dimensional grid of blocks.
+    ///
+    /// Each element of `grid` is a row of blocks concatenated with `Self::hstack`, and the
+    /// resulting rows are concatenated with `Self::vstack`.
+    ///
+    /// # Panics
+    /// Panics if the rows of the grid do not all contain the same number of blocks, if the
+    /// blocks of a row of the grid do not have the same number of rows, or if the blocks of
+    /// a column of the grid do not have the same number of columns.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::DMatrix;
+    /// let a = DMatrix::from_element(1, 1, 1);
+    /// let b = DMatrix::from_element(1, 2, 2);
+    /// let c = DMatrix::from_element(2, 1, 3);
+    /// let d = DMatrix::from_element(2, 2, 4);
+    ///
+    /// let m = DMatrix::block(&[&[a, b], &[c, d]]);
+    /// assert_eq!(m, DMatrix::from_row_slice(3, 3, &[
+    ///     1, 2, 2,
+    ///     3, 4, 4,
+    ///     3, 4, 4,
+    /// ]));
+    /// ```
+    pub fn block(grid: &[&[Self]]) -> Self {
+        let rows: Vec<_> = grid
+            .iter()
+            .enumerate()
+            .map(|(i, row)| {
+                assert_eq!(
+                    row.len(),
+                    grid[0].len(),
+                    "block: the row {} of the grid has {} blocks instead of {}.",
+                    i,
+                    row.len(),
+                    grid[0].len()
+                );
+
+                for (j, block) in row.iter().enumerate() {
+                    assert_eq!(
+                        block.ncols(),
+                        grid[0][j].ncols(),
+                        "block: the block ({}, {}) has {} columns instead of {}.",
+                        i,
+                        j,
+                        block.ncols(),
+                        grid[0][j].ncols()
+                    );
+                }
+
+                Self::hstack(row)
+            })
+            .collect();
+
+        Self::vstack(&rows)
+    }
+}
+
+/// # Constructors of evenly spaced vectors
+#[cfg(any(feature = "std", feature = "alloc"))]
+impl<T: crate::RealField> OVector<T, Dynamic>
+where
+    DefaultAllocator: Allocator<T, Dynamic>,
+{
+    /// Creates a vector of `n` evenly spaced values from `start` to `end`, both included.
+    ///
+    /// The first and last components are exactly equal to `start` and `end`. If `n` is `1`, the
+    /// result is `[start]`, and if `n` is `0`, the result is empty.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::DVector;
+    /// let v = DVector::linspace(0.0, 1.0, 5);
+    /// assert_eq!(v, DVector::from_vec(vec![0.0, 0.25, 0.5, 0.75, 1.0]));
+    ///
+    /// assert_eq!(DVector::linspace(0.0, 0.3, 4)[3], 0.3);
+    /// assert_eq!(DVector::linspace(2.0, 3.0, 1), DVector::from_vec(vec![2.0]));
+    /// assert!(DVector::<f64>::linspace(2.0, 3.0, 0).is_empty());
+    /// ```
+    pub fn linspace(start: T, end: T, n: usize) -> Self {
+        let last = crate::convert::<_, T>(n.saturating_sub(1).max(1) as f64);
+
+        // Interpolating from both ends ensures that they are reached exactly.
+        Self::from_fn(n, |i, _| {
+            let t = crate::convert::<_, T>(i as f64) / last.clone();
+            start.clone() * (T::one() - t.clone()) + end.clone() * t
+        })
+    }
+
+    /// Creates a vector of `n` values evenly spaced on a logarithmic scale, from
+    /// `base^start_exp` to `base^end_exp`, both included.
+    ///
+    /// This computes `base` raised to each component of `Self::linspace(start_exp, end_exp, n)`.
+    /// If `n` is `1`, the result is `[base^start_exp]`, and if `n` is `0`, the result is empty.
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::DVector;
+    /// let v = DVector::logspace(0.0, 3.0, 4, 10.0);
+    /// assert_relative_eq!(v, DVector::from_vec(vec![1.0, 10.0, 100.0, 1000.0]), epsilon = 1.0e-10);
+    ///
+    /// let v = DVector::logspace(-1.0, 2.0, 4, 2.0);
+    /// assert_relative_eq!(v, DVector::from_vec(vec![0.5, 1.0, 2.0, 4.0]), epsilon = 1.0e-10);
+    /// ```
+    pub fn logspace(start_exp: T, end_exp: T, n: usize, base: T) -> Self {
+        Self::linspace(start_exp, end_exp, n).map(|e| base.clone().powf(e))
+    }
+
+    /// Creates a vector of the values `start + i * step` lying in the half-open interval from
+    /// `start` (included) to `end` (excluded).
+    ///
+    /// This is similar to NumPy's `arange`. The `step` may be negative for decreasing values, and
+    /// the result is empty if `end` cannot be reached from `start` by moving in the direction of
+    /// `step`. Because of rounding errors, a value very close to `end` may be included or not
+    /// depending on the representation of `step`; prefer [`Self::linspace`] if both endpoints
+    /// matter.
+    ///
+    /// The result is also empty if any of the arguments is NaN.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `step` is zero, or if the number of values is infinite or too large to be
+    /// stored in a vector, e.g., if `end` is infinite or if `step` is tiny compared to
+    /// `end - start`.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::DVector;
+    /// assert_eq!(DVector::range(0.0, 2.0, 0.5), DVector::from_vec(vec![0.0, 0.5, 1.0, 1.5]));
+    /// assert_eq!(DVector::range(3.0, 0.0, -1.0), DVector::from_vec(vec![3.0, 2.0, 1.0]));
+    /// assert!(DVector::range(0.0, 2.0, -1.0).is_empty());
+    /// ```
+    pub fn range(start: T, end: T, step: T) -> Self {
+        assert!(!step.is_zero(), "range: the step must not be zero.");
+
+        let len = ((end - start.clone()) / step.clone()).ceil();
+        let len = match crate::try_convert::<_, f64>(len) {
+            Some(len) if len.is_nan() || len <= 0.0 => 0,
+            // The cast saturates, and `Layout::array` fails if the allocation would be too large.
+            Some(len) if len.is_finite() && Layout::array::<T>(len as usize).is_ok() => {
+                len as usize
+            }
+            _ => panic!("range: the number of values is infinite or too large."),
+        };
+
+        Self::from_fn(len, |i, _| {
+            start.clone() + step.clone() * crate::convert::<_, T>(i as f64)
+        })
+    }
+}
+
+/// # Coordinate grid constructors
+#[cfg(any(feature = "std", feature = "alloc"))]
+impl<T: Scalar> OMatrix<T, Dynamic, Dynamic>
+where
+    DefaultAllocator: Allocator<T, Dynamic, Dynamic>,
+{
+    /// Creates the coordinate matrices of the two-dimensional grid with the given `x` and `y`
+    /// coordinates.
+    ///
+    /// This follows the default (`'xy'`, i.e., cartesian) indexing convention of NumPy's
+    /// `meshgrid`: both matrices have `y.len()` rows and `x.len()` columns, and the
+    /// component `(i, j)` of the grid has the coordinates `(x[j], y[i])`. Thus, each row of the
+    /// first matrix is a copy of `x`, and each column of the second matrix is a copy of `y`.
+    ///
+    /// The matrix indexing convention (`'ij'`) of NumPy, where the component `(i, j)` has the
+    /// coordinates `(x[i], y[j])`, is obtained by transposing both matrices.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::{DMatrix, DVector};
+    /// let x = DVector::from_vec(vec![1, 2, 3]);
+    /// let y = DVector::from_vec(vec![10, 20]);
+    /// let (xx, yy) = DMatrix::meshgrid(&x, &y);
+    ///
+    /// assert_eq!(xx, DMatrix::from_row_slice(2, 3, &[1, 2, 3,
+    ///                                                1, 2, 3]));
+    /// assert_eq!(yy, DMatrix::from_row_slice(2, 3, &[10, 10, 10,
+    ///                                                20, 20, 20]));
+    ///
+    /// // Evaluate a function on the whole grid.
+    /// let f = xx.zip_map(&yy, |x, y| x * y);
+    /// assert_eq!(f, DMatrix::from_row_slice(2, 3, &[10, 20, 30,
+    ///                                               20, 40, 60]));
+    /// ```
+    pub fn meshgrid<D1: Dim, D2: Dim, S1, S2>(
+        x: &Vector<T, D1, S1>,
+        y: &Vector<T, D2, S2>,
+    ) -> (Self, Self)
+    where
+        S1: RawStorage<T, D1>,
+        S2: RawStorage<T, D2>,
+    {
+        let (nrows, ncols) = (y.len(), x.len());
+
+        (
+            Self::from_fn(nrows, ncols, |_, j| x[j].clone()),
+            Self::from_fn(nrows, ncols, |i, _| y[i].clone()),
+        )
+    }
+}
+
 /*
  *
  * Constructors that don't necessarily require all dimensions
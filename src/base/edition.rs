@@ -1,3 +1,6 @@
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::{vec, vec::Vec};
+
 use num::{One, Zero};
 use std::cmp;
 #[cfg(any(feature = "std", feature = "alloc"))]
@@ -10,7 +13,10 @@ use crate::base::constraint::{DimEq, SameNumberOfColumns, SameNumberOfRows, Shap
 use crate::base::dimension::Dynamic;
 use crate::base::dimension::{Const, Dim, DimAdd, DimDiff, DimMin, DimMinimum, DimSub, DimSum, U1};
 use crate::base::storage::{RawStorage, RawStorageMut, ReshapableStorage};
-use crate::base::{DefaultAllocator, Matrix, OMatrix, RowVector, Scalar, Vector};
+use crate::base::{
+    ArrayStorage, DefaultAllocator, Matrix, MatrixSlice, OMatrix, RowVector, SMatrix, Scalar,
+    Vector,
+};
 use crate::{Storage, UninitMatrix};
 use std::mem::MaybeUninit;
 
@@ -41,6 +47,97 @@ impl<T: Scalar + Zero, R: Dim, C: Dim, S: Storage<T, R, C>> Matrix<T, R, C, S> {
 
         res
     }
+
+    /// Extracts the part of this matrix on and above its `offset`-th diagonal, and sets the
+    /// other elements to zero.
+    ///
+    /// The element at coordinates `(i, j)` is kept if, and only if, `j - i >= offset`. Thus,
+    /// a positive `offset` also discards the `offset - 1` first superdiagonals, a negative
+    /// `offset` also keeps the `-offset` first subdiagonals, and an `offset` of `0` is
+    /// equivalent to `.upper_triangle()`. The strictly upper triangular part is obtained with
+    /// an `offset` of `1`.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::Matrix3;
+    /// let m = Matrix3::new(1, 2, 3,
+    ///                      4, 5, 6,
+    ///                      7, 8, 9);
+    ///
+    /// assert_eq!(m.upper_triangle_with_offset(1), Matrix3::new(0, 2, 3,
+    ///                                                          0, 0, 6,
+    ///                                                          0, 0, 0));
+    /// assert_eq!(m.upper_triangle_with_offset(-1), Matrix3::new(1, 2, 3,
+    ///                                                           4, 5, 6,
+    ///                                                           0, 8, 9));
+    /// assert_eq!(m.upper_triangle_with_offset(0), m.upper_triangle());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn upper_triangle_with_offset(&self, offset: isize) -> OMatrix<T, R, C>
+    where
+        DefaultAllocator: Allocator<T, R, C>,
+    {
+        let mut res = self.clone_owned();
+        let nrows = res.nrows();
+
+        for j in 0..res.ncols() {
+            let start = cmp::max((j as isize).saturating_sub(offset).saturating_add(1), 0) as usize;
+
+            for i in start..nrows {
+                unsafe { *res.get_unchecked_mut((i, j)) = T::zero() }
+            }
+        }
+
+        res
+    }
+
+    /// Extracts the part of this matrix on and below its `offset`-th diagonal, and sets the
+    /// other elements to zero.
+    ///
+    /// The element at coordinates `(i, j)` is kept if, and only if, `j - i <= offset`. Thus,
+    /// a positive `offset` also keeps the `offset` first superdiagonals, a negative `offset`
+    /// also discards the `-offset - 1` first subdiagonals, and an `offset` of `0` is
+    /// equivalent to `.lower_triangle()`. The strictly lower triangular part is obtained with
+    /// an `offset` of `-1`.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::Matrix3;
+    /// let m = Matrix3::new(1, 2, 3,
+    ///                      4, 5, 6,
+    ///                      7, 8, 9);
+    ///
+    /// assert_eq!(m.lower_triangle_with_offset(-1), Matrix3::new(0, 0, 0,
+    ///                                                           4, 0, 0,
+    ///                                                           7, 8, 0));
+    /// assert_eq!(m.lower_triangle_with_offset(1), Matrix3::new(1, 2, 0,
+    ///                                                          4, 5, 6,
+    ///                                                          7, 8, 9));
+    /// assert_eq!(m.lower_triangle_with_offset(0), m.lower_triangle());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn lower_triangle_with_offset(&self, offset: isize) -> OMatrix<T, R, C>
+    where
+        DefaultAllocator: Allocator<T, R, C>,
+    {
+        let mut res = self.clone_owned();
+        let nrows = res.nrows();
+
+        for j in 0..res.ncols() {
+            let end = cmp::min(
+                cmp::max((j as isize).saturating_sub(offset), 0) as usize,
+                nrows,
+            );
+
+            for i in 0..end {
+                unsafe { *res.get_unchecked_mut((i, j)) = T::zero() }
+            }
+        }
+
+        res
+    }
 }
 
 /// # Rows and columns extraction
@@ -140,6 +237,60 @@ impl<T: Scalar, R: Dim, C: Dim, S: RawStorageMut<T, R, C>> Matrix<T, R, C, S> {
         }
     }
 
+    /// Fills the diagonal at the given `offset` of this matrix with the content of the given
+    /// vector.
+    ///
+    /// The element `i` of `diag` is written at coordinates `(i, i + offset)` if `offset` is
+    /// nonnegative (superdiagonals), and at coordinates `(i - offset, i)` otherwise
+    /// (subdiagonals). An `offset` of zero sets the main diagonal, like `.set_diagonal(diag)`.
+    ///
+    /// # Panics
+    /// Panics if the length of `diag` differs from the length of the selected diagonal.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::{DMatrix, DVector};
+    /// // The tridiagonal second-order finite-difference stencil.
+    /// let mut m = DMatrix::zeros(4, 4);
+    /// m.set_diagonal(&DVector::repeat(4, -2.0));
+    /// m.set_offset_diagonal(1, &DVector::repeat(3, 1.0));
+    /// m.set_offset_diagonal(-1, &DVector::repeat(3, 1.0));
+    ///
+    /// assert_eq!(m, DMatrix::from_row_slice(4, 4, &[
+    ///     -2.0,  1.0,  0.0,  0.0,
+    ///      1.0, -2.0,  1.0,  0.0,
+    ///      0.0,  1.0, -2.0,  1.0,
+    ///      0.0,  0.0,  1.0, -2.0,
+    /// ]));
+    /// ```
+    #[inline]
+    pub fn set_offset_diagonal<R2: Dim, S2>(&mut self, offset: isize, diag: &Vector<T, R2, S2>)
+    where
+        S2: RawStorage<T, R2>,
+    {
+        let (nrows, ncols) = self.shape();
+        let shift = offset.unsigned_abs();
+        let (row_shift, col_shift) = if offset >= 0 { (0, shift) } else { (shift, 0) };
+        let len = cmp::min(
+            nrows.saturating_sub(row_shift),
+            ncols.saturating_sub(col_shift),
+        );
+        assert_eq!(
+            diag.len(),
+            len,
+            "Mismatched dimensions: the diagonal at offset {} has {} elements.",
+            offset,
+            len
+        );
+
+        for i in 0..len {
+            unsafe {
+                *self.get_unchecked_mut((i + row_shift, i + col_shift)) =
+                    diag.vget_unchecked(i).clone()
+            }
+        }
+    }
+
     /// Fills the selected row of this matrix with the content of the given vector.
     #[inline]
     pub fn set_row<C2: Dim, S2>(&mut self, i: usize, row: &RowVector<T, C2, S2>)
@@ -236,7 +387,29 @@ impl<T, R: Dim, C: Dim, S: RawStorageMut<T, R, C>> Matrix<T, R, C, S> {
     /// * If `shift = 0` then the diagonal is overwritten as well.
     /// * If `shift = 1` then the diagonal is left untouched.
     /// * If `shift > 1`, then the diagonal and the first `shift - 1` subdiagonals are left
-    /// untouched.
+    ///   untouched.
+    ///
+    /// In other words, the element at coordinates `(i, j)` is set to `val` if, and only if,
+    /// `i - j >= shift`. The matrix is traversed column by column. Setting the lower triangle
+    /// to zero with a given `shift` is the in-place equivalent of
+    /// `.upper_triangle_with_offset(1 - shift)`.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::Matrix3;
+    /// let mut m = Matrix3::new(1, 2, 3,
+    ///                          4, 5, 6,
+    ///                          7, 8, 9);
+    /// m.fill_lower_triangle(0, 1);
+    /// assert_eq!(m, Matrix3::new(1, 2, 3,
+    ///                            0, 5, 6,
+    ///                            0, 0, 9));
+    ///
+    /// m.fill_lower_triangle(-1, 2);
+    /// assert_eq!(m, Matrix3::new( 1, 2, 3,
+    ///                             0, 5, 6,
+    ///                            -1, 0, 9));
+    /// ```
     #[inline]
     pub fn fill_lower_triangle(&mut self, val: T, shift: usize)
     where
@@ -249,13 +422,35 @@ impl<T, R: Dim, C: Dim, S: RawStorageMut<T, R, C>> Matrix<T, R, C, S> {
         }
     }
 
-    /// Sets all the elements of the lower-triangular part of this matrix to `val`.
+    /// Sets all the elements of the upper-triangular part of this matrix to `val`.
     ///
     /// The parameter `shift` allows some superdiagonals to be left untouched:
     /// * If `shift = 0` then the diagonal is overwritten as well.
     /// * If `shift = 1` then the diagonal is left untouched.
     /// * If `shift > 1`, then the diagonal and the first `shift - 1` superdiagonals are left
-    /// untouched.
+    ///   untouched.
+    ///
+    /// In other words, the element at coordinates `(i, j)` is set to `val` if, and only if,
+    /// `j - i >= shift`. The matrix is traversed column by column. Setting the upper triangle
+    /// to zero with a given `shift` is the in-place equivalent of
+    /// `.lower_triangle_with_offset(shift - 1)`.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::Matrix3;
+    /// let mut m = Matrix3::new(1, 2, 3,
+    ///                          4, 5, 6,
+    ///                          7, 8, 9);
+    /// m.fill_upper_triangle(0, 1);
+    /// assert_eq!(m, Matrix3::new(1, 0, 0,
+    ///                            4, 5, 0,
+    ///                            7, 8, 9));
+    ///
+    /// m.fill_upper_triangle(-1, 0);
+    /// assert_eq!(m, Matrix3::new(-1, -1, -1,
+    ///                             4, -1, -1,
+    ///                             7,  8, -1));
+    /// ```
     #[inline]
     pub fn fill_upper_triangle(&mut self, val: T, shift: usize)
     where
@@ -335,6 +530,81 @@ impl<T: Scalar, R: Dim, C: Dim, S: RawStorageMut<T, R, C>> Matrix<T, R, C, S> {
     }
 }
 
+/// # In-place sorting
+impl<T: Scalar, R: Dim, C: Dim, S: RawStorageMut<T, R, C>> Matrix<T, R, C, S> {
+    /// Sorts the columns of this matrix in-place according to the comparator `cmp`.
+    ///
+    /// The sort is stable. Returns the applied permutation `perm`, such that the `i`-th column
+    /// of the sorted matrix is the `perm[i]`-th column of the original matrix. This can be used
+    /// to reorder any data associated to the columns (e.g. eigenvalues) identically.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::Matrix2x3;
+    /// let mut m = Matrix2x3::new(3.0, 1.0, 2.0,
+    ///                            6.0, 4.0, 5.0);
+    /// let perm = m.sort_columns_by(|a, b| a[0].partial_cmp(&b[0]).unwrap());
+    ///
+    /// assert_eq!(perm, vec![1, 2, 0]);
+    /// assert_eq!(m, Matrix2x3::new(1.0, 2.0, 3.0,
+    ///                              4.0, 5.0, 6.0));
+    /// ```
+    #[cfg(any(feature = "std", feature = "alloc"))]
+    pub fn sort_columns_by<F>(&mut self, mut cmp: F) -> Vec<usize>
+    where
+        F: FnMut(
+            &MatrixSlice<'_, T, R, U1, S::RStride, S::CStride>,
+            &MatrixSlice<'_, T, R, U1, S::RStride, S::CStride>,
+        ) -> cmp::Ordering,
+    {
+        let mut perm: Vec<usize> = (0..self.ncols()).collect();
+        perm.sort_by(|&i, &j| cmp(&self.column(i), &self.column(j)));
+        apply_permutation(&perm, |i, j| self.swap_columns(i, j));
+        perm
+    }
+
+    /// Sorts the rows of this matrix in-place according to the comparator `cmp`.
+    ///
+    /// The sort is stable. Returns the applied permutation `perm`, such that the `i`-th row
+    /// of the sorted matrix is the `perm[i]`-th row of the original matrix.
+    #[cfg(any(feature = "std", feature = "alloc"))]
+    pub fn sort_rows_by<F>(&mut self, mut cmp: F) -> Vec<usize>
+    where
+        F: FnMut(
+            &MatrixSlice<'_, T, U1, C, S::RStride, S::CStride>,
+            &MatrixSlice<'_, T, U1, C, S::RStride, S::CStride>,
+        ) -> cmp::Ordering,
+    {
+        let mut perm: Vec<usize> = (0..self.nrows()).collect();
+        perm.sort_by(|&i, &j| cmp(&self.row(i), &self.row(j)));
+        apply_permutation(&perm, |i, j| self.swap_rows(i, j));
+        perm
+    }
+}
+
+/// Applies the permutation `perm` using transpositions, such that the element at position `i`
+/// ends up being the one that was originally at position `perm[i]`.
+#[cfg(any(feature = "std", feature = "alloc"))]
+fn apply_permutation(perm: &[usize], mut swap: impl FnMut(usize, usize)) {
+    let mut visited = vec![false; perm.len()];
+
+    for start in 0..perm.len() {
+        let mut i = start;
+
+        while !visited[i] {
+            visited[i] = true;
+            let j = perm[i];
+
+            if j == start {
+                break;
+            }
+
+            swap(i, j);
+            i = j;
+        }
+    }
+}
+
 /*
  *
  * TODO: specialize all the following for slices.
@@ -954,10 +1224,15 @@ impl<T: Scalar, R: Dim, C: Dim, S: Storage<T, R, C>> Matrix<T, R, C, S> {
     /// columns. The arrangements of the component in the output matrix are the same as what
     /// would be obtained by `Matrix::from_slice_generic(self.as_slice(), new_nrows, new_ncols)`.
     ///
+    /// Since matrices are stored in column-major order, the components are read column by column
+    /// from `self`, and written column by column into the result.
+    ///
     /// If `self` is a dynamically-sized matrix, then its components are neither copied nor moved.
-    /// If `self` is staticyll-sized, then a copy may happen in some situations.
+    /// If `self` is statically-sized, then a copy may happen in some situations.
     /// This function will panic if the given dimensions are such that the number of elements of
-    /// the input matrix are not equal to the number of elements of the output matrix.
+    /// the input matrix are not equal to the number of elements of the output matrix. For
+    /// statically-sized matrices, this is checked at compile-time. See
+    /// [`Matrix::try_reshape_generic`] for a version that does not panic.
     ///
     /// # Examples
     ///
@@ -1014,6 +1289,87 @@ impl<T: Scalar, R: Dim, C: Dim, S: Storage<T, R, C>> Matrix<T, R, C, S> {
         let data = self.data.reshape_generic(new_nrows, new_ncols);
         Matrix::from_data(data)
     }
+
+    /// Reshapes `self` such that it has dimensions `new_nrows × new_ncols`, or returns `None` if
+    /// the number of components of `self` is not `new_nrows * new_ncols`.
+    ///
+    /// This is the same as [`Matrix::reshape_generic`], including the column-major
+    /// reinterpretation of the components, except that it does not panic on mismatched
+    /// dimensions.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use nalgebra::{DMatrix, Dynamic};
+    /// let dm = DMatrix::from_row_slice(2, 3, &[1, 2, 3,
+    ///                                          4, 5, 6]);
+    ///
+    /// let reshaped = dm.clone().try_reshape_generic(Dynamic::new(3), Dynamic::new(2));
+    /// assert_eq!(reshaped, Some(DMatrix::from_row_slice(3, 2, &[1, 5,
+    ///                                                           4, 3,
+    ///                                                           2, 6])));
+    /// assert!(dm.try_reshape_generic(Dynamic::new(4), Dynamic::new(2)).is_none());
+    /// ```
+    pub fn try_reshape_generic<R2, C2>(
+        self,
+        new_nrows: R2,
+        new_ncols: C2,
+    ) -> Option<Matrix<T, R2, C2, S::Output>>
+    where
+        R2: Dim,
+        C2: Dim,
+        S: ReshapableStorage<T, R, C, R2, C2>,
+    {
+        if new_nrows.value().checked_mul(new_ncols.value()) == Some(self.len()) {
+            Some(self.reshape_generic(new_nrows, new_ncols))
+        } else {
+            None
+        }
+    }
+}
+
+/// # Reshaping statically-sized matrices
+impl<T: Scalar, const R: usize, const C: usize> SMatrix<T, R, C> {
+    /// Reshapes `self` into a matrix with `R2` rows and `C2` columns.
+    ///
+    /// The components are reinterpreted in column-major order, as with
+    /// [`Matrix::reshape_generic`]. Reshaping into a matrix with a different number of components
+    /// does not compile.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use nalgebra::{Matrix2x3, Matrix3x2, Vector6};
+    /// let m = Matrix2x3::new(1, 2, 3,
+    ///                        4, 5, 6);
+    ///
+    /// let reshaped: Matrix3x2<_> = m.reshape();
+    /// assert_eq!(reshaped, Matrix3x2::new(1, 5,
+    ///                                     4, 3,
+    ///                                     2, 6));
+    /// assert_eq!(m.reshape::<6, 1>(), Vector6::new(1, 4, 2, 5, 3, 6));
+    /// ```
+    ///
+    /// ```compile_fail
+    /// # use nalgebra::Matrix2x3;
+    /// let m = Matrix2x3::new(1, 2, 3, 4, 5, 6);
+    /// let _ = m.reshape::<2, 2>();
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn reshape<const R2: usize, const C2: usize>(self) -> SMatrix<T, R2, C2>
+    where
+        ArrayStorage<T, R, C>: ReshapableStorage<
+            T,
+            Const<R>,
+            Const<C>,
+            Const<R2>,
+            Const<C2>,
+            Output = ArrayStorage<T, R2, C2>,
+        >,
+    {
+        self.reshape_generic(Const::<R2>, Const::<C2>)
+    }
 }
 
 /// # In-place resizing
@@ -62,6 +62,11 @@ impl<T: Scalar + Zero + One + ClosedAdd + ClosedSub + ClosedMul, D: Dim, S: Stor
 impl<T: RealField, D: Dim, S: Storage<T, D>> Unit<Vector<T, D, S>> {
     /// Computes the spherical linear interpolation between two unit vectors.
     ///
+    /// The result always lies on the unit sphere and follows the great circle from `self` to
+    /// `rhs`. If both vectors are opposite, there is an infinity of such great circles. In that
+    /// case, the interpolation goes through an arbitrary direction orthogonal to `self`. See
+    /// [`Self::try_slerp`] to detect this ambiguous case instead.
+    ///
     /// # Examples:
     ///
     /// ```
@@ -83,15 +88,52 @@ impl<T: RealField, D: Dim, S: Storage<T, D>> Unit<Vector<T, D, S>> {
     where
         DefaultAllocator: Allocator<T, D>,
     {
-        // TODO: the result is wrong when self and rhs are collinear with opposite direction.
-        self.try_slerp(rhs, t, T::default_epsilon())
-            .unwrap_or_else(|| Unit::new_unchecked(self.clone_owned()))
+        self.try_slerp(rhs, t.clone(), T::default_epsilon())
+            .unwrap_or_else(|| {
+                // The vectors are opposite: rotate through the canonical basis vector that is
+                // the furthest from `self`, orthogonalized wrt. `self`.
+                let i = self.iamin();
+                let mut perp = self.scale(-self[i].clone());
+                perp[i] += T::one();
+
+                match perp.try_normalize(T::zero()) {
+                    Some(perp) => {
+                        let angle = T::pi() * t;
+                        let mut res = self.scale(angle.clone().cos());
+                        res.axpy(angle.sin(), &perp, T::one());
+                        Unit::new_normalize(res)
+                    }
+                    // There is no orthogonal direction in 1D.
+                    None => Unit::new_unchecked(self.clone_owned()),
+                }
+            })
     }
 
     /// Computes the spherical linear interpolation between two unit vectors.
     ///
+    /// The result follows the great circle from `self` to `rhs`. This works for unit vectors of
+    /// any dimension.
+    ///
     /// Returns `None` if the two vectors are almost collinear and with opposite direction
-    /// (in this case, there is an infinity of possible results).
+    /// (in this case, there is an infinity of possible results). The `epsilon` is the value
+    /// below which the sinus of the angle separating both vectors must be for them to be
+    /// considered collinear. Almost identical vectors are interpolated linearly instead.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::{Unit, Vector3};
+    /// let v1 = Vector3::x_axis();
+    /// let v2 = Vector3::y_axis();
+    ///
+    /// let v = v1.try_slerp(&v2, 0.5, 1.0e-6).unwrap();
+    /// let expected = Unit::new_normalize(Vector3::new(1.0, 1.0, 0.0));
+    /// assert_relative_eq!(v, expected, epsilon = 1.0e-7);
+    ///
+    /// // Antipodal vectors have no unique great circle between them.
+    /// assert!(v1.try_slerp(&-v1, 0.5, 1.0e-6).is_none());
+    /// ```
     #[must_use]
     pub fn try_slerp<S2: Storage<T, D>>(
         &self,
@@ -109,12 +151,20 @@ impl<T: RealField, D: Dim, S: Storage<T, D>> Unit<Vector<T, D, S>> {
             return Some(Unit::new_unchecked(self.clone_owned()));
         }
 
+        let c_hang_is_negative = c_hang < T::zero();
         let hang = c_hang.clone().acos();
         let s_hang = (T::one() - c_hang.clone() * c_hang).sqrt();
 
-        // TODO: what if s_hang is 0.0 ? The result is not well-defined.
         if relative_eq!(s_hang, T::zero(), epsilon = epsilon) {
-            None
+            if c_hang_is_negative {
+                // Antipodal vectors: the great circle is not well-defined.
+                None
+            } else {
+                // Almost identical vectors: a linear interpolation is accurate enough.
+                let mut res = self.scale(T::one() - t.clone());
+                res.axpy(t, &**rhs, T::one());
+                Some(Unit::new_normalize(res))
+            }
         } else {
             let ta = ((T::one() - t.clone()) * hang.clone()).sin() / s_hang.clone();
             let tb = (t * hang).sin() / s_hang;
@@ -1,3 +1,6 @@
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::vec::Vec;
+
 use num::{One, Zero};
 
 use approx::{AbsDiffEq, RelativeEq, UlpsEq};
@@ -12,7 +15,7 @@ use std::mem;
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
 use simba::scalar::{ClosedAdd, ClosedMul, ClosedSub, Field, SupersetOf};
-use simba::simd::SimdPartialOrd;
+use simba::simd::SimdBool;
 
 use crate::base::allocator::{Allocator, SameShapeAllocator, SameShapeC, SameShapeR};
 use crate::base::constraint::{DimEq, SameNumberOfColumns, SameNumberOfRows, ShapeConstraint};
@@ -22,7 +25,10 @@ use crate::base::iter::{
 };
 use crate::base::storage::{Owned, RawStorage, RawStorageMut, SameShapeStorage};
 use crate::base::{Const, DefaultAllocator, OMatrix, OVector, Scalar, Unit};
-use crate::{ArrayStorage, SMatrix, SimdComplexField, Storage, UninitMatrix};
+use crate::{
+    ArrayStorage, ComplexField, RealField, SMatrix, SimdComplexField, SimdRealField, Storage,
+    UninitMatrix,
+};
 
 use crate::storage::IsContiguous;
 use crate::uninit::{Init, InitStatus, Uninit};
@@ -69,6 +75,7 @@ pub type MatrixCross<T, R1, C1, R2, C2> =
 ///   (`Matrix2xX`…)
 /// - [Generic constructors](#generic-constructors)
 ///   (For code generic wrt. the vectors or matrices dimensions.)
+/// - [Block matrix constructors <span style="float:right;">`hstack`, `vstack`, `block`</span>](#block-matrix-constructors)
 ///
 /// #### Computer graphics utilities for transformations
 /// - [2D transformations as a Matrix3 <span style="float:right;">`new_rotation`…</span>](#2d-transformations-as-a-matrix3)
@@ -760,6 +767,22 @@ impl<T, R: Dim, C: Dim, S: RawStorage<T, R, C>> Matrix<T, R, C, S> {
 
     /// Returns a matrix containing the result of `f` applied to each of its entries. Unlike `map`,
     /// `f` also gets passed the row and column index, i.e. `f(row, col, value)`.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::{DMatrix, Matrix2x3};
+    /// // Zero-out the entries below the diagonal.
+    /// let m = Matrix2x3::new(1.0, 2.0, 3.0,
+    ///                        4.0, 5.0, 6.0);
+    /// let upper = m.map_with_location(|i, j, e| if i > j { 0.0 } else { e });
+    /// assert_eq!(upper, Matrix2x3::new(1.0, 2.0, 3.0,
+    ///                                  0.0, 5.0, 6.0));
+    ///
+    /// // This works on matrix slices too and always produces an owned matrix.
+    /// let dm = DMatrix::from_element(3, 3, 1.0);
+    /// let weighted = dm.slice((1, 1), (2, 2)).map_with_location(|i, j, e| e * (i + j) as f64);
+    /// assert_eq!(weighted, DMatrix::from_row_slice(2, 2, &[0.0, 1.0, 1.0, 2.0]));
+    /// ```
     #[inline]
     #[must_use]
     pub fn map_with_location<T2: Scalar, F: FnMut(usize, usize, T) -> T2>(
@@ -789,6 +812,17 @@ impl<T, R: Dim, C: Dim, S: RawStorage<T, R, C>> Matrix<T, R, C, S> {
 
     /// Returns a matrix containing the result of `f` applied to each entries of `self` and
     /// `rhs`.
+    ///
+    /// Panics if `self` and `rhs` do not have the same dimensions.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::Vector3;
+    /// let y = Vector3::new(1.0, -1.0, 0.0);
+    /// let x = Vector3::new(1.0, 1.0, 2.0);
+    /// let angles = y.zip_map(&x, |y, x| f64::atan2(y, x));
+    /// assert_eq!(angles, Vector3::new(1.0f64.atan2(1.0), (-1.0f64).atan2(1.0), 0.0));
+    /// ```
     #[inline]
     #[must_use]
     pub fn zip_map<T2, N3, S2, F>(&self, rhs: &Matrix<T2, R, C, S2>, mut f: F) -> OMatrix<N3, R, C>
@@ -826,6 +860,18 @@ impl<T, R: Dim, C: Dim, S: RawStorage<T, R, C>> Matrix<T, R, C, S> {
 
     /// Returns a matrix containing the result of `f` applied to each entries of `self` and
     /// `b`, and `c`.
+    ///
+    /// Panics if `self`, `b`, and `c` do not have the same dimensions.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::Vector3;
+    /// let a = Vector3::new(0.0, 1.0, 2.0);
+    /// let b = Vector3::new(10.0, 10.0, 10.0);
+    /// let t = Vector3::new(0.0, 0.5, 1.0);
+    /// let blend = a.zip_zip_map(&b, &t, |a, b, t| a * (1.0 - t) + b * t);
+    /// assert_eq!(blend, Vector3::new(0.0, 5.5, 10.0));
+    /// ```
     #[inline]
     #[must_use]
     pub fn zip_zip_map<T2, N3, N4, S2, S3, F>(
@@ -875,6 +921,17 @@ impl<T, R: Dim, C: Dim, S: RawStorage<T, R, C>> Matrix<T, R, C, S> {
     }
 
     /// Folds a function `f` on each entry of `self`.
+    ///
+    /// The entries are traversed in column-major order.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::Matrix2x3;
+    /// let m = Matrix2x3::new(1, 2, 3,
+    ///                        4, 5, 6);
+    /// assert_eq!(m.fold(0, |acc, e| acc + e), 21);
+    /// assert_eq!(m.fold(Vec::new(), |mut acc, e| { acc.push(e); acc }), vec![1, 4, 2, 5, 3, 6]);
+    /// ```
     #[inline]
     #[must_use]
     pub fn fold<Acc>(&self, init: Acc, mut f: impl FnMut(Acc, T) -> Acc) -> Acc
@@ -898,7 +955,60 @@ impl<T, R: Dim, C: Dim, S: RawStorage<T, R, C>> Matrix<T, R, C, S> {
         res
     }
 
+    /// Folds a function `f` on each entry of `self`. Unlike `fold`, `f` also gets passed the row
+    /// and column index, i.e. `f(acc, row, col, value)`.
+    ///
+    /// The entries are traversed in column-major order.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::Matrix2x3;
+    /// let m = Matrix2x3::new(1, 2, 3,
+    ///                        4, 5, 6);
+    /// // Sum of the entries weighted by their column index.
+    /// assert_eq!(m.fold_with_index(0, |acc, _, j, e| acc + j * e), 1 * (2 + 5) + 2 * (3 + 6));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn fold_with_index<Acc>(
+        &self,
+        init: Acc,
+        mut f: impl FnMut(Acc, usize, usize, T) -> Acc,
+    ) -> Acc
+    where
+        T: Scalar,
+    {
+        let (nrows, ncols) = self.shape_generic();
+
+        let mut res = init;
+
+        for j in 0..ncols.value() {
+            for i in 0..nrows.value() {
+                // Safety: all indices are in range.
+                unsafe {
+                    let a = self.data.get_unchecked(i, j).clone();
+                    res = f(res, i, j, a)
+                }
+            }
+        }
+
+        res
+    }
+
     /// Folds a function `f` on each pairs of entries from `self` and `rhs`.
+    ///
+    /// The entries are traversed in column-major order. Panics if `self` and `rhs` do not have
+    /// the same dimensions.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::Vector3;
+    /// let residuals = Vector3::new(1.0, -2.0, 3.0);
+    /// let weights = Vector3::new(0.5, 1.0, 2.0);
+    /// // Weighted sum of squares.
+    /// let wss = residuals.zip_fold(&weights, 0.0, |acc, r, w| acc + w * r * r);
+    /// assert_eq!(wss, 0.5 + 4.0 + 18.0);
+    /// ```
     #[inline]
     #[must_use]
     pub fn zip_fold<T2, R2, C2, S2, Acc>(
@@ -1285,8 +1395,73 @@ impl<T, D: Dim, S: RawStorageMut<T, D>> Vector<T, D, S> {
     }
 }
 
+/// # Contiguous data access
+impl<T, R: Dim, C: Dim, S: RawStorage<T, R, C>> Matrix<T, R, C, S> {
+    /// Extracts a slice containing the entire matrix entries ordered column-by-columns, if they
+    /// are stored contiguously.
+    ///
+    /// Unlike `Self::as_slice`, this is available for any storage, including matrix slices, and
+    /// returns `None` at runtime if the components are not contiguous in memory, e.g., for a
+    /// slice of a subset of the rows of a matrix.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::Matrix2x3;
+    /// let m = Matrix2x3::new(1, 2, 3,
+    ///                        4, 5, 6);
+    ///
+    /// assert_eq!(m.columns(1, 2).try_as_slice(), Some(&[2, 5, 3, 6][..]));
+    /// assert_eq!(m.rows(0, 1).try_as_slice(), None);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn try_as_slice(&self) -> Option<&[T]> {
+        if self.data.is_contiguous() {
+            // Safety: the data has been checked to be contiguous.
+            Some(unsafe { self.data.as_slice_unchecked() })
+        } else {
+            None
+        }
+    }
+
+    /// Copies the entries of this matrix, ordered column-by-column, into a new `Vec`.
+    ///
+    /// This works for any storage, whether it is contiguous or not.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::Matrix2x3;
+    /// let m = Matrix2x3::new(1, 2, 3,
+    ///                        4, 5, 6);
+    ///
+    /// assert_eq!(m.flatten_to_vec(), vec![1, 4, 2, 5, 3, 6]);
+    /// assert_eq!(m.rows(1, 1).flatten_to_vec(), vec![4, 5, 6]);
+    /// ```
+    #[cfg(any(feature = "std", feature = "alloc"))]
+    #[must_use]
+    pub fn flatten_to_vec(&self) -> Vec<T>
+    where
+        T: Scalar,
+    {
+        self.iter().cloned().collect()
+    }
+}
+
 impl<T, R: Dim, C: Dim, S: RawStorage<T, R, C> + IsContiguous> Matrix<T, R, C, S> {
     /// Extracts a slice containing the entire matrix entries ordered column-by-columns.
+    ///
+    /// Matrices are stored in column-major order: the slice contains the first column, followed
+    /// by the second column, etc. This is only available for storages that are statically known
+    /// to be contiguous; see `Self::try_as_slice` for other storages like matrix slices.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::Matrix2x3;
+    /// let m = Matrix2x3::new(1, 2, 3,
+    ///                        4, 5, 6);
+    ///
+    /// assert_eq!(m.as_slice(), &[1, 4, 2, 5, 3, 6]);
+    /// ```
     #[inline]
     #[must_use]
     pub fn as_slice(&self) -> &[T] {
@@ -1558,6 +1733,78 @@ impl<T: Scalar, D: Dim, S: RawStorage<T, D, D>> SquareMatrix<T, D, S> {
     }
 }
 
+impl<T: Scalar + Zero + ClosedAdd, R: Dim, C: Dim, S: RawStorage<T, R, C>> Matrix<T, R, C, S> {
+    /// The sum of the elements of the main diagonal of this matrix.
+    ///
+    /// Unlike `.trace()`, this also accepts rectangular matrices: the main diagonal of a
+    /// matrix with `R` rows and `C` columns contains the `min(R, C)` elements at coordinates
+    /// `(i, i)`. For square matrices, this is equal to `.trace()`.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::{Matrix2x3, Matrix3x2};
+    /// let m = Matrix2x3::new(1.0, 2.0, 3.0,
+    ///                        4.0, 5.0, 6.0);
+    /// assert_eq!(m.diagonal_sum(), 6.0);
+    /// assert_eq!(m.transpose().diagonal_sum(), 6.0);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn diagonal_sum(&self) -> T {
+        let (nrows, ncols) = self.shape();
+        let mut res = T::zero();
+
+        for i in 0..nrows.min(ncols) {
+            res += unsafe { self.get_unchecked((i, i)).clone() };
+        }
+
+        res
+    }
+
+    /// Computes `(self * rhs).trace()` without computing the matrix product.
+    ///
+    /// This only computes the diagonal elements of the product, i.e., `R * C` multiplications
+    /// instead of `R * R * C`. The matrix `rhs` must have as many rows as `self` has columns,
+    /// and as many columns as `self` has rows.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::{Matrix2x3, Matrix3x2};
+    /// let a = Matrix2x3::new(1.0, 2.0, 3.0,
+    ///                        4.0, 5.0, 6.0);
+    /// let b = Matrix3x2::new(1.0, -1.0,
+    ///                        2.0,  0.5,
+    ///                       -3.0,  2.0);
+    /// assert_eq!(a.trace_mul(&b), (a * b).trace());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn trace_mul<R2: Dim, C2: Dim, SB>(&self, rhs: &Matrix<T, R2, C2, SB>) -> T
+    where
+        T: ClosedMul,
+        SB: RawStorage<T, R2, C2>,
+        ShapeConstraint: DimEq<C, R2> + DimEq<R, C2>,
+    {
+        assert_eq!(
+            (self.ncols(), self.nrows()),
+            rhs.shape(),
+            "Trace of matrix product: dimensions mismatch."
+        );
+
+        let mut res = T::zero();
+
+        for i in 0..self.nrows() {
+            for k in 0..self.ncols() {
+                res += unsafe {
+                    self.get_unchecked((i, k)).clone() * rhs.get_unchecked((k, i)).clone()
+                };
+            }
+        }
+
+        res
+    }
+}
+
 impl<T: SimdComplexField, D: Dim, S: Storage<T, D, D>> SquareMatrix<T, D, S> {
     /// The symmetric part of `self`, i.e., `0.5 * (self + self.transpose())`.
     #[inline]
@@ -1576,6 +1823,25 @@ impl<T: SimdComplexField, D: Dim, S: Storage<T, D, D>> SquareMatrix<T, D, S> {
         tr
     }
 
+    /// The skew-symmetric part of `self`, i.e., `0.5 * (self - self.transpose())`.
+    ///
+    /// The sum of the symmetric and skew-symmetric parts of `self` is equal to `self`.
+    #[inline]
+    #[must_use]
+    pub fn skew_symmetric_part(&self) -> OMatrix<T, D, D>
+    where
+        DefaultAllocator: Allocator<T, D, D>,
+    {
+        assert!(
+            self.is_square(),
+            "Cannot compute the skew-symmetric part of a non-square matrix."
+        );
+        let mut res = self.clone_owned();
+        res -= self.transpose();
+        res *= crate::convert::<_, T>(0.5);
+        res
+    }
+
     /// The hermitian part of `self`, i.e., `0.5 * (self + self.adjoint())`.
     #[inline]
     #[must_use]
@@ -1595,6 +1861,44 @@ impl<T: SimdComplexField, D: Dim, S: Storage<T, D, D>> SquareMatrix<T, D, S> {
     }
 }
 
+impl<T: SimdComplexField, D: Dim, S: RawStorageMut<T, D, D>> SquareMatrix<T, D, S> {
+    /// Replaces `self` by its symmetric part, i.e., `0.5 * (self + self.transpose())`, in-place.
+    ///
+    /// This is the in-place equivalent of `.symmetric_part()`, useful to restore the exact
+    /// symmetry of a matrix after it was lost due to rounding errors. Panics if `self` is not
+    /// square.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::Matrix2;
+    /// let mut m = Matrix2::new(1.0, 2.0,
+    ///                          4.0, 3.0);
+    /// m.symmetrize();
+    /// assert_eq!(m, Matrix2::new(1.0, 3.0,
+    ///                            3.0, 3.0));
+    /// assert!(m.is_symmetric(0.0));
+    /// ```
+    #[inline]
+    pub fn symmetrize(&mut self) {
+        assert!(self.is_square(), "Cannot symmetrize a non-square matrix.");
+
+        let dim = self.nrows();
+        let half: T = crate::convert(0.5);
+
+        for j in 0..dim {
+            for i in j + 1..dim {
+                unsafe {
+                    let mean = (self.get_unchecked((i, j)).clone()
+                        + self.get_unchecked((j, i)).clone())
+                        * half.clone();
+                    *self.get_unchecked_mut((i, j)) = mean.clone();
+                    *self.get_unchecked_mut((j, i)) = mean;
+                }
+            }
+        }
+    }
+}
+
 impl<T: Scalar + Zero + One, D: DimAdd<U1> + IsNotStaticOne, S: RawStorage<T, D, D>>
     Matrix<T, D, D, S>
 {
@@ -2067,6 +2371,21 @@ impl<T: Scalar + ClosedAdd + ClosedSub + ClosedMul, R: Dim, C: Dim, S: RawStorag
 
 impl<T: Scalar + Field, S: RawStorage<T, U3>> Vector<T, U3, S> {
     /// Computes the matrix `M` such that for all vector `v` we have `M * v == self.cross(&v)`.
+    ///
+    /// This is the skew-symmetric matrix `[self]×`, also known as the `hat` map of `so(3)`. Its
+    /// inverse is [`Matrix::uncross`].
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::{Matrix3, Vector3};
+    /// let a = Vector3::new(1.0, 2.0, 3.0);
+    /// let b = Vector3::new(4.0, 5.0, 6.0);
+    /// let m = a.cross_matrix();
+    ///
+    /// assert_eq!(m, Matrix3::new(0.0, -3.0, 2.0, 3.0, 0.0, -1.0, -2.0, 1.0, 0.0));
+    /// assert_eq!(m * b, a.cross(&b));
+    /// assert_eq!(m.uncross(), a);
+    /// ```
     #[inline]
     #[must_use]
     pub fn cross_matrix(&self) -> OMatrix<T, U3, U3> {
@@ -2084,8 +2403,59 @@ impl<T: Scalar + Field, S: RawStorage<T, U3>> Vector<T, U3, S> {
     }
 }
 
+impl<T: Scalar + Field, S: RawStorage<T, U3, U3>> Matrix<T, U3, U3, S> {
+    /// Computes the vector `v` such that `v.cross_matrix()` is the skew-symmetric part of `self`.
+    ///
+    /// This is the `vee` map of `so(3)`, i.e., the inverse of [`Vector::cross_matrix`]. Each
+    /// component is the half-difference of a pair of opposite off-diagonal entries, so the
+    /// diagonal and the symmetric part of `self` are ignored.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::{Matrix3, Vector3};
+    /// let m = Matrix3::new(0.0, -3.0, 2.0,
+    ///                      3.0, 0.0, -1.0,
+    ///                     -2.0, 1.0, 0.0);
+    /// assert_eq!(m.uncross(), Vector3::new(1.0, 2.0, 3.0));
+    ///
+    /// // The symmetric part is discarded.
+    /// let sym = Matrix3::new(1.0, 2.0, 3.0, 2.0, 4.0, 5.0, 3.0, 5.0, 6.0);
+    /// assert_eq!((m + sym).uncross(), Vector3::new(1.0, 2.0, 3.0));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn uncross(&self) -> OVector<T, U3> {
+        let two = T::one() + T::one();
+
+        OVector::<T, U3>::new(
+            (self[(2, 1)].clone() - self[(1, 2)].clone()) / two.clone(),
+            (self[(0, 2)].clone() - self[(2, 0)].clone()) / two.clone(),
+            (self[(1, 0)].clone() - self[(0, 1)].clone()) / two,
+        )
+    }
+}
+
 impl<T: SimdComplexField, R: Dim, C: Dim, S: Storage<T, R, C>> Matrix<T, R, C, S> {
-    /// The smallest angle between two vectors.
+    /// The smallest angle between two vectors, in `[0, π]`.
+    ///
+    /// This uses the formula `2 * atan2(‖a‖b‖ - ‖b‖a‖, ‖a‖b‖ + ‖b‖a‖)` which, unlike
+    /// `acos(a·b / (‖a‖‖b‖))`, remains accurate for nearly collinear vectors. Returns zero if
+    /// any of the two vectors is zero.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::Vector3;
+    /// # use std::f64::consts::FRAC_PI_2;
+    /// let a = Vector3::new(1.0, 0.0, 0.0);
+    /// let b = Vector3::new(0.0, 2.0, 0.0);
+    /// assert_relative_eq!(a.angle(&b), FRAC_PI_2);
+    ///
+    /// // Tiny angles are not lost to rounding.
+    /// let c = Vector3::new(1.0, 1.0e-10, 0.0);
+    /// assert_relative_eq!(a.angle(&c), 1.0e-10, max_relative = 1.0e-6);
+    /// ```
     #[inline]
     #[must_use]
     pub fn angle<R2: Dim, C2: Dim, SB>(&self, other: &Matrix<T, R2, C2, SB>) -> T::SimdRealField
@@ -2093,20 +2463,299 @@ impl<T: SimdComplexField, R: Dim, C: Dim, S: Storage<T, R, C>> Matrix<T, R, C, S
         SB: Storage<T, R2, C2>,
         ShapeConstraint: DimEq<R, R2> + DimEq<C, C2>,
     {
-        let prod = self.dotc(other);
+        assert_eq!(
+            self.shape(),
+            other.shape(),
+            "Angle: vector dimension mismatch."
+        );
+
         let n1 = self.norm();
         let n2 = other.norm();
 
         if n1.is_zero() || n2.is_zero() {
             T::SimdRealField::zero()
         } else {
-            let cang = prod.simd_real() / (n1 * n2);
-            cang.simd_clamp(-T::SimdRealField::one(), T::SimdRealField::one())
-                .simd_acos()
+            let mut diff2 = T::SimdRealField::zero();
+            let mut sum2 = T::SimdRealField::zero();
+
+            for (a, b) in self.iter().zip(other.iter()) {
+                let a = a.clone().simd_scale(n2.clone());
+                let b = b.clone().simd_scale(n1.clone());
+                diff2 += (a.clone() - b.clone()).simd_modulus_squared();
+                sum2 += (a + b).simd_modulus_squared();
+            }
+
+            let two = T::SimdRealField::one() + T::SimdRealField::one();
+            diff2.simd_sqrt().simd_atan2(sum2.simd_sqrt()) * two
         }
     }
 }
 
+impl<T: SimdRealField, S: Storage<T, U2>> Vector<T, U2, S> {
+    /// The signed angle, in `(-π, π]`, of the rotation from `self` to `other`.
+    ///
+    /// The angle is positive if `other` is counterclockwise from `self`. Returns zero if any of
+    /// the two vectors is zero.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::Vector2;
+    /// # use std::f64::consts::FRAC_PI_2;
+    /// let a = Vector2::new(1.0, 0.0);
+    /// let b = Vector2::new(0.0, 3.0);
+    /// assert_relative_eq!(a.angle_signed(&b), FRAC_PI_2);
+    /// assert_relative_eq!(b.angle_signed(&a), -FRAC_PI_2);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn angle_signed<SB: Storage<T, U2>>(&self, other: &Vector<T, U2, SB>) -> T {
+        let angle = self.perp(other).simd_atan2(self.dot(other));
+        let pi = T::simd_pi();
+
+        // `atan2` returns `-π` for antiparallel vectors if the perp product is `-0.0`.
+        angle.clone().simd_le(-pi.clone()).if_else(|| pi, || angle)
+    }
+}
+
+impl<T: RealField, S: Storage<T, U3>> Vector<T, U3, S> {
+    /// The signed angle, in `[-π, π]`, between `self` and `other` as seen from the tip of `axis`.
+    ///
+    /// The magnitude of the result is the unsigned angle between both vectors. It is
+    /// positive if the rotation from `self` to `other` is counterclockwise around `axis`, i.e.,
+    /// if `self.cross(other)` points in the same direction as `axis`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::Vector3;
+    /// # use std::f64::consts::FRAC_PI_2;
+    /// let a = Vector3::<f64>::x();
+    /// let b = Vector3::y();
+    /// assert_relative_eq!(a.angle_signed_about(&b, &Vector3::z()), FRAC_PI_2);
+    /// assert_relative_eq!(a.angle_signed_about(&b, &-Vector3::z()), -FRAC_PI_2);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn angle_signed_about<SB, SC>(
+        &self,
+        other: &Vector<T, U3, SB>,
+        axis: &Vector<T, U3, SC>,
+    ) -> T
+    where
+        SB: Storage<T, U3>,
+        SC: Storage<T, U3>,
+    {
+        let cross = self.cross(other);
+        let angle = cross.norm().atan2(self.dot(other));
+
+        if cross.dot(axis) < T::zero() {
+            -angle
+        } else {
+            angle
+        }
+    }
+}
+
+/// # Reflection and refraction
+impl<T: RealField, D: Dim, S: Storage<T, D>> Vector<T, D, S> {
+    /// Reflects `self` about the surface with the given `normal`.
+    ///
+    /// This computes `self - 2 * normal.dot(self) * normal`, like the GLSL `reflect` function.
+    /// The result does not depend on the orientation of `normal`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use nalgebra::Vector3;
+    /// let incident = Vector3::new(1.0, -1.0, 0.0);
+    /// let reflected = incident.reflect(&Vector3::y_axis());
+    /// assert_eq!(reflected, Vector3::new(1.0, 1.0, 0.0));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn reflect<S2: Storage<T, D>>(&self, normal: &Unit<Vector<T, D, S2>>) -> OVector<T, D>
+    where
+        DefaultAllocator: Allocator<T, D>,
+    {
+        let two = T::one() + T::one();
+        let mut res = self.clone_owned();
+        res.axpy(-normal.dot(self) * two, &**normal, T::one());
+        res
+    }
+
+    /// Refracts `self` through the surface with the given `normal`, following Snell's law.
+    ///
+    /// Like the GLSL `refract` function, `self` is expected to be the normalized incident
+    /// direction, `normal` must point against it (i.e. `normal.dot(self) <= 0`), and `eta` is
+    /// the ratio between the indices of refraction of the medium containing the incident ray
+    /// and of the medium it enters.
+    ///
+    /// Returns `None` in case of total internal reflection, in which case no light is refracted.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::{Unit, Vector2};
+    /// let incident = Unit::new_normalize(Vector2::new(1.0, -1.0));
+    /// let normal = Vector2::y_axis();
+    ///
+    /// // Entering a denser medium bends the ray toward the normal.
+    /// let refracted = incident.refract(&normal, 1.0 / 1.5).unwrap();
+    /// assert_relative_eq!(refracted.x, incident.x / 1.5);
+    /// assert_relative_eq!(refracted.norm(), 1.0);
+    ///
+    /// // Grazing rays leaving a denser medium are totally reflected.
+    /// assert!(incident.refract(&normal, 1.5).is_none());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn refract<S2: Storage<T, D>>(
+        &self,
+        normal: &Unit<Vector<T, D, S2>>,
+        eta: T,
+    ) -> Option<OVector<T, D>>
+    where
+        DefaultAllocator: Allocator<T, D>,
+    {
+        let ni = normal.dot(self);
+        let k = T::one() - eta.clone() * eta.clone() * (T::one() - ni.clone() * ni.clone());
+
+        if k < T::zero() {
+            None
+        } else {
+            let mut res = self.scale(eta.clone());
+            res.axpy(-(eta * ni + k.sqrt()), &**normal, T::one());
+            Some(res)
+        }
+    }
+}
+
+/// # Projection and rejection
+impl<T: ComplexField, D: Dim, S: Storage<T, D>> Vector<T, D, S> {
+    /// The vector projection of `self` onto the line spanned by `other`.
+    ///
+    /// Returns the zero vector if `other` is zero.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use nalgebra::Vector3;
+    /// let v = Vector3::new(1.0, 2.0, 3.0);
+    /// let dir = Vector3::new(0.0, 0.0, 2.0);
+    /// assert_eq!(v.project_onto(&dir), Vector3::new(0.0, 0.0, 3.0));
+    /// assert_eq!(v.project_onto(&Vector3::zeros()), Vector3::zeros());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn project_onto<S2: Storage<T, D>>(&self, other: &Vector<T, D, S2>) -> OVector<T, D>
+    where
+        DefaultAllocator: Allocator<T, D>,
+    {
+        let sq_norm = other.norm_squared();
+
+        if sq_norm.is_zero() {
+            other.clone_owned()
+        } else {
+            other.scale(T::RealField::one() / sq_norm) * other.dotc(self)
+        }
+    }
+
+    /// The vector projection of `self` onto the line spanned by the unit vector `dir`.
+    ///
+    /// This is equivalent to `self.project_onto(&dir)`, but skips the normalization since `dir`
+    /// is already known to be unit-length.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use nalgebra::Vector3;
+    /// let v = Vector3::new(1.0, 2.0, 3.0);
+    /// assert_eq!(v.project_onto_unit(&Vector3::y_axis()), Vector3::new(0.0, 2.0, 0.0));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn project_onto_unit<S2: Storage<T, D>>(
+        &self,
+        dir: &Unit<Vector<T, D, S2>>,
+    ) -> OVector<T, D>
+    where
+        DefaultAllocator: Allocator<T, D>,
+    {
+        dir.as_ref() * dir.dotc(self)
+    }
+
+    /// The component of `self` orthogonal to the unit vector `dir`, i.e.,
+    /// `self - self.project_onto_unit(dir)`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use nalgebra::Vector3;
+    /// let v = Vector3::new(1.0, 2.0, 3.0);
+    /// assert_eq!(v.reject_from_unit(&Vector3::y_axis()), Vector3::new(1.0, 0.0, 3.0));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn reject_from_unit<S2: Storage<T, D>>(&self, dir: &Unit<Vector<T, D, S2>>) -> OVector<T, D>
+    where
+        DefaultAllocator: Allocator<T, D>,
+    {
+        self - self.project_onto_unit(dir)
+    }
+
+    /// The component of `self` orthogonal to `other`, i.e., `self - self.project_onto(other)`.
+    ///
+    /// Returns a copy of `self` if `other` is zero.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use nalgebra::Vector3;
+    /// let v = Vector3::new(1.0, 2.0, 3.0);
+    /// let dir = Vector3::new(0.0, 0.0, 2.0);
+    /// assert_eq!(v.reject_from(&dir), Vector3::new(1.0, 2.0, 0.0));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn reject_from<S2: Storage<T, D>>(&self, other: &Vector<T, D, S2>) -> OVector<T, D>
+    where
+        DefaultAllocator: Allocator<T, D>,
+    {
+        self.decompose_along(other).1
+    }
+
+    /// Splits `self` into its components parallel and orthogonal to `other`.
+    ///
+    /// Returns `(self.project_onto(other), self.reject_from(other))`, whose sum is `self`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use nalgebra::Vector2;
+    /// let v = Vector2::new(3.0, 1.0);
+    /// let (parallel, perpendicular) = v.decompose_along(&Vector2::new(1.0, 1.0));
+    /// assert_eq!(parallel, Vector2::new(2.0, 2.0));
+    /// assert_eq!(perpendicular, Vector2::new(1.0, -1.0));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn decompose_along<S2: Storage<T, D>>(
+        &self,
+        other: &Vector<T, D, S2>,
+    ) -> (OVector<T, D>, OVector<T, D>)
+    where
+        DefaultAllocator: Allocator<T, D>,
+    {
+        let parallel = self.project_onto(other);
+        let perpendicular = self - &parallel;
+        (parallel, perpendicular)
+    }
+}
+
 impl<T, R: Dim, C: Dim, S> AbsDiffEq for Unit<Matrix<T, R, C, S>>
 where
     T: Scalar + AbsDiffEq,
@@ -4,10 +4,12 @@ use std::slice;
 
 use crate::base::allocator::Allocator;
 use crate::base::default_allocator::DefaultAllocator;
-use crate::base::dimension::{Const, Dim, DimName, Dynamic, IsNotStaticOne, U1};
+use crate::base::dimension::{
+    Const, Dim, DimMin, DimMinimum, DimName, Dynamic, IsNotStaticOne, U1,
+};
 use crate::base::iter::MatrixIter;
 use crate::base::storage::{IsContiguous, Owned, RawStorage, RawStorageMut, Storage};
-use crate::base::{Matrix, Scalar};
+use crate::base::{Matrix, Scalar, VectorSliceMut};
 
 macro_rules! slice_storage_impl(
     ($doc: expr; $Storage: ident as $SRef: ty; $T: ident.$get_addr: ident ($Ptr: ty as $Ref: ty)) => {
@@ -324,7 +326,16 @@ macro_rules! matrix_slice_impl(
             $me.$rows_generic(first_row, Dynamic::new(nrows))
         }
 
-        /// Extracts from this matrix a set of consecutive rows regularly skipping `step` rows.
+        /// Extracts from this matrix a set of rows regularly skipping `step` rows.
+        ///
+        /// The `i`-th row of the returned slice is the row `first_row + i * (step + 1)` of this
+        /// matrix. Note that `step` is the number of rows *skipped* between two consecutive
+        /// rows of the slice, not the stride: `step = 0` selects consecutive rows while
+        /// `step = 1` selects every other row. No data is copied: the slice borrows this
+        /// matrix with a row stride equal to `step + 1` times its own row stride.
+        ///
+        /// Panics if the last selected row, `first_row + (nrows - 1) * (step + 1)`, is out
+        /// of bounds.
         #[inline]
         pub fn $rows_with_step($me: $Me, first_row: usize, nrows: usize, step: usize)
             -> $MatrixSlice<'_, T, Dynamic, C, Dynamic, S::CStride> {
@@ -411,8 +422,17 @@ macro_rules! matrix_slice_impl(
             $me.$columns_generic(first_col, Dynamic::new(ncols))
         }
 
-        /// Extracts from this matrix a set of consecutive columns regularly skipping `step`
-        /// columns.
+        /// Extracts from this matrix a set of columns regularly skipping `step` columns.
+        ///
+        /// The `j`-th column of the returned slice is the column `first_col + j * (step + 1)`
+        /// of this matrix. Note that `step` is the number of columns *skipped* between two
+        /// consecutive columns of the slice, not the stride: `step = 0` selects consecutive
+        /// columns while `step = 1` selects every other column. No data is copied: the slice
+        /// borrows this matrix with a column stride equal to `step + 1` times its own column
+        /// stride.
+        ///
+        /// Panics if the last selected column, `first_col + (ncols - 1) * (step + 1)`, is out
+        /// of bounds.
         #[inline]
         pub fn $columns_with_step($me: $Me, first_col: usize, ncols: usize, step: usize)
             -> $MatrixSlice<'_, T, R, Dynamic, S::RStride, Dynamic> {
@@ -499,6 +519,10 @@ macro_rules! matrix_slice_impl(
         /// `(shape.0, shape.1)` components. Each row (resp. column) of the sliced matrix is
         /// separated by `steps.0` (resp. `steps.1`) ignored rows (resp. columns) of the
         /// original matrix.
+        ///
+        /// The component `(i, j)` of the slice is the component
+        /// `(start.0 + i * (steps.0 + 1), start.1 + j * (steps.1 + 1))` of this matrix, so
+        /// `steps = (0, 0)` is equivalent to a contiguous slice.
         #[inline]
         pub fn $slice_with_steps($me: $Me, start: (usize, usize), shape: (usize, usize), steps: (usize, usize))
             -> $MatrixSlice<'_, T, Dynamic, Dynamic, Dynamic, Dynamic> {
@@ -716,6 +740,47 @@ impl<T, R: Dim, C: Dim, S: RawStorageMut<T, R, C>> Matrix<T, R, C, S> {
      columns_range_pair_mut);
 }
 
+impl<T, R: DimMin<C>, C: Dim, S: RawStorageMut<T, R, C>> Matrix<T, R, C, S> {
+    /// A mutable vector slice aliasing the main diagonal of this matrix.
+    ///
+    /// The main diagonal contains the `min(nrows, ncols)` elements at coordinates `(i, i)`.
+    /// Unlike `.diagonal()`, this does not copy the diagonal: modifying the returned slice
+    /// modifies this matrix in place. Its stride is the sum of the row and column strides
+    /// of this matrix.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::{Matrix2x3, Matrix3};
+    /// let mut m = Matrix3::new(1.0, 2.0, 3.0,
+    ///                          4.0, 5.0, 6.0,
+    ///                          7.0, 8.0, 9.0);
+    /// // Add a regularization term to the diagonal.
+    /// m.diagonal_mut().add_scalar_mut(0.5);
+    /// assert_eq!(m, Matrix3::new(1.5, 2.0, 3.0,
+    ///                            4.0, 5.5, 6.0,
+    ///                            7.0, 8.0, 9.5));
+    ///
+    /// let mut r = Matrix2x3::new(1.0, 2.0, 3.0,
+    ///                            4.0, 5.0, 6.0);
+    /// r.diagonal_mut().fill(0.0);
+    /// assert_eq!(r, Matrix2x3::new(0.0, 2.0, 3.0,
+    ///                              4.0, 0.0, 6.0));
+    /// ```
+    #[inline]
+    pub fn diagonal_mut(&mut self) -> VectorSliceMut<'_, T, DimMinimum<R, C>, Dynamic, S::CStride> {
+        let (nrows, ncols) = self.shape_generic();
+        let (rstride, cstride) = self.data.strides();
+        let shape = (nrows.min(ncols), Const::<1>);
+        let strides = (Dynamic::new(rstride.value() + cstride.value()), cstride);
+
+        unsafe {
+            let data =
+                SliceStorageMut::new_with_strides_unchecked(&mut self.data, (0, 0), shape, strides);
+            Matrix::from_data_statically_unchecked(data)
+        }
+    }
+}
+
 /// A range with a size that may be known at compile-time.
 ///
 /// This may be:
@@ -14,7 +14,7 @@ use crate::base::constraint::{
 use crate::base::dimension::{Dim, DimMul, DimName, DimProd, Dynamic};
 use crate::base::storage::{Storage, StorageMut};
 use crate::base::uninit::Uninit;
-use crate::base::{DefaultAllocator, Matrix, MatrixSum, OMatrix, Scalar, VectorSlice};
+use crate::base::{DefaultAllocator, Matrix, MatrixSum, OMatrix, Scalar, Vector, VectorSlice};
 use crate::storage::IsContiguous;
 use crate::uninit::{Init, InitStatus};
 use crate::{RawStorage, RawStorageMut, SimdComplexField};
@@ -793,6 +793,27 @@ where
 
     /// The kronecker product of two matrices (aka. tensor product of the corresponding linear
     /// maps).
+    ///
+    /// Both operands may have any combination of static and dynamic dimensions. Each dimension
+    /// of the result is the product of the corresponding dimensions of the operands: it is
+    /// static if both are static, and dynamic otherwise.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::{DMatrix, Matrix2, Matrix2x3, RowVector3, Vector2};
+    /// let a = Matrix2::new(1, 2,
+    ///                      3, 4);
+    /// let b = DMatrix::from_element(1, 2, 10);
+    ///
+    /// let expected = DMatrix::from_row_slice(2, 4, &[10, 10, 20, 20,
+    ///                                                30, 30, 40, 40]);
+    /// assert_eq!(a.kronecker(&b), expected);
+    ///
+    /// // The product of two static matrices is static.
+    /// let c: Matrix2x3<i32> = Vector2::new(1, 2).kronecker(&RowVector3::new(1, 2, 3));
+    /// assert_eq!(c, Matrix2x3::new(1, 2, 3,
+    ///                              2, 4, 6));
+    /// ```
     #[must_use]
     pub fn kronecker<R2: Dim, C2: Dim, SB>(
         &self,
@@ -831,6 +852,113 @@ where
             res.assume_init()
         }
     }
+
+    /// The kronecker sum `self ⊗ I + I ⊗ rhs` of two square matrices, where the identity
+    /// matrices have the same dimensions as `rhs` and `self` respectively.
+    ///
+    /// This is the linear map whose exponential is the kronecker product of the exponentials of
+    /// `self` and `rhs`. It typically appears as the discretization of separable operators on
+    /// tensor-product grids, e.g., the 2D Laplacian is the kronecker sum of two 1D Laplacians.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` or `rhs` is not square.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::{Matrix1, Matrix2, Matrix4};
+    /// let a = Matrix2::new(1, 2,
+    ///                      3, 4);
+    /// let b = Matrix2::new(10, 20,
+    ///                      30, 40);
+    ///
+    /// let expected = Matrix4::new(11, 20,  2,  0,
+    ///                             30, 41,  0,  2,
+    ///                              3,  0, 14, 20,
+    ///                              0,  3, 30, 44);
+    /// assert_eq!(a.kronecker_sum(&b), expected);
+    /// assert_eq!(a.kronecker_sum(&b), a.kronecker(&Matrix2::identity()) + Matrix2::identity().kronecker(&b));
+    ///
+    /// // With a 1x1 matrix, this adds a multiple of the identity.
+    /// assert_eq!(a.kronecker_sum(&Matrix1::new(5)), a + Matrix2::identity() * 5);
+    /// ```
+    #[must_use]
+    pub fn kronecker_sum<R2: Dim, C2: Dim, SB>(
+        &self,
+        rhs: &Matrix<T, R2, C2, SB>,
+    ) -> OMatrix<T, DimProd<R1, R2>, DimProd<C1, C2>>
+    where
+        R1: DimMul<R2>,
+        C1: DimMul<C2>,
+        SB: Storage<T, R2, C2>,
+        DefaultAllocator: Allocator<T, DimProd<R1, R2>, DimProd<C1, C2>>,
+    {
+        assert!(
+            self.is_square() && rhs.is_square(),
+            "Kronecker sum: both matrices must be square."
+        );
+
+        let (nrows1, ncols1) = self.shape_generic();
+        let (nrows2, ncols2) = rhs.shape_generic();
+        let dim2 = nrows2.value();
+
+        OMatrix::from_fn_generic(nrows1.mul(nrows2), ncols1.mul(ncols2), |i, j| {
+            let (i1, i2) = (i / dim2, i % dim2);
+            let (j1, j2) = (j / dim2, j % dim2);
+            let mut res = T::zero();
+
+            // SAFETY: the indices are within the dimensions of `self` and `rhs`.
+            unsafe {
+                if i2 == j2 {
+                    res += self.get_unchecked((i1, j1)).clone();
+                }
+
+                if i1 == j1 {
+                    res += rhs.get_unchecked((i2, j2)).clone();
+                }
+            }
+
+            res
+        })
+    }
+}
+
+impl<T, D1: Dim, SA> Vector<T, D1, SA>
+where
+    T: Scalar + ClosedMul,
+    SA: Storage<T, D1>,
+{
+    /// The outer product `self * rhs.transpose()` of two vectors.
+    ///
+    /// The result has as many rows as `self` has components, and as many columns as `rhs` has
+    /// components. Its component `(i, j)` is `self[i] * rhs[j]`.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::{DMatrix, DVector, Matrix2x3, Vector2, Vector3};
+    /// let a = Vector2::new(1, 2);
+    /// let b = Vector3::new(3, 4, 5);
+    /// assert_eq!(a.outer_product(&b), Matrix2x3::new(3, 4, 5,
+    ///                                                6, 8, 10));
+    ///
+    /// let c = DVector::from_vec(vec![1, -1]);
+    /// assert_eq!(c.outer_product(&c), DMatrix::from_row_slice(2, 2, &[1, -1,
+    ///                                                                 -1, 1]));
+    /// ```
+    #[must_use]
+    pub fn outer_product<D2: Dim, SB>(&self, rhs: &Vector<T, D2, SB>) -> OMatrix<T, D1, D2>
+    where
+        SB: Storage<T, D2>,
+        DefaultAllocator: Allocator<T, D1, D2>,
+    {
+        let nrows = self.shape_generic().0;
+        let ncols = rhs.shape_generic().0;
+
+        // SAFETY: the indices are within the dimensions of `self` and `rhs`.
+        OMatrix::from_fn_generic(nrows, ncols, |i, j| unsafe {
+            self.vget_unchecked(i).clone() * rhs.vget_unchecked(j).clone()
+        })
+    }
 }
 
 impl<T, D: DimName> iter::Product for OMatrix<T, D, D>
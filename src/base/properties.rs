@@ -2,12 +2,12 @@
 use approx::RelativeEq;
 use num::{One, Zero};
 
-use simba::scalar::{ClosedAdd, ClosedMul, ComplexField, RealField};
+use simba::scalar::{ClosedAdd, ClosedMul, ClosedNeg, ComplexField, RealField};
 
 use crate::base::allocator::Allocator;
-use crate::base::dimension::{Dim, DimMin};
+use crate::base::dimension::{Const, Dim, DimMin};
 use crate::base::storage::Storage;
-use crate::base::{DefaultAllocator, Matrix, SquareMatrix};
+use crate::base::{DefaultAllocator, Matrix, OVector, Scalar, SquareMatrix};
 use crate::RawStorage;
 
 impl<T, R: Dim, C: Dim, S: RawStorage<T, R, C>> Matrix<T, R, C, S> {
@@ -77,9 +77,129 @@ impl<T, R: Dim, C: Dim, S: RawStorage<T, R, C>> Matrix<T, R, C, S> {
 
         true
     }
+
+    /// Indicates if this matrix is symmetric, i.e., if `M == Mᵀ` within a relative error of `eps`.
+    ///
+    /// This only makes sense for square matrices: `false` is returned if `self` is not square.
+    /// Note that, for complex matrices, this does not conjugate any element.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// # use nalgebra::{Matrix2x3, Matrix3};
+    /// let m = Matrix3::new(1.0, 2.0, 3.0,
+    ///                      2.0, 4.0, 5.0,
+    ///                      3.0, 5.0, 6.0);
+    /// assert!(m.is_symmetric(1.0e-7));
+    /// assert!(!Matrix3::new(1.0, 2.0, 3.0, 2.1, 4.0, 5.0, 3.0, 5.0, 6.0).is_symmetric(1.0e-7));
+    /// assert!(!Matrix2x3::<f64>::zeros().is_symmetric(1.0e-7));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn is_symmetric(&self, eps: T::Epsilon) -> bool
+    where
+        T: RelativeEq,
+        T::Epsilon: Clone,
+    {
+        if !self.is_square() {
+            return false;
+        }
+
+        let dim = self.nrows();
+
+        for j in 0..dim {
+            for i in j + 1..dim {
+                let (a, b) = unsafe { (self.get_unchecked((i, j)), self.get_unchecked((j, i))) };
+                if !relative_eq!(*a, *b, epsilon = eps.clone()) {
+                    return false;
+                }
+            }
+        }
+
+        true
+    }
+
+    /// Indicates if this matrix is skew-symmetric, i.e., if `M == -Mᵀ` within a relative error of
+    /// `eps`.
+    ///
+    /// This only makes sense for square matrices: `false` is returned if `self` is not square.
+    /// The diagonal elements of a skew-symmetric matrix must be zero.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// # use nalgebra::{Matrix3, Vector3};
+    /// let m = Vector3::new(1.0, 2.0, 3.0).cross_matrix();
+    /// assert!(m.is_skew_symmetric(1.0e-7));
+    /// assert!(!(m + Matrix3::identity()).is_skew_symmetric(1.0e-7));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn is_skew_symmetric(&self, eps: T::Epsilon) -> bool
+    where
+        T: Scalar + Zero + ClosedNeg + RelativeEq,
+        T::Epsilon: Clone,
+    {
+        if !self.is_square() {
+            return false;
+        }
+
+        let dim = self.nrows();
+
+        for j in 0..dim {
+            for i in j..dim {
+                let (a, b) = unsafe { (self.get_unchecked((i, j)), self.get_unchecked((j, i))) };
+                if !relative_eq!(*a, -b.clone(), epsilon = eps.clone()) {
+                    return false;
+                }
+            }
+        }
+
+        true
+    }
 }
 
 impl<T: ComplexField, R: Dim, C: Dim, S: Storage<T, R, C>> Matrix<T, R, C, S> {
+    /// Indicates if all the components of this matrix are finite, i.e., neither infinite nor NaN.
+    ///
+    /// This is a cheap check to perform before a decomposition, as non-finite inputs would
+    /// silently produce meaningless results. An empty matrix is finite.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// # use nalgebra::Vector3;
+    /// assert!(Vector3::new(1.0, -2.0, 3.0).is_finite());
+    /// assert!(!Vector3::new(1.0, f64::INFINITY, 3.0).is_finite());
+    /// assert!(!Vector3::new(1.0, 2.0, f64::NAN).is_finite());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn is_finite(&self) -> bool {
+        self.iter().all(|e| e.is_finite())
+    }
+
+    /// Indicates if at least one component of this matrix is NaN.
+    ///
+    /// For complex components, this is the case if either their real or imaginary part is NaN.
+    /// Unlike [`is_finite`](Self::is_finite), infinite components are not taken into account.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// # use nalgebra::Vector3;
+    /// assert!(Vector3::new(1.0, f64::NAN, 3.0).contains_nan());
+    /// assert!(!Vector3::new(1.0, f64::INFINITY, 3.0).contains_nan());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn contains_nan(&self) -> bool {
+        // NaN is the only value that is not comparable to itself.
+        let is_nan = |x: T::RealField| x.partial_cmp(&x).is_none();
+        self.iter()
+            .any(|e| is_nan(e.clone().real()) || is_nan(e.clone().imaginary()))
+    }
+
     /// Checks that `Mᵀ × M = Id`.
     ///
     /// In this definition `Id` is approximately equal to the identity matrix with a relative error
@@ -95,6 +215,109 @@ impl<T: ComplexField, R: Dim, C: Dim, S: Storage<T, R, C>> Matrix<T, R, C, S> {
     {
         (self.ad_mul(self)).is_identity(eps)
     }
+
+    /// Indicates if this matrix is (row-wise) diagonally dominant.
+    ///
+    /// The matrix is diagonally dominant if, for each row `i`, the modulus of the diagonal element
+    /// `|a_ii|` is greater than or equal to the sum of the moduli of the other elements of the
+    /// row. If `strict` is `true`, the inequality has to be strict for every row instead. Strictly
+    /// diagonally dominant matrices are invertible, and iterative methods like Jacobi or
+    /// Gauss-Seidel converge on them.
+    ///
+    /// This only makes sense for square matrices: `false` is returned if `self` is not square.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// # use nalgebra::Matrix3;
+    /// let m = Matrix3::new(4.0, -1.0,  2.0,
+    ///                      1.0,  3.0, -2.0,
+    ///                      0.0,  1.0,  1.0);
+    /// assert!(m.is_diagonally_dominant(false));
+    /// // The last row only satisfies `|a_ii| >= Σ|a_ij|` with equality.
+    /// assert!(!m.is_diagonally_dominant(true));
+    /// ```
+    #[must_use]
+    pub fn is_diagonally_dominant(&self, strict: bool) -> bool {
+        if !self.is_square() {
+            return false;
+        }
+
+        (0..self.nrows()).all(|i| {
+            let row = self.row(i);
+            let diag = row[i].clone().modulus();
+            let off_diag = row
+                .iter()
+                .enumerate()
+                .filter(|(j, _)| *j != i)
+                .fold(T::RealField::zero(), |acc, (_, e)| {
+                    acc + e.clone().modulus()
+                });
+
+            if strict {
+                diag > off_diag
+            } else {
+                diag >= off_diag
+            }
+        })
+    }
+}
+
+impl<T: ComplexField, D: Dim, S: Storage<T, D, D>> SquareMatrix<T, D, S>
+where
+    DefaultAllocator: Allocator<T, D>,
+{
+    /// Estimates the spectral radius of this matrix, i.e., the largest modulus of its
+    /// eigenvalues, using `niter` steps of the power iteration.
+    ///
+    /// The estimate converges to the spectral radius when the matrix has a single eigenvalue
+    /// of largest modulus, at a rate that depends on the ratio between the two largest eigenvalue
+    /// moduli. Since the iteration starts from a fixed vector, the estimate may be too small if that
+    /// vector happens to be orthogonal to the dominant eigenspace.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is not square.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::Matrix3;
+    /// let m = Matrix3::new(4.0, 1.0, 0.0,
+    ///                      1.0, 3.0, 1.0,
+    ///                      0.0, 1.0, 2.0);
+    /// let exact = m.symmetric_eigenvalues().amax();
+    /// assert_relative_eq!(m.spectral_radius_estimate(200), exact, epsilon = 1.0e-7);
+    /// ```
+    #[must_use]
+    pub fn spectral_radius_estimate(&self, niter: usize) -> T::RealField {
+        assert!(
+            self.is_square(),
+            "Unable to estimate the spectral radius of a non-square matrix."
+        );
+
+        let (nrows, _) = self.shape_generic();
+        // Start from a vector that is unlikely to be orthogonal to the dominant eigenvector.
+        let mut v = OVector::<T, D>::from_fn_generic(nrows, Const::<1>, |i, _| {
+            T::from_real(crate::convert(1.0 / (i + 1) as f64))
+        });
+        let _ = v.normalize_mut();
+        let mut radius = T::RealField::zero();
+
+        for _ in 0..niter {
+            let mut w = self * &v;
+            radius = w.normalize_mut();
+
+            if radius.is_zero() {
+                break;
+            }
+
+            v = w;
+        }
+
+        radius
+    }
 }
 
 impl<T: RealField, D: Dim, S: Storage<T, D, D>> SquareMatrix<T, D, S>
@@ -1,8 +1,11 @@
 use crate::allocator::Allocator;
 use crate::storage::RawStorage;
-use crate::{Const, DefaultAllocator, Dim, Matrix, OVector, RowOVector, Scalar, VectorSlice, U1};
+use crate::{
+    Const, DefaultAllocator, Dim, Matrix, OMatrix, OVector, RowOVector, Scalar, VectorSlice, U1,
+};
 use num::{One, Zero};
-use simba::scalar::{ClosedAdd, ClosedMul, Field, SupersetOf};
+use simba::scalar::{ClosedAdd, ClosedMul, Field, RealField, SupersetOf};
+use std::cmp::Ordering;
 use std::mem::MaybeUninit;
 
 /// # Folding on columns and rows
@@ -422,6 +425,174 @@ impl<T: Scalar, R: Dim, C: Dim, S: RawStorage<T, R, C>> Matrix<T, R, C, S> {
         })
     }
 
+    /// The variance of each column of this matrix, with `ddof` delta degrees of freedom.
+    ///
+    /// The sum of the squared deviations from the mean of each column is divided by `n - ddof`,
+    /// where `n` is the number of rows. Thus, a `ddof` of `0` computes the population variance
+    /// (as [`row_variance`](Self::row_variance) does) while a `ddof` of `1` computes the unbiased
+    /// sample variance. The result is a row vector, like `row_variance`.
+    ///
+    /// This uses Welford's algorithm, which is single-pass and remains accurate even if the
+    /// mean of the data is much larger than its dispersion.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` has `ddof` rows or fewer, and at least one column.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use nalgebra::{Matrix3x2, RowVector2};
+    ///
+    /// let m = Matrix3x2::new(1.0, 2.0,
+    ///                        4.0, 6.0,
+    ///                        7.0, 10.0);
+    /// assert_eq!(m.variance_rows(0), RowVector2::new(6.0, 32.0 / 3.0));
+    /// assert_eq!(m.variance_rows(1), RowVector2::new(9.0, 16.0));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn variance_rows(&self, ddof: usize) -> RowOVector<T, C>
+    where
+        T: Field + SupersetOf<f64>,
+        DefaultAllocator: Allocator<T, U1, C>,
+    {
+        self.compress_rows(|col| {
+            let n = col.len();
+            assert!(
+                n > ddof,
+                "Variance: the number of samples must be greater than ddof."
+            );
+
+            let mut mean = T::zero();
+            let mut m2 = T::zero();
+
+            for (k, x) in col.iter().enumerate() {
+                let delta = x.clone() - mean.clone();
+                mean += delta.clone() / crate::convert::<_, T>((k + 1) as f64);
+                m2 += delta * (x.clone() - mean.clone());
+            }
+
+            m2 / crate::convert::<_, T>((n - ddof) as f64)
+        })
+    }
+
+    /// The variance of each row of this matrix, with `ddof` delta degrees of freedom.
+    ///
+    /// The sum of the squared deviations from the mean of each row is divided by `n - ddof`,
+    /// where `n` is the number of columns. Thus, a `ddof` of `0` computes the population variance
+    /// (as [`column_variance`](Self::column_variance) does) while a `ddof` of `1` computes the
+    /// unbiased sample variance. The result is a column vector, like `column_variance`.
+    ///
+    /// This uses Welford's algorithm, which is single-pass and remains accurate even if the
+    /// mean of the data is much larger than its dispersion.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` has `ddof` columns or fewer, and at least one row.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use nalgebra::{Matrix2x3, Vector2};
+    ///
+    /// let m = Matrix2x3::new(1.0, 4.0, 7.0,
+    ///                        2.0, 6.0, 10.0);
+    /// assert_eq!(m.variance_columns(0), Vector2::new(6.0, 32.0 / 3.0));
+    /// assert_eq!(m.variance_columns(1), Vector2::new(9.0, 16.0));
+    /// ```
+    #[must_use]
+    pub fn variance_columns(&self, ddof: usize) -> OVector<T, R>
+    where
+        T: Field + SupersetOf<f64>,
+        DefaultAllocator: Allocator<T, R>,
+    {
+        let (nrows, ncols) = self.shape_generic();
+        let mut mean = OVector::zeros_generic(nrows, Const::<1>);
+        let mut m2 = OVector::zeros_generic(nrows, Const::<1>);
+
+        if nrows.value() == 0 {
+            return m2;
+        }
+
+        assert!(
+            ncols.value() > ddof,
+            "Variance: the number of samples must be greater than ddof."
+        );
+
+        for j in 0..ncols.value() {
+            let inv_k = T::one() / crate::convert::<_, T>((j + 1) as f64);
+
+            for i in 0..nrows.value() {
+                let x = self[(i, j)].clone();
+                let delta = x.clone() - mean[i].clone();
+                mean[i] += delta.clone() * inv_k.clone();
+                m2[i] += delta * (x - mean[i].clone());
+            }
+        }
+
+        let denom = T::one() / crate::convert::<_, T>((ncols.value() - ddof) as f64);
+        m2 * denom
+    }
+
+    /// The standard deviation of each column of this matrix, with `ddof` delta degrees of
+    /// freedom.
+    ///
+    /// This is the square root of [`variance_rows`](Self::variance_rows), see its documentation
+    /// for the meaning of `ddof`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` has `ddof` rows or fewer, and at least one column.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use nalgebra::{Matrix3x2, RowVector2};
+    ///
+    /// let m = Matrix3x2::new(1.0, 2.0,
+    ///                        4.0, 6.0,
+    ///                        7.0, 10.0);
+    /// assert_eq!(m.std_dev_rows(1), RowVector2::new(3.0, 4.0));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn std_dev_rows(&self, ddof: usize) -> RowOVector<T, C>
+    where
+        T: RealField,
+        DefaultAllocator: Allocator<T, U1, C>,
+    {
+        self.variance_rows(ddof).map(|v| v.sqrt())
+    }
+
+    /// The standard deviation of each row of this matrix, with `ddof` delta degrees of freedom.
+    ///
+    /// This is the square root of [`variance_columns`](Self::variance_columns), see its
+    /// documentation for the meaning of `ddof`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` has `ddof` columns or fewer, and at least one row.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use nalgebra::{Matrix2x3, Vector2};
+    ///
+    /// let m = Matrix2x3::new(1.0, 4.0, 7.0,
+    ///                        2.0, 6.0, 10.0);
+    /// assert_eq!(m.std_dev_columns(1), Vector2::new(3.0, 4.0));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn std_dev_columns(&self, ddof: usize) -> OVector<T, R>
+    where
+        T: RealField,
+        DefaultAllocator: Allocator<T, R>,
+    {
+        self.variance_columns(ddof).map(|v| v.sqrt())
+    }
+
     /*
      *
      * Mean computation.
@@ -519,4 +690,166 @@ impl<T: Scalar, R: Dim, C: Dim, S: RawStorage<T, R, C>> Matrix<T, R, C, S> {
             out.axpy(denom.clone(), &col, T::one())
         })
     }
+
+    /*
+     *
+     * Order statistics.
+     *
+     */
+    /// The element of the given fractional `rank` among the sorted elements of this matrix,
+    /// linearly interpolated between the two closest elements.
+    ///
+    /// This relies on a partial sort of a copy of the elements, which runs in linear time on
+    /// average.
+    fn interpolated_order_statistic(&self, rank: f64) -> Option<T>
+    where
+        T: RealField,
+        DefaultAllocator: Allocator<T, R, C>,
+    {
+        if self.is_empty() {
+            return None;
+        }
+
+        let cmp = |a: &T, b: &T| a.partial_cmp(b).unwrap_or(Ordering::Equal);
+        let (nrows, ncols) = self.shape_generic();
+        let mut values = OMatrix::from_iterator_generic(nrows, ncols, self.iter().cloned());
+        let values = values.as_mut_slice();
+
+        let k = rank as usize;
+        let frac = rank - k as f64;
+        let (_, lo, upper) = values.select_nth_unstable_by(k, cmp);
+
+        if frac == 0.0 {
+            return Some(lo.clone());
+        }
+
+        // The next element in the sorted order is the smallest one of the upper partition.
+        let hi = upper.iter().min_by(|a, b| cmp(a, b))?.clone();
+        let frac: T = crate::convert(frac);
+        Some(lo.clone() * (T::one() - frac.clone()) + hi * frac)
+    }
+
+    /// The median of all the elements of this matrix, or `None` if it is empty.
+    ///
+    /// If the number of elements is even, this is the mean of the two central elements. The
+    /// result is unspecified if any element is NaN.
+    ///
+    /// This runs in linear time on average, but copies all the elements of the matrix.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use nalgebra::{DVector, Matrix2x3};
+    ///
+    /// let v = DVector::from_vec(vec![3.0, 1.0, 4.0, 1.0, 5.0]);
+    /// assert_eq!(v.median(), Some(3.0));
+    ///
+    /// let m = Matrix2x3::new(1.0, 2.0, 3.0,
+    ///                        4.0, 5.0, 6.0);
+    /// assert_eq!(m.median(), Some(3.5));
+    ///
+    /// assert_eq!(DVector::<f64>::zeros(0).median(), None);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn median(&self) -> Option<T>
+    where
+        T: RealField,
+        DefaultAllocator: Allocator<T, R, C>,
+    {
+        self.interpolated_order_statistic((self.len().max(1) - 1) as f64 / 2.0)
+    }
+
+    /// The `p`-th percentile of all the elements of this matrix, or `None` if it is empty.
+    ///
+    /// The percentile `p` must be in `[0, 100]`: `0` gives the smallest element, `50` the
+    /// median, and `100` the largest element. If the percentile falls between two elements, it
+    /// is linearly interpolated between them (this is the default convention of NumPy). The
+    /// result is unspecified if any element is NaN.
+    ///
+    /// This runs in linear time on average, but copies all the elements of the matrix.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `p` is not in `[0, 100]`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use nalgebra::DVector;
+    ///
+    /// let v = DVector::from_vec(vec![4.0, 1.0, 3.0, 2.0, 5.0]);
+    /// assert_eq!(v.percentile(0.0), Some(1.0));
+    /// assert_eq!(v.percentile(25.0), Some(2.0));
+    /// assert_eq!(v.percentile(90.0), Some(4.6));
+    /// assert_eq!(v.percentile(100.0), Some(5.0));
+    /// ```
+    #[must_use]
+    pub fn percentile(&self, p: f64) -> Option<T>
+    where
+        T: RealField,
+        DefaultAllocator: Allocator<T, R, C>,
+    {
+        assert!(
+            (0.0..=100.0).contains(&p),
+            "Percentile: the percentile must be in [0, 100]."
+        );
+        self.interpolated_order_statistic(p / 100.0 * (self.len().max(1) - 1) as f64)
+    }
+
+    /// The median of all the rows of this matrix, or `None` if it has no rows.
+    ///
+    /// Use `.column_median` for the median of each row instead.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use nalgebra::{Matrix3x2, RowVector2};
+    ///
+    /// let m = Matrix3x2::new(1.0, 6.0,
+    ///                        3.0, 4.0,
+    ///                        2.0, 5.0);
+    /// assert_eq!(m.row_median(), Some(RowVector2::new(2.0, 5.0)));
+    /// ```
+    #[must_use]
+    pub fn row_median(&self) -> Option<RowOVector<T, C>>
+    where
+        T: RealField,
+        DefaultAllocator: Allocator<T, R> + Allocator<T, U1, C>,
+    {
+        if self.nrows() == 0 {
+            return None;
+        }
+
+        Some(self.compress_rows(|col| col.median().unwrap()))
+    }
+
+    /// The median of all the columns of this matrix, or `None` if it has no columns.
+    ///
+    /// Use `.row_median` for the median of each column instead.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use nalgebra::{Matrix2x3, Vector2};
+    ///
+    /// let m = Matrix2x3::new(1.0, 2.0, 6.0,
+    ///                        4.0, 8.0, 5.0);
+    /// assert_eq!(m.column_median(), Some(Vector2::new(2.0, 5.0)));
+    /// ```
+    #[must_use]
+    pub fn column_median(&self) -> Option<OVector<T, R>>
+    where
+        T: RealField,
+        DefaultAllocator: Allocator<T, R> + Allocator<T, U1, C>,
+    {
+        if self.ncols() == 0 {
+            return None;
+        }
+
+        let nrows = self.shape_generic().0;
+        Some(OVector::from_fn_generic(nrows, Const::<1>, |i, _| {
+            self.row(i).median().unwrap()
+        }))
+    }
 }
@@ -25,6 +25,10 @@ use std::mem::MaybeUninit;
 /// A Vec-based matrix data storage. It may be dynamically-sized.
 #[repr(C)]
 #[derive(Eq, Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "rkyv-serialize-no-std",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
 pub struct VecStorage<T, R: Dim, C: Dim> {
     data: Vec<T>,
     nrows: R,
@@ -186,6 +190,22 @@ impl<T, R: Dim, C: Dim> From<VecStorage<T, R, C>> for Vec<T> {
     }
 }
 
+#[cfg(feature = "rkyv-serialize-no-std")]
+impl<T, R, C> ArchivedVecStorage<T, R, C>
+where
+    T: rkyv::Archive,
+    R: Dim + rkyv::Archive,
+    C: Dim + rkyv::Archive,
+{
+    /// A slice containing all the archived components in column-major order.
+    ///
+    /// This gives access to the components of an archived matrix without deserializing it.
+    #[inline]
+    pub fn as_slice(&self) -> &[T::Archived] {
+        self.data.as_slice()
+    }
+}
+
 /*
  *
  * Dynamic − Static
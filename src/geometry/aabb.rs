@@ -0,0 +1,187 @@
+#[cfg(feature = "serde-serialize-no-std")]
+use serde::{Deserialize, Serialize};
+
+use simba::scalar::RealField;
+
+use crate::base::{SVector, Scalar};
+use crate::geometry::Point;
+
+/// An axis-aligned bounding box.
+///
+/// The box is the set of points lying between its two corners `mins` and `maxs`, boundaries
+/// included. Every coordinate of `mins` is expected to be smaller than or equal to the
+/// corresponding coordinate of `maxs`.
+///
+/// Note that instead of using the [`Aabb`](crate::Aabb) type in your code directly, you should
+/// use one of its aliases: [`Aabb2`](crate::Aabb2), [`Aabb3`](crate::Aabb3).
+#[repr(C)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde-serialize-no-std", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serde-serialize-no-std",
+    serde(bound(serialize = "T: Serialize"))
+)]
+#[cfg_attr(
+    feature = "serde-serialize-no-std",
+    serde(bound(deserialize = "T: Deserialize<'de>"))
+)]
+pub struct Aabb<T: Scalar, const D: usize> {
+    mins: Point<T, D>,
+    maxs: Point<T, D>,
+}
+
+impl<T: Scalar, const D: usize> Aabb<T, D> {
+    /// Creates a new bounding box from its minimum and maximum corners.
+    ///
+    /// No check is performed to ensure that `mins` is componentwise smaller than or equal to
+    /// `maxs`. Use [`Aabb::from_corners`] if the corners are not known to be ordered.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use nalgebra::{Aabb2, Point2};
+    /// let aabb = Aabb2::new(Point2::new(0.0, -1.0), Point2::new(2.0, 1.0));
+    /// assert_eq!(aabb.mins(), &Point2::new(0.0, -1.0));
+    /// assert_eq!(aabb.maxs(), &Point2::new(2.0, 1.0));
+    /// ```
+    #[inline]
+    pub fn new(mins: Point<T, D>, maxs: Point<T, D>) -> Self {
+        Self { mins, maxs }
+    }
+
+    /// The corner of this bounding box with the smallest coordinates.
+    #[inline]
+    #[must_use]
+    pub fn mins(&self) -> &Point<T, D> {
+        &self.mins
+    }
+
+    /// The corner of this bounding box with the largest coordinates.
+    #[inline]
+    #[must_use]
+    pub fn maxs(&self) -> &Point<T, D> {
+        &self.maxs
+    }
+}
+
+impl<T: RealField, const D: usize> Aabb<T, D> {
+    /// Creates the smallest bounding box having the two given points as opposite corners.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use nalgebra::{Aabb2, Point2};
+    /// let aabb = Aabb2::from_corners(&Point2::new(2.0, -1.0), &Point2::new(0.0, 1.0));
+    /// assert_eq!(aabb, Aabb2::new(Point2::new(0.0, -1.0), Point2::new(2.0, 1.0)));
+    /// ```
+    #[inline]
+    pub fn from_corners(a: &Point<T, D>, b: &Point<T, D>) -> Self {
+        let (mins, maxs) = a.inf_sup(b);
+        Self::new(mins, maxs)
+    }
+
+    /// Creates the smallest bounding box containing all the given points.
+    ///
+    /// The bounding box of a single point is degenerate: both its corners are equal to that
+    /// point.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `points` is empty.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use nalgebra::{Aabb3, Point3};
+    /// let points = [
+    ///     Point3::new(1.0, 0.0, -3.0),
+    ///     Point3::new(-1.0, 2.0, 0.0),
+    ///     Point3::new(0.0, 1.0, 4.0),
+    /// ];
+    /// let aabb = Aabb3::from_points(&points);
+    /// assert_eq!(aabb.mins(), &Point3::new(-1.0, 0.0, -3.0));
+    /// assert_eq!(aabb.maxs(), &Point3::new(1.0, 2.0, 4.0));
+    /// ```
+    pub fn from_points(points: &[Point<T, D>]) -> Self {
+        let (first, rest) = points
+            .split_first()
+            .expect("Aabb: cannot compute the bounding box of an empty set of points.");
+        let mut mins = first.clone();
+        let mut maxs = first.clone();
+
+        for p in rest {
+            mins = mins.inf(p);
+            maxs = maxs.sup(p);
+        }
+
+        Self::new(mins, maxs)
+    }
+
+    /// Checks if the given point lies inside of this bounding box, boundaries included.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use nalgebra::{Aabb2, Point2};
+    /// let aabb = Aabb2::new(Point2::new(0.0, 0.0), Point2::new(2.0, 1.0));
+    /// assert!(aabb.contains(&Point2::new(1.0, 0.5)));
+    /// assert!(aabb.contains(&Point2::new(2.0, 1.0)));
+    /// assert!(!aabb.contains(&Point2::new(3.0, 0.5)));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn contains(&self, point: &Point<T, D>) -> bool {
+        self.mins
+            .iter()
+            .zip(self.maxs.iter())
+            .zip(point.iter())
+            .all(|((min, max), x)| min <= x && x <= max)
+    }
+
+    /// The smallest bounding box containing both `self` and `other`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use nalgebra::{Aabb2, Point2};
+    /// let a = Aabb2::new(Point2::new(0.0, 0.0), Point2::new(1.0, 1.0));
+    /// let b = Aabb2::new(Point2::new(2.0, -1.0), Point2::new(3.0, 0.5));
+    /// let merged = a.merge(&b);
+    /// assert_eq!(merged, Aabb2::new(Point2::new(0.0, -1.0), Point2::new(3.0, 1.0)));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn merge(&self, other: &Self) -> Self {
+        Self::new(self.mins.inf(&other.mins), self.maxs.sup(&other.maxs))
+    }
+
+    /// The center of this bounding box.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use nalgebra::{Aabb2, Point2};
+    /// let aabb = Aabb2::new(Point2::new(0.0, -1.0), Point2::new(2.0, 3.0));
+    /// assert_eq!(aabb.center(), Point2::new(1.0, 1.0));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn center(&self) -> Point<T, D> {
+        crate::center(&self.mins, &self.maxs)
+    }
+
+    /// The full size of this bounding box along each axis, i.e., `maxs - mins`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use nalgebra::{Aabb2, Point2, Vector2};
+    /// let aabb = Aabb2::new(Point2::new(0.0, -1.0), Point2::new(2.0, 3.0));
+    /// assert_eq!(aabb.extents(), Vector2::new(2.0, 4.0));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn extents(&self) -> SVector<T, D> {
+        &self.maxs - &self.mins
+    }
+}
@@ -0,0 +1,7 @@
+use crate::geometry::Aabb;
+
+/// A 2-dimensional axis-aligned bounding box.
+pub type Aabb2<T> = Aabb<T, 2>;
+
+/// A 3-dimensional axis-aligned bounding box.
+pub type Aabb3<T> = Aabb<T, 3>;
@@ -1,5 +1,5 @@
-use crate::geometry::{Rotation, UnitComplex, UnitQuaternion};
-use crate::{Const, OVector, Point, SVector, Scalar, SimdRealField, Unit};
+use crate::geometry::{Rotation, Rotation2, Rotation3, UnitComplex, UnitQuaternion};
+use crate::{Const, OVector, Point, RealField, SVector, Scalar, SimdRealField, Unit};
 
 use simba::scalar::ClosedMul;
 
@@ -149,3 +149,41 @@ where
         self.inverse_transform_point(p)
     }
 }
+
+/// Trait implemented by rotations that can be interpolated, e.g., inside of an `Isometry`.
+pub trait InterpolatableRotation<T: RealField, const D: usize>: AbstractRotation<T, D> {
+    /// Spherical interpolation between `self` (for `t = 0`) and `other` (for `t = 1`).
+    ///
+    /// Returns `None` if the interpolation is not well-defined (as determined by `epsilon`).
+    fn interpolate(&self, other: &Self, t: T, epsilon: T) -> Option<Self>;
+}
+
+impl<T: RealField> InterpolatableRotation<T, 2> for Rotation2<T> {
+    /// The interpolation of 2D rotations is always well-defined, so this never returns `None`.
+    #[inline]
+    fn interpolate(&self, other: &Self, t: T, _epsilon: T) -> Option<Self> {
+        Some(self.slerp(other, t))
+    }
+}
+
+impl<T: RealField> InterpolatableRotation<T, 3> for Rotation3<T> {
+    #[inline]
+    fn interpolate(&self, other: &Self, t: T, epsilon: T) -> Option<Self> {
+        self.try_slerp(other, t, epsilon)
+    }
+}
+
+impl<T: RealField> InterpolatableRotation<T, 3> for UnitQuaternion<T> {
+    #[inline]
+    fn interpolate(&self, other: &Self, t: T, epsilon: T) -> Option<Self> {
+        self.try_slerp(other, t, epsilon)
+    }
+}
+
+impl<T: RealField> InterpolatableRotation<T, 2> for UnitComplex<T> {
+    /// The interpolation of 2D rotations is always well-defined, so this never returns `None`.
+    #[inline]
+    fn interpolate(&self, other: &Self, t: T, _epsilon: T) -> Option<Self> {
+        Some(self.slerp(other, t))
+    }
+}
@@ -0,0 +1,207 @@
+#[cfg(feature = "serde-serialize-no-std")]
+use serde::{Deserialize, Serialize};
+
+use simba::scalar::RealField;
+
+use crate::base::Scalar;
+use crate::geometry::{AbstractRotation, Isometry, Point};
+
+/// A bounding sphere.
+///
+/// The sphere is the set of points lying at a distance smaller than or equal to its `radius`
+/// from its `center`. The radius is expected to be non-negative.
+///
+/// Note that instead of using the [`BoundingSphere`](crate::BoundingSphere) type in your code
+/// directly, you should use one of its aliases: [`BoundingSphere2`](crate::BoundingSphere2),
+/// [`BoundingSphere3`](crate::BoundingSphere3).
+#[repr(C)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde-serialize-no-std", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serde-serialize-no-std",
+    serde(bound(serialize = "T: Serialize"))
+)]
+#[cfg_attr(
+    feature = "serde-serialize-no-std",
+    serde(bound(deserialize = "T: Deserialize<'de>"))
+)]
+pub struct BoundingSphere<T: Scalar, const D: usize> {
+    center: Point<T, D>,
+    radius: T,
+}
+
+impl<T: Scalar, const D: usize> BoundingSphere<T, D> {
+    /// Creates a new bounding sphere from its center and radius.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use nalgebra::{BoundingSphere2, Point2};
+    /// let sphere = BoundingSphere2::new(Point2::new(1.0, 2.0), 3.0);
+    /// assert_eq!(sphere.center(), &Point2::new(1.0, 2.0));
+    /// assert_eq!(sphere.radius(), 3.0);
+    /// ```
+    #[inline]
+    pub fn new(center: Point<T, D>, radius: T) -> Self {
+        Self { center, radius }
+    }
+
+    /// The center of this bounding sphere.
+    #[inline]
+    #[must_use]
+    pub fn center(&self) -> &Point<T, D> {
+        &self.center
+    }
+
+    /// The radius of this bounding sphere.
+    #[inline]
+    #[must_use]
+    pub fn radius(&self) -> T {
+        self.radius.clone()
+    }
+}
+
+impl<T: RealField, const D: usize> BoundingSphere<T, D> {
+    /// Creates a bounding sphere containing all the given points.
+    ///
+    /// This uses Ritter's algorithm, which is fast but only approximates the smallest enclosing
+    /// sphere: the result is usually up to 5% to 20% larger than the optimal one.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `points` is empty.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use nalgebra::{BoundingSphere2, Point2};
+    /// let points = [
+    ///     Point2::new(-1.0, 0.0),
+    ///     Point2::new(1.0, 0.0),
+    ///     Point2::new(0.0, 0.5),
+    /// ];
+    /// let sphere = BoundingSphere2::from_points(&points);
+    /// assert_eq!(sphere, BoundingSphere2::new(Point2::origin(), 1.0));
+    /// assert!(points.iter().all(|p| sphere.contains(p)));
+    /// ```
+    pub fn from_points(points: &[Point<T, D>]) -> Self {
+        let first = points.first().expect(
+            "BoundingSphere: cannot compute the bounding sphere of an empty set of points.",
+        );
+        let farthest_from = |p: &Point<T, D>| {
+            points
+                .iter()
+                .max_by(|a, b| {
+                    crate::distance_squared(p, a)
+                        .partial_cmp(&crate::distance_squared(p, b))
+                        .unwrap_or(core::cmp::Ordering::Equal)
+                })
+                .unwrap()
+        };
+
+        // Start with the sphere whose diameter joins two distant points.
+        let a = farthest_from(first);
+        let b = farthest_from(a);
+        let mut center = crate::center(a, b);
+        let mut radius = crate::distance(a, b) / crate::convert(2.0);
+
+        // Grow the sphere toward each point lying outside of it.
+        for p in points {
+            let dist = crate::distance(&center, p);
+
+            if dist > radius {
+                let new_radius = (radius.clone() + dist.clone()) / crate::convert(2.0);
+                let shift = (new_radius.clone() - radius) / dist;
+                center += (p - &center) * shift;
+                radius = new_radius;
+            }
+        }
+
+        // Account for rounding errors so that all the points are contained.
+        for p in points {
+            radius = radius.max(crate::distance(&center, p));
+        }
+
+        Self::new(center, radius)
+    }
+
+    /// Checks if the given point lies inside of this bounding sphere, boundary included.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use nalgebra::{BoundingSphere2, Point2};
+    /// let sphere = BoundingSphere2::new(Point2::new(1.0, 0.0), 2.0);
+    /// assert!(sphere.contains(&Point2::new(2.0, 1.0)));
+    /// assert!(sphere.contains(&Point2::new(3.0, 0.0)));
+    /// assert!(!sphere.contains(&Point2::new(3.0, 1.0)));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn contains(&self, point: &Point<T, D>) -> bool {
+        crate::distance(&self.center, point) <= self.radius
+    }
+
+    /// A bounding sphere containing both `self` and `other`.
+    ///
+    /// This is the smallest sphere containing both spheres.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use nalgebra::{BoundingSphere2, Point2};
+    /// let a = BoundingSphere2::new(Point2::new(0.0, 0.0), 1.0);
+    /// let b = BoundingSphere2::new(Point2::new(4.0, 0.0), 1.0);
+    /// let merged = a.merge(&b);
+    /// assert_eq!(merged, BoundingSphere2::new(Point2::new(2.0, 0.0), 3.0));
+    ///
+    /// // A sphere already containing the other one is left unchanged.
+    /// let c = BoundingSphere2::new(Point2::new(1.0, 0.0), 5.0);
+    /// assert_eq!(a.merge(&c), c);
+    /// ```
+    #[must_use]
+    pub fn merge(&self, other: &Self) -> Self {
+        let dir = &other.center - &self.center;
+        let dist = dir.norm();
+
+        if dist.clone() + other.radius.clone() <= self.radius {
+            return self.clone();
+        }
+
+        if dist.clone() + self.radius.clone() <= other.radius {
+            return other.clone();
+        }
+
+        let radius =
+            (dist.clone() + self.radius.clone() + other.radius.clone()) / crate::convert(2.0);
+        let center = &self.center + dir * ((radius.clone() - self.radius.clone()) / dist);
+
+        // Account for rounding errors so that both spheres are contained.
+        let radius = radius
+            .max(crate::distance(&center, &self.center) + self.radius.clone())
+            .max(crate::distance(&center, &other.center) + other.radius.clone());
+
+        Self::new(center, radius)
+    }
+
+    /// The bounding sphere transformed by the given isometry.
+    ///
+    /// Because isometries preserve distances, the result contains exactly the transformed
+    /// points of `self`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use nalgebra::{BoundingSphere2, Isometry2, Point2, Vector2};
+    /// let sphere = BoundingSphere2::new(Point2::new(1.0, 0.0), 2.0);
+    /// let iso = Isometry2::new(Vector2::new(0.0, 3.0), std::f64::consts::PI);
+    /// let transformed = sphere.transform_by(&iso);
+    /// assert_eq!(transformed.radius(), 2.0);
+    /// assert!((transformed.center() - Point2::new(-1.0, 3.0)).norm() < 1.0e-10);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn transform_by<R: AbstractRotation<T, D>>(&self, iso: &Isometry<T, R, D>) -> Self {
+        Self::new(iso.transform_point(&self.center), self.radius.clone())
+    }
+}
@@ -0,0 +1,7 @@
+use crate::geometry::BoundingSphere;
+
+/// A 2-dimensional bounding sphere, i.e., a bounding circle.
+pub type BoundingSphere2<T> = BoundingSphere<T, 2>;
+
+/// A 3-dimensional bounding sphere.
+pub type BoundingSphere3<T> = BoundingSphere<T, 3>;
@@ -0,0 +1,90 @@
+#[cfg(feature = "serde-serialize-no-std")]
+use serde::{Deserialize, Serialize};
+
+/// The sequence of axes about which the three elementary rotations of an Euler angle convention
+/// are performed.
+///
+/// The first six sequences, where all the axes are distinct, are the Tait-Bryan angles. The last
+/// six, where the first and last axes are the same, are the proper Euler angles.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde-serialize-no-std", derive(Serialize, Deserialize))]
+pub enum EulerAxes {
+    /// Rotations about `x`, then `y`, then `z`.
+    XYZ,
+    /// Rotations about `x`, then `z`, then `y`.
+    XZY,
+    /// Rotations about `y`, then `x`, then `z`.
+    YXZ,
+    /// Rotations about `y`, then `z`, then `x`.
+    YZX,
+    /// Rotations about `z`, then `x`, then `y`.
+    ZXY,
+    /// Rotations about `z`, then `y`, then `x`.
+    ZYX,
+    /// Rotations about `x`, then `y`, then `x`.
+    XYX,
+    /// Rotations about `x`, then `z`, then `x`.
+    XZX,
+    /// Rotations about `y`, then `x`, then `y`.
+    YXY,
+    /// Rotations about `y`, then `z`, then `y`.
+    YZY,
+    /// Rotations about `z`, then `x`, then `z`.
+    ZXZ,
+    /// Rotations about `z`, then `y`, then `z`.
+    ZYZ,
+}
+
+impl EulerAxes {
+    /// The indices (`0` for `x`, `1` for `y`, and `2` for `z`) of the three axes of this sequence.
+    #[inline]
+    #[must_use]
+    pub fn indices(self) -> [usize; 3] {
+        match self {
+            EulerAxes::XYZ => [0, 1, 2],
+            EulerAxes::XZY => [0, 2, 1],
+            EulerAxes::YXZ => [1, 0, 2],
+            EulerAxes::YZX => [1, 2, 0],
+            EulerAxes::ZXY => [2, 0, 1],
+            EulerAxes::ZYX => [2, 1, 0],
+            EulerAxes::XYX => [0, 1, 0],
+            EulerAxes::XZX => [0, 2, 0],
+            EulerAxes::YXY => [1, 0, 1],
+            EulerAxes::YZY => [1, 2, 1],
+            EulerAxes::ZXZ => [2, 0, 2],
+            EulerAxes::ZYZ => [2, 1, 2],
+        }
+    }
+
+    /// Whether these are proper Euler axes, i.e., if the first and last axes are the same.
+    #[inline]
+    #[must_use]
+    pub fn is_proper_euler(self) -> bool {
+        let [first, _, last] = self.indices();
+        first == last
+    }
+}
+
+/// An Euler angle convention: the sequence of axes of the three elementary rotations, and
+/// whether these axes move with the rotated frame.
+///
+/// Given three angles `(a1, a2, a3)` and the axes sequence `(e1, e2, e3)`, the rotation is:
+///
+/// * `R(e1, a1) * R(e2, a2) * R(e3, a3)` for intrinsic rotations, where each elementary
+///   rotation is performed about an axis of the frame rotated by the previous ones.
+/// * `R(e3, a3) * R(e2, a2) * R(e1, a1)` for extrinsic rotations, where all the elementary
+///   rotations are performed about the axes of the fixed original frame.
+///
+/// Thus, the intrinsic rotations with axes `(e1, e2, e3)` are equal to the extrinsic rotations
+/// with axes `(e3, e2, e1)` and angles in reverse order. For example, the (roll, pitch, yaw)
+/// convention of [`Rotation3::from_euler_angles`](crate::Rotation3::from_euler_angles) is
+/// `EulerOrder::Extrinsic(EulerAxes::XYZ)`, or equivalently `EulerOrder::Intrinsic(EulerAxes::ZYX)`
+/// with the angles (yaw, pitch, roll).
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde-serialize-no-std", derive(Serialize, Deserialize))]
+pub enum EulerOrder {
+    /// Elementary rotations about the axes of the rotating frame.
+    Intrinsic(EulerAxes),
+    /// Elementary rotations about the axes of the fixed frame.
+    Extrinsic(EulerAxes),
+}
@@ -11,7 +11,7 @@ use simba::simd::SimdRealField;
 use crate::base::allocator::Allocator;
 use crate::base::dimension::{DimNameAdd, DimNameSum, U1};
 use crate::base::storage::Owned;
-use crate::base::{Const, DefaultAllocator, OMatrix, SVector, Scalar, Unit};
+use crate::base::{Const, DefaultAllocator, OMatrix, SMatrix, SVector, Scalar, Unit};
 use crate::geometry::{AbstractRotation, Point, Translation};
 
 /// A direct isometry, i.e., a rotation followed by a translation (aka. a rigid-body motion).
@@ -43,7 +43,7 @@ use crate::geometry::{AbstractRotation, Point, Translation};
 ///
 /// * [Transformation of a vector or a point <span style="float:right;">`transform_vector`, `inverse_transform_point`…</span>](#transformation-of-a-vector-or-a-point)
 /// * [Inversion and in-place composition <span style="float:right;">`inverse`, `append_rotation_wrt_point_mut`…</span>](#inversion-and-in-place-composition)
-/// * [Interpolation <span style="float:right;">`lerp_slerp`…</span>](#interpolation)
+/// * [Interpolation <span style="float:right;">`lerp_slerp`, `try_interpolate`…</span>](#interpolation)
 ///
 /// # Conversion to a matrix
 /// * [Conversion to a matrix <span style="float:right;">`to_matrix`…</span>](#conversion-to-a-matrix)
@@ -324,6 +324,9 @@ where
     /// less expensive than computing the entire isometry inverse and then
     /// transforming the point.
     ///
+    /// This computes `R⁻¹ * (pt - t)` directly, where `R` and `t` are the rotational and
+    /// translational parts of this isometry, without constructing the inverse isometry.
+    ///
     /// # Example
     ///
     /// ```
@@ -391,6 +394,228 @@ where
     pub fn inverse_transform_unit_vector(&self, v: &Unit<SVector<T, D>>) -> Unit<SVector<T, D>> {
         self.rotation.inverse_transform_unit_vector(v)
     }
+
+    /// Transforms all the points of `points` by this isometry, writing the results into `out`.
+    ///
+    /// The rotation matrix is computed only once and applied to every point, which is
+    /// faster than transforming the points one by one for rotations that are not represented
+    /// as matrices (e.g. unit quaternions).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `points` and `out` don't have the same length.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use std::f32;
+    /// # use nalgebra::{Isometry3, Point3, Vector3};
+    /// let iso = Isometry3::new(Vector3::new(0.0, 0.0, 3.0), Vector3::y() * f32::consts::FRAC_PI_2);
+    /// let points = [Point3::new(1.0, 2.0, 3.0), Point3::new(-1.0, 0.0, 1.0)];
+    /// let mut out = [Point3::origin(); 2];
+    /// iso.transform_points(&points, &mut out);
+    ///
+    /// assert_relative_eq!(out[0], iso * points[0], epsilon = 1.0e-6);
+    /// assert_relative_eq!(out[1], iso * points[1], epsilon = 1.0e-6);
+    /// ```
+    #[inline]
+    pub fn transform_points(&self, points: &[Point<T, D>], out: &mut [Point<T, D>]) {
+        assert_eq!(
+            points.len(),
+            out.len(),
+            "Isometry point batch transformation: input and output lengths must match."
+        );
+        let rotation = self.rotation_matrix();
+
+        for (pt, res) in points.iter().zip(out.iter_mut()) {
+            *res = Point::from(&rotation * &pt.coords + &self.translation.vector);
+        }
+    }
+
+    /// Transforms all the points of `points` in-place by this isometry.
+    ///
+    /// See [`Self::transform_points`] for details.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use std::f32;
+    /// # use nalgebra::{Isometry2, Point2, Vector2};
+    /// let iso = Isometry2::new(Vector2::new(1.0, 2.0), f32::consts::FRAC_PI_2);
+    /// let mut points = [Point2::new(1.0, 0.0), Point2::new(0.0, 1.0)];
+    /// iso.transform_points_mut(&mut points);
+    ///
+    /// assert_relative_eq!(points[0], Point2::new(1.0, 3.0), epsilon = 1.0e-6);
+    /// assert_relative_eq!(points[1], Point2::new(0.0, 2.0), epsilon = 1.0e-6);
+    /// ```
+    #[inline]
+    pub fn transform_points_mut(&self, points: &mut [Point<T, D>]) {
+        let rotation = self.rotation_matrix();
+
+        for pt in points.iter_mut() {
+            pt.coords = &rotation * &pt.coords + &self.translation.vector;
+        }
+    }
+
+    /// Transforms all the vectors of `vectors` by this isometry, ignoring the translation
+    /// component of the isometry, and writes the results into `out`.
+    ///
+    /// See [`Self::transform_points`] for details.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `vectors` and `out` don't have the same length.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use std::f32;
+    /// # use nalgebra::{Isometry3, Vector3};
+    /// let iso = Isometry3::new(Vector3::new(0.0, 0.0, 3.0), Vector3::y() * f32::consts::FRAC_PI_2);
+    /// let vectors = [Vector3::new(1.0, 2.0, 3.0), Vector3::new(-1.0, 0.0, 1.0)];
+    /// let mut out = [Vector3::zeros(); 2];
+    /// iso.transform_vectors(&vectors, &mut out);
+    ///
+    /// assert_relative_eq!(out[0], iso * vectors[0], epsilon = 1.0e-6);
+    /// assert_relative_eq!(out[1], iso * vectors[1], epsilon = 1.0e-6);
+    /// ```
+    #[inline]
+    pub fn transform_vectors(&self, vectors: &[SVector<T, D>], out: &mut [SVector<T, D>]) {
+        assert_eq!(
+            vectors.len(),
+            out.len(),
+            "Isometry vector batch transformation: input and output lengths must match."
+        );
+        let rotation = self.rotation_matrix();
+
+        for (v, res) in vectors.iter().zip(out.iter_mut()) {
+            *res = &rotation * v;
+        }
+    }
+
+    /// Transforms all the points of `points` by the inverse of this isometry, writing the
+    /// results into `out`.
+    ///
+    /// Each point `p` is mapped to `Rᵀ * (p - t)`, where `R` and `t` are the rotation matrix and
+    /// translation vector of this isometry, without computing the inverse isometry. As for
+    /// [`Self::transform_points`], the rotation matrix is computed only once.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `points` and `out` don't have the same length.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use std::f32;
+    /// # use nalgebra::{Isometry3, Point3, Vector3};
+    /// let iso = Isometry3::new(Vector3::new(0.0, 0.0, 3.0), Vector3::y() * f32::consts::FRAC_PI_2);
+    /// let points = [Point3::new(1.0, 2.0, 3.0), Point3::new(-1.0, 0.0, 1.0)];
+    /// let mut out = [Point3::origin(); 2];
+    /// iso.inverse_transform_points(&points, &mut out);
+    ///
+    /// assert_relative_eq!(out[0], iso.inverse_transform_point(&points[0]), epsilon = 1.0e-6);
+    /// assert_relative_eq!(out[1], iso.inverse_transform_point(&points[1]), epsilon = 1.0e-6);
+    /// ```
+    #[inline]
+    pub fn inverse_transform_points(&self, points: &[Point<T, D>], out: &mut [Point<T, D>]) {
+        assert_eq!(
+            points.len(),
+            out.len(),
+            "Isometry point batch transformation: input and output lengths must match."
+        );
+        let rotation = self.rotation_matrix();
+
+        for (pt, res) in points.iter().zip(out.iter_mut()) {
+            *res = Point::from(rotation.tr_mul(&(&pt.coords - &self.translation.vector)));
+        }
+    }
+
+    /// Transforms all the points of `points` in-place by the inverse of this isometry.
+    ///
+    /// See [`Self::inverse_transform_points`] for details.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use std::f32;
+    /// # use nalgebra::{Isometry2, Point2, Vector2};
+    /// let iso = Isometry2::new(Vector2::new(1.0, 2.0), f32::consts::FRAC_PI_2);
+    /// let mut points = [Point2::new(1.0, 3.0), Point2::new(0.0, 2.0)];
+    /// iso.inverse_transform_points_mut(&mut points);
+    ///
+    /// assert_relative_eq!(points[0], Point2::new(1.0, 0.0), epsilon = 1.0e-6);
+    /// assert_relative_eq!(points[1], Point2::new(0.0, 1.0), epsilon = 1.0e-6);
+    /// ```
+    #[inline]
+    pub fn inverse_transform_points_mut(&self, points: &mut [Point<T, D>]) {
+        let rotation = self.rotation_matrix();
+
+        for pt in points.iter_mut() {
+            pt.coords = rotation.tr_mul(&(&pt.coords - &self.translation.vector));
+        }
+    }
+
+    /// Transforms all the vectors of `vectors` by the inverse of this isometry, ignoring the
+    /// translation component of the isometry, and writes the results into `out`.
+    ///
+    /// See [`Self::inverse_transform_points`] for details.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `vectors` and `out` don't have the same length.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use std::f32;
+    /// # use nalgebra::{Isometry3, Vector3};
+    /// let iso = Isometry3::new(Vector3::new(0.0, 0.0, 3.0), Vector3::y() * f32::consts::FRAC_PI_2);
+    /// let vectors = [Vector3::new(1.0, 2.0, 3.0), Vector3::new(-1.0, 0.0, 1.0)];
+    /// let mut out = [Vector3::zeros(); 2];
+    /// iso.inverse_transform_vectors(&vectors, &mut out);
+    ///
+    /// assert_relative_eq!(out[0], iso.inverse_transform_vector(&vectors[0]), epsilon = 1.0e-6);
+    /// assert_relative_eq!(out[1], iso.inverse_transform_vector(&vectors[1]), epsilon = 1.0e-6);
+    /// ```
+    #[inline]
+    pub fn inverse_transform_vectors(&self, vectors: &[SVector<T, D>], out: &mut [SVector<T, D>]) {
+        assert_eq!(
+            vectors.len(),
+            out.len(),
+            "Isometry vector batch transformation: input and output lengths must match."
+        );
+        let rotation = self.rotation_matrix();
+
+        for (v, res) in vectors.iter().zip(out.iter_mut()) {
+            *res = rotation.tr_mul(v);
+        }
+    }
+
+    /// The matrix of the rotational part of this isometry.
+    fn rotation_matrix(&self) -> SMatrix<T, D, D> {
+        let mut res = SMatrix::<T, D, D>::zeros();
+
+        for i in 0..D {
+            res.set_column(i, &self.rotated_basis_vector(i));
+        }
+
+        res
+    }
+
+    /// The `i`-th canonical basis vector transformed by the rotational part of this isometry,
+    /// i.e., the `i`-th column of its rotation matrix.
+    fn rotated_basis_vector(&self, i: usize) -> SVector<T, D> {
+        let mut basis = SVector::<T, D>::zeros();
+        basis[i] = T::one();
+        self.rotation.transform_vector(&basis)
+    }
 }
 
 // NOTE: we don't require `R: Rotation<...>` here because this is not useful for the implementation
@@ -458,6 +683,46 @@ impl<T: SimdRealField, R, const D: usize> Isometry<T, R, D> {
     {
         self.to_homogeneous()
     }
+
+    /// Writes the homogeneous transformation matrix equivalent to this isometry into `out`.
+    ///
+    /// This computes the same matrix as `self.to_homogeneous()` (up to rounding errors), but
+    /// writes the rotation and translation blocks directly into `out` without building any
+    /// intermediate matrix. This lets the caller reuse an existing matrix, e.g., when converting
+    /// many isometries in a loop.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use std::f32;
+    /// # use nalgebra::{Isometry3, Matrix4, Vector3};
+    /// let iso = Isometry3::new(Vector3::new(1.0, 2.0, 3.0), Vector3::y() * f32::consts::FRAC_PI_3);
+    /// let mut out = Matrix4::zeros();
+    /// iso.fill_homogeneous(&mut out);
+    ///
+    /// assert_relative_eq!(out, iso.to_homogeneous(), epsilon = 1.0e-6);
+    /// ```
+    #[inline]
+    pub fn fill_homogeneous(
+        &self,
+        out: &mut OMatrix<T, DimNameSum<Const<D>, U1>, DimNameSum<Const<D>, U1>>,
+    ) where
+        Const<D>: DimNameAdd<U1>,
+        T::Element: SimdRealField,
+        R: AbstractRotation<T, D>,
+        DefaultAllocator: Allocator<T, DimNameSum<Const<D>, U1>, DimNameSum<Const<D>, U1>>,
+    {
+        for j in 0..D {
+            out.fixed_slice_mut::<D, 1>(0, j)
+                .copy_from(&self.rotated_basis_vector(j));
+            out[(D, j)] = T::zero();
+        }
+
+        out.fixed_slice_mut::<D, 1>(0, D)
+            .copy_from(&self.translation.vector);
+        out[(D, D)] = T::one();
+    }
 }
 
 impl<T: SimdRealField, R, const D: usize> Eq for Isometry<T, R, D> where
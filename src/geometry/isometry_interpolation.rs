@@ -1,4 +1,7 @@
-use crate::{Isometry2, Isometry3, IsometryMatrix2, IsometryMatrix3, RealField, SimdRealField};
+use crate::geometry::InterpolatableRotation;
+use crate::{
+    Isometry, Isometry2, Isometry3, IsometryMatrix2, IsometryMatrix3, RealField, SimdRealField,
+};
 
 /// # Interpolation
 impl<T: SimdRealField> Isometry3<T> {
@@ -232,3 +235,46 @@ impl<T: SimdRealField> IsometryMatrix2<T> {
         Self::from_parts(tr.into(), rot)
     }
 }
+
+impl<T: RealField, R: InterpolatableRotation<T, D>, const D: usize> Isometry<T, R, D> {
+    /// Attempts to interpolate between two isometries using a linear interpolation for the
+    /// translation part, and a spherical interpolation for the rotation part.
+    ///
+    /// This works for any rotation representation, the rotation part being interpolated with
+    /// `InterpolatableRotation::interpolate`. Returns `None` if that interpolation is not
+    /// well-defined.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::{Vector2, Translation2, Isometry2, IsometryMatrix2, UnitComplex};
+    ///
+    /// let t1 = Translation2::new(1.0, 2.0);
+    /// let t2 = Translation2::new(4.0, 8.0);
+    /// let iso1 = Isometry2::from_parts(t1, UnitComplex::new(std::f64::consts::FRAC_PI_4));
+    /// let iso2 = Isometry2::from_parts(t2, UnitComplex::new(-std::f64::consts::PI));
+    ///
+    /// let iso3 = iso1.try_interpolate(&iso2, 1.0 / 3.0, 1.0e-6).unwrap();
+    ///
+    /// assert_eq!(iso3.translation.vector, Vector2::new(2.0, 4.0));
+    /// assert_relative_eq!(iso3.rotation.angle(), std::f64::consts::FRAC_PI_2);
+    ///
+    /// // The same interpolation, with rotation matrices.
+    /// let mat1 = IsometryMatrix2::from_parts(t1, iso1.rotation.to_rotation_matrix());
+    /// let mat2 = IsometryMatrix2::from_parts(t2, iso2.rotation.to_rotation_matrix());
+    /// let mat3 = mat1.try_interpolate(&mat2, 1.0 / 3.0, 1.0e-6).unwrap();
+    ///
+    /// assert_relative_eq!(mat3.to_homogeneous(), iso3.to_homogeneous(), epsilon = 1.0e-7);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn try_interpolate(&self, other: &Self, t: T, epsilon: T) -> Option<Self> {
+        let tr = self
+            .translation
+            .vector
+            .lerp(&other.translation.vector, t.clone());
+        let rot = self.rotation.interpolate(&other.rotation, t, epsilon)?;
+        Some(Self::from_parts(tr.into(), rot))
+    }
+}
@@ -22,6 +22,8 @@ mod rotation_ops;
 mod rotation_simba; // TODO: implement Rotation methods.
 mod rotation_specialization;
 
+mod euler_order;
+
 mod quaternion;
 mod quaternion_construction;
 mod quaternion_conversion;
@@ -83,10 +85,16 @@ mod transform_simba;
 mod reflection;
 mod reflection_alias;
 
+mod aabb;
+mod aabb_alias;
+mod bounding_sphere;
+mod bounding_sphere_alias;
+mod plane;
+
 mod orthographic;
 mod perspective;
 
-pub use self::abstract_rotation::AbstractRotation;
+pub use self::abstract_rotation::{AbstractRotation, InterpolatableRotation};
 
 pub use self::point::*;
 pub use self::point_alias::*;
@@ -94,6 +102,8 @@ pub use self::point_alias::*;
 pub use self::rotation::*;
 pub use self::rotation_alias::*;
 
+pub use self::euler_order::{EulerAxes, EulerOrder};
+
 pub use self::quaternion::*;
 
 pub use self::dual_quaternion::*;
@@ -118,5 +128,11 @@ pub use self::transform_alias::*;
 pub use self::reflection::*;
 pub use self::reflection_alias::*;
 
+pub use self::aabb::*;
+pub use self::aabb_alias::*;
+pub use self::bounding_sphere::*;
+pub use self::bounding_sphere_alias::*;
+pub use self::plane::*;
+
 pub use self::orthographic::Orthographic3;
 pub use self::perspective::Perspective3;
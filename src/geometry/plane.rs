@@ -0,0 +1,172 @@
+#[cfg(feature = "serde-serialize-no-std")]
+use serde::{Deserialize, Serialize};
+
+use simba::scalar::RealField;
+
+use crate::base::{Scalar, Unit, Vector3};
+use crate::geometry::Point3;
+
+/// A plane in 3-dimensional space.
+///
+/// The plane is the set of points `p` such that `normal.dot(&p.coords) == offset`, where `normal`
+/// is a unit vector. The sign of [`Plane3::signed_distance`] is positive on the side the normal
+/// points to.
+#[repr(C)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde-serialize-no-std", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serde-serialize-no-std",
+    serde(bound(serialize = "T: Serialize"))
+)]
+#[cfg_attr(
+    feature = "serde-serialize-no-std",
+    serde(bound(deserialize = "T: Deserialize<'de>"))
+)]
+pub struct Plane3<T: Scalar> {
+    normal: Unit<Vector3<T>>,
+    offset: T,
+}
+
+impl<T: Scalar> Plane3<T> {
+    /// Creates the plane with the given unit normal and signed offset from the origin along the
+    /// normal.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use nalgebra::{Plane3, Vector3};
+    /// let plane = Plane3::new(Vector3::z_axis(), 2.0);
+    /// assert_eq!(plane.normal(), &Vector3::z_axis());
+    /// assert_eq!(*plane.offset(), 2.0);
+    /// ```
+    #[inline]
+    pub fn new(normal: Unit<Vector3<T>>, offset: T) -> Self {
+        Self { normal, offset }
+    }
+
+    /// The unit normal of this plane.
+    #[inline]
+    #[must_use]
+    pub fn normal(&self) -> &Unit<Vector3<T>> {
+        &self.normal
+    }
+
+    /// The signed distance from the origin to this plane, along its normal.
+    #[inline]
+    #[must_use]
+    pub fn offset(&self) -> &T {
+        &self.offset
+    }
+}
+
+impl<T: RealField> Plane3<T> {
+    /// Creates the plane passing through `point` with the given unit normal.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use nalgebra::{Plane3, Point3, Vector3};
+    /// let plane = Plane3::from_point_normal(&Point3::new(1.0, 2.0, 3.0), Vector3::z_axis());
+    /// assert_eq!(plane, Plane3::new(Vector3::z_axis(), 3.0));
+    /// ```
+    #[inline]
+    pub fn from_point_normal(point: &Point3<T>, normal: Unit<Vector3<T>>) -> Self {
+        let offset = normal.dot(&point.coords);
+        Self::new(normal, offset)
+    }
+
+    /// Creates the plane passing through the three given points.
+    ///
+    /// The normal is oriented such that `a`, `b`, `c` appear in counterclockwise order when seen
+    /// from the positive side of the plane. Returns `None` if the points are collinear (or
+    /// nearly so), in which case they do not define a unique plane. The points are considered
+    /// collinear if the sine of the angle between `b - a` and `c - a` is smaller than or equal
+    /// to `T::default_epsilon()`, so that the result does not depend on the scale of the points.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::{Plane3, Point3, Vector3};
+    /// let a = Point3::new(0.0, 0.0, 1.0);
+    /// let b = Point3::new(1.0, 0.0, 1.0);
+    /// let c = Point3::new(0.0, 1.0, 1.0);
+    /// let plane = Plane3::from_points(&a, &b, &c).unwrap();
+    /// assert_relative_eq!(plane.normal(), &Vector3::z_axis());
+    /// assert_relative_eq!(*plane.offset(), 1.0);
+    ///
+    /// // Collinear points.
+    /// assert!(Plane3::from_points(&a, &b, &Point3::new(2.0, 0.0, 1.0)).is_none());
+    /// ```
+    #[inline]
+    pub fn from_points(a: &Point3<T>, b: &Point3<T>, c: &Point3<T>) -> Option<Self> {
+        let (ab, ac) = (b - a, c - a);
+        let min_norm = T::default_epsilon() * ab.norm() * ac.norm();
+        Unit::try_new(ab.cross(&ac), min_norm).map(|n| Self::from_point_normal(a, n))
+    }
+
+    /// The signed distance from `point` to this plane.
+    ///
+    /// It is positive if `point` lies on the side the normal points to, negative if it lies on
+    /// the other side, and zero if it lies on the plane.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use nalgebra::{Plane3, Point3, Vector3};
+    /// let plane = Plane3::new(Vector3::z_axis(), 1.0);
+    /// assert_eq!(plane.signed_distance(&Point3::new(4.0, 5.0, 3.0)), 2.0);
+    /// assert_eq!(plane.signed_distance(&Point3::new(4.0, 5.0, -1.0)), -2.0);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn signed_distance(&self, point: &Point3<T>) -> T {
+        self.normal.dot(&point.coords) - self.offset.clone()
+    }
+
+    /// The orthogonal projection of `point` onto this plane.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use nalgebra::{Plane3, Point3, Vector3};
+    /// let plane = Plane3::new(Vector3::z_axis(), 1.0);
+    /// assert_eq!(plane.project_point(&Point3::new(4.0, 5.0, 3.0)), Point3::new(4.0, 5.0, 1.0));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn project_point(&self, point: &Point3<T>) -> Point3<T> {
+        point - self.normal.as_ref() * self.signed_distance(point)
+    }
+
+    /// Computes the intersection of this plane with the line passing through `origin` with the
+    /// direction `dir`.
+    ///
+    /// Returns the parameter `t` such that `origin + dir * t` lies on this plane, or `None` if
+    /// the line is parallel to the plane (including if it lies on the plane) or if `dir` is
+    /// zero. The line is considered parallel if the sine of the angle between `dir` and the
+    /// plane is smaller than or equal to `T::default_epsilon()`, so that the result does not
+    /// depend on the norm of `dir`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use nalgebra::{Plane3, Point3, Vector3};
+    /// let plane = Plane3::new(Vector3::z_axis(), 1.0);
+    /// let origin = Point3::new(1.0, 2.0, 5.0);
+    /// let t = plane.intersect_line(&origin, &Vector3::new(0.0, 1.0, -2.0)).unwrap();
+    /// assert_eq!(t, 2.0);
+    /// assert!(plane.intersect_line(&origin, &Vector3::x()).is_none());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn intersect_line(&self, origin: &Point3<T>, dir: &Vector3<T>) -> Option<T> {
+        let denom = self.normal.dot(dir);
+
+        if denom.clone().abs() <= T::default_epsilon() * dir.norm() {
+            None
+        } else {
+            Some(-self.signed_distance(origin) / denom)
+        }
+    }
+}
@@ -1,5 +1,5 @@
 use approx::{AbsDiffEq, RelativeEq, UlpsEq};
-use num::One;
+use num::{One, Zero};
 use std::cmp::Ordering;
 use std::fmt;
 use std::hash;
@@ -7,6 +7,7 @@ use std::hash;
 #[cfg(feature = "serde-serialize-no-std")]
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
+use simba::scalar::{ClosedAdd, ClosedMul, ClosedSub};
 use simba::simd::SimdPartialOrd;
 
 use crate::base::allocator::Allocator;
@@ -448,6 +449,30 @@ where
     }
 }
 
+/// # Interpolation
+impl<T: Scalar + Zero + One + ClosedAdd + ClosedSub + ClosedMul, D: DimName> OPoint<T, D>
+where
+    DefaultAllocator: Allocator<T, D>,
+{
+    /// Returns `self * (1.0 - t) + other * t`, i.e., the linear blend of the points `self` and
+    /// `other` using the scalar value `t`.
+    ///
+    /// The value for `t` is not restricted to the range `[0, 1]`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use nalgebra::Point3;
+    /// let a = Point3::new(1.0, 2.0, 3.0);
+    /// let b = Point3::new(10.0, 20.0, 30.0);
+    /// assert_eq!(a.lerp(&b, 0.1), Point3::new(1.9, 3.8, 5.7));
+    /// ```
+    #[must_use]
+    pub fn lerp(&self, other: &OPoint<T, D>, t: T) -> OPoint<T, D> {
+        self.coords.lerp(&other.coords, t).into()
+    }
+}
+
 /*
  *
  * Display
@@ -12,10 +12,10 @@ use crate::base::allocator::Allocator;
 use crate::base::dimension::{DimNameAdd, DimNameSum, U1};
 use crate::base::{DefaultAllocator, Scalar};
 use crate::{
-    Const, DimName, OPoint, OVector, Point1, Point2, Point3, Point4, Point5, Point6, Vector1,
-    Vector2, Vector3, Vector4, Vector5, Vector6,
+    Const, DimName, OPoint, OVector, Point1, Point2, Point3, Point4, Point5, Point6,
+    SimdComplexField, Vector1, Vector2, Vector3, Vector4, Vector5, Vector6,
 };
-use simba::scalar::{ClosedDiv, SupersetOf};
+use simba::scalar::{ClosedDiv, ComplexField, SupersetOf};
 
 use crate::geometry::Point;
 
@@ -120,6 +120,121 @@ where
         }
     }
 
+    /// Creates a new point from its homogeneous vector representation, unless its last
+    /// component is too close to zero.
+    ///
+    /// This is the same as [`OPoint::from_homogeneous`] except that `None` is returned whenever
+    /// the modulus of the last component of `v` is smaller than or equal to `eps`. This avoids
+    /// the huge (or non-finite) coordinates that the perspective divide yields for points close
+    /// to infinity.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use nalgebra::{Point3, Vector4};
+    /// let coords = Vector4::new(1.0, 2.0, 3.0, 2.0);
+    /// let pt = Point3::try_from_homogeneous(coords, 1.0e-7);
+    /// assert_eq!(pt, Some(Point3::new(0.5, 1.0, 1.5)));
+    ///
+    /// // Fails because the last component is too close to zero.
+    /// let coords = Vector4::new(1.0, 2.0, 3.0, 1.0e-10);
+    /// assert!(Point3::try_from_homogeneous(coords, 1.0e-7).is_none());
+    ///
+    /// // `from_homogeneous` only rejects an exact zero.
+    /// assert!(Point3::from_homogeneous(coords).is_some());
+    /// ```
+    #[inline]
+    pub fn try_from_homogeneous(v: OVector<T, DimNameSum<D, U1>>, eps: T::RealField) -> Option<Self>
+    where
+        T: ComplexField,
+        D: DimNameAdd<U1>,
+        DefaultAllocator: Allocator<T, DimNameSum<D, U1>>,
+    {
+        if v[D::dim()].clone().modulus() > eps {
+            Self::from_homogeneous(v)
+        } else {
+            None
+        }
+    }
+
+    /// Computes the centroid, i.e., the average, of a set of points.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `points` is empty, since the centroid of an empty set is not defined.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use nalgebra::Point2;
+    /// let points = [
+    ///     Point2::new(0.0, 0.0),
+    ///     Point2::new(4.0, 0.0),
+    ///     Point2::new(2.0, 3.0),
+    /// ];
+    /// assert_eq!(Point2::centroid(&points), Point2::new(2.0, 1.0));
+    /// ```
+    #[inline]
+    pub fn centroid(points: &[Self]) -> Self
+    where
+        T: SimdComplexField,
+    {
+        assert!(
+            !points.is_empty(),
+            "Point centroid: cannot compute the centroid of an empty set of points."
+        );
+
+        let mut sum = OVector::from_element(T::zero());
+        for p in points {
+            sum += &p.coords;
+        }
+
+        Self::from(sum / crate::convert::<_, T>(points.len() as f64))
+    }
+
+    /// Computes the weighted centroid of a set of points.
+    ///
+    /// The `i`-th point has the weight `weights[i]`. The result is the sum of the weighted
+    /// points divided by the sum of the weights. If the weights sum up to zero, the coordinates
+    /// of the result are not finite.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `points` is empty or if `points` and `weights` have different lengths.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use nalgebra::Point2;
+    /// let points = [Point2::new(0.0, 0.0), Point2::new(4.0, 8.0)];
+    /// let weights = [3.0, 1.0];
+    /// assert_eq!(Point2::centroid_weighted(&points, &weights), Point2::new(1.0, 2.0));
+    /// ```
+    #[inline]
+    pub fn centroid_weighted(points: &[Self], weights: &[T]) -> Self
+    where
+        T: SimdComplexField,
+    {
+        assert!(
+            !points.is_empty(),
+            "Point centroid: cannot compute the centroid of an empty set of points."
+        );
+        assert_eq!(
+            points.len(),
+            weights.len(),
+            "Point centroid: the number of weights must match the number of points."
+        );
+
+        let mut sum = OVector::from_element(T::zero());
+        let mut total_weight = T::zero();
+        for (p, w) in points.iter().zip(weights.iter()) {
+            sum.axpy(w.clone(), &p.coords, T::one());
+            total_weight += w.clone();
+        }
+
+        Self::from(sum / total_weight)
+    }
+
     /// Cast the components of `self` to another type.
     ///
     /// # Example
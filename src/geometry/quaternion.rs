@@ -550,6 +550,39 @@ where
         (self.ln() * n).exp()
     }
 
+    /// Raise the quaternion to a given integer power.
+    ///
+    /// This uses exponentiation by squaring, which is faster and more accurate than
+    /// `self.powf(n as T)` since it only involves quaternion multiplications.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::Quaternion;
+    /// let q = Quaternion::new(1.0, 2.0, 3.0, 4.0);
+    /// assert_eq!(q.powu(0), Quaternion::identity());
+    /// assert_eq!(q.powu(3), q * q * q);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn powu(&self, mut n: u32) -> Self {
+        let mut base = self.clone();
+        let mut res = Self::identity();
+
+        while n > 0 {
+            if n & 1 == 1 {
+                res *= base.clone();
+            }
+
+            n >>= 1;
+
+            if n > 0 {
+                base = &base * &base;
+            }
+        }
+
+        res
+    }
+
     /// Transforms this quaternion into its 4D vector form (Vector part, Scalar part).
     ///
     /// # Example
@@ -993,13 +1026,26 @@ impl<T: RealField + UlpsEq<Epsilon = T>> UlpsEq for Quaternion<T> {
     }
 }
 
+// Formats `w − (x, y, z)`, forwarding the flags of `f` (width, precision…) to each component.
+fn fmt_components<T: fmt::Display>(
+    f: &mut fmt::Formatter<'_>,
+    first: &T,
+    rest: [&T; 3],
+) -> fmt::Result {
+    fmt::Display::fmt(first, f)?;
+    f.write_str(" − (")?;
+    fmt::Display::fmt(rest[0], f)?;
+    f.write_str(", ")?;
+    fmt::Display::fmt(rest[1], f)?;
+    f.write_str(", ")?;
+    fmt::Display::fmt(rest[2], f)?;
+    f.write_str(")")
+}
+
 impl<T: RealField + fmt::Display> fmt::Display for Quaternion<T> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(
-            f,
-            "Quaternion {} − ({}, {}, {})",
-            self[3], self[0], self[1], self[2]
-        )
+        f.write_str("Quaternion ")?;
+        fmt_components(f, &self[3], [&self[0], &self[1], &self[2]])
     }
 }
 
@@ -1410,6 +1456,35 @@ where
         }
     }
 
+    /// Raise the quaternion to a given integer power.
+    ///
+    /// This returns the unit quaternion that identifies a rotation with axis `self.axis()` and
+    /// angle `self.angle() × n`. A negative `n` yields a power of the inverse rotation. Unlike
+    /// repeated multiplications, this does not accumulate any norm drift.
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::{UnitQuaternion, Vector3, Unit};
+    /// let axis = Unit::new_normalize(Vector3::new(1.0, 2.0, 3.0));
+    /// let rot = UnitQuaternion::from_axis_angle(&axis, 0.4);
+    /// assert_relative_eq!(rot.powi(3), rot * rot * rot, epsilon = 1.0e-7);
+    /// assert_relative_eq!(rot.powi(-2), rot.inverse() * rot.inverse(), epsilon = 1.0e-7);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn powi(&self, n: i32) -> Self
+    where
+        T: RealField,
+    {
+        if let Some(v) = self.axis() {
+            let n: T = crate::convert(n as f64);
+            Self::from_axis_angle(&v, self.angle() * n)
+        } else {
+            Self::identity()
+        }
+    }
+
     /// Builds a rotation matrix from this unit quaternion.
     ///
     /// # Example
@@ -1471,7 +1546,9 @@ where
 
     /// Retrieves the euler angles corresponding to this unit quaternion.
     ///
-    /// The angles are produced in the form (roll, pitch, yaw).
+    /// The angles are produced in the form (roll, pitch, yaw), with the same convention as
+    /// [`UnitQuaternion::from_euler_angles`]. See [`Rotation3::euler_angles`](crate::Rotation3::euler_angles) for the range of
+    /// each angle and the choice made at gimbal lock, when the pitch is `±pi/2`.
     ///
     /// # Example
     /// ```
@@ -1634,24 +1711,24 @@ impl<T: RealField> Default for UnitQuaternion<T> {
     }
 }
 
+/// Displays the components of the unit quaternion as `UnitQuaternion w − (x, y, z)`, or its
+/// axis-angle representation with the alternate flag, i.e., with `{:#}`.
 impl<T: RealField + fmt::Display> fmt::Display for UnitQuaternion<T> {
+    // Both `{}` and the alternate `{:#}` form print the axis-angle representation.
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("UnitQuaternion angle: ")?;
+        fmt::Display::fmt(&self.angle(), f)?;
+
         if let Some(axis) = self.axis() {
-            let axis = axis.into_inner();
-            write!(
-                f,
-                "UnitQuaternion angle: {} − axis: ({}, {}, {})",
-                self.angle(),
-                axis[0],
-                axis[1],
-                axis[2]
-            )
+            f.write_str(" − axis: (")?;
+            fmt::Display::fmt(&axis[0], f)?;
+            f.write_str(", ")?;
+            fmt::Display::fmt(&axis[1], f)?;
+            f.write_str(", ")?;
+            fmt::Display::fmt(&axis[2], f)?;
+            f.write_str(")")
         } else {
-            write!(
-                f,
-                "UnitQuaternion angle: {} − axis: (undefined)",
-                self.angle()
-            )
+            f.write_str(" − axis: (undefined)")
         }
     }
 }
@@ -274,12 +274,37 @@ where
 
     /// Creates a new unit quaternion from a quaternion.
     ///
-    /// The input quaternion will be normalized.
+    /// The input quaternion will be normalized. No check is performed on the norm of `q`: a zero
+    /// (or near-zero) input quaternion will result in a unit quaternion with NaN (or inaccurate)
+    /// components. Use [`UnitQuaternion::try_from_quaternion`] if `q` may be degenerate.
     #[inline]
     pub fn from_quaternion(q: Quaternion<T>) -> Self {
         Self::new_normalize(q)
     }
 
+    /// Attempts to create a new unit quaternion from a quaternion.
+    ///
+    /// The input quaternion will be normalized. Returns `None` if the norm of `q` is smaller
+    /// or equal to `eps`.
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::{Quaternion, UnitQuaternion};
+    /// let q = Quaternion::new(2.0, 0.0, 0.0, 0.0);
+    /// assert_eq!(UnitQuaternion::try_from_quaternion(q, 1.0e-6), Some(UnitQuaternion::identity()));
+    ///
+    /// let zero = Quaternion::new(0.0, 0.0, 0.0, 0.0);
+    /// assert!(UnitQuaternion::try_from_quaternion(zero, 1.0e-6).is_none());
+    /// ```
+    #[inline]
+    pub fn try_from_quaternion(q: Quaternion<T>, eps: T) -> Option<Self>
+    where
+        T: RealField,
+    {
+        Self::try_new(q, eps)
+    }
+
     /// Creates a new unit quaternion from Euler angles.
     ///
     /// The primitive rotations are applied in order: 1 roll − 2 pitch − 3 yaw.
@@ -312,15 +337,57 @@ where
 
     /// Builds an unit quaternion from a basis assumed to be orthonormal.
     ///
-    /// In order to get a valid unit-quaternion, the input must be an
-    /// orthonormal basis, i.e., all vectors are normalized, and the are
-    /// all orthogonal to each other. These invariants are not checked
-    /// by this method.
+    /// The basis vectors are the images of the `x`, `y` and `z` axes by the rotation, i.e., the
+    /// columns of its rotation matrix. In order to get a valid unit-quaternion, the input must be
+    /// an orthonormal basis, i.e., all vectors are normalized, and the are all orthogonal to each
+    /// other. These invariants are not checked by this method: use `.from_basis` to check them.
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::{UnitQuaternion, Vector3};
+    /// let x = Vector3::new(0.0, 1.0, 0.0);
+    /// let y = Vector3::new(-1.0, 0.0, 0.0);
+    /// let z = Vector3::new(0.0, 0.0, 1.0);
+    /// let q = UnitQuaternion::from_basis_unchecked(&[x, y, z]);
+    ///
+    /// assert_relative_eq!(q * Vector3::x(), x, epsilon = 1.0e-7);
+    /// assert_relative_eq!(q * Vector3::y(), y, epsilon = 1.0e-7);
+    /// assert_relative_eq!(q * Vector3::z(), z, epsilon = 1.0e-7);
+    /// ```
     pub fn from_basis_unchecked(basis: &[Vector3<T>; 3]) -> Self {
         let rot = Rotation3::from_basis_unchecked(basis);
         Self::from_rotation_matrix(&rot)
     }
 
+    /// Builds an unit quaternion from a basis, checking that it is orthonormal and right-handed.
+    ///
+    /// The basis vectors are the images of the `x`, `y` and `z` axes by the rotation. Returns
+    /// `None` if the basis is not orthonormal up to `eps`, or if it is not right-handed (it would
+    /// then describe a reflection).
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::{UnitQuaternion, Vector3};
+    /// let x = Vector3::new(0.0, 1.0, 0.0);
+    /// let y = Vector3::new(-1.0, 0.0, 0.0);
+    /// let z = Vector3::new(0.0, 0.0, 1.0);
+    /// let q = UnitQuaternion::from_basis(&[x, y, z], 1.0e-7).unwrap();
+    /// assert_relative_eq!(q, UnitQuaternion::from_basis_unchecked(&[x, y, z]));
+    ///
+    /// // Not orthonormal.
+    /// assert!(UnitQuaternion::from_basis(&[x, y, x + y], 1.0e-7).is_none());
+    /// // Orthonormal, but left-handed.
+    /// assert!(UnitQuaternion::from_basis(&[x, y, -z], 1.0e-7).is_none());
+    /// ```
+    pub fn from_basis(basis: &[Vector3<T>; 3], eps: T) -> Option<Self>
+    where
+        T: RealField,
+    {
+        Rotation3::from_basis(basis, eps).map(|rot| Self::from_rotation_matrix(&rot))
+    }
+
     /// Builds an unit quaternion from a rotation matrix.
     ///
     /// # Example
@@ -21,7 +21,7 @@ use crate::base::{
     Matrix2, Matrix3, SMatrix, SVector, Unit, UnitVector3, Vector, Vector1, Vector2, Vector3,
 };
 
-use crate::geometry::{Rotation2, Rotation3, UnitComplex, UnitQuaternion};
+use crate::geometry::{EulerOrder, Rotation2, Rotation3, UnitComplex, UnitQuaternion};
 
 /*
  *
@@ -56,6 +56,24 @@ impl<T: SimdRealField> Rotation2<T> {
     pub fn from_scaled_axis<SB: Storage<T, U1>>(axisangle: Vector<T, U1, SB>) -> Self {
         Self::new(axisangle[0].clone())
     }
+
+    /// The exponential map from the Lie algebra `so(2)` to the rotation group `SO(2)`.
+    ///
+    /// The tangent vector is the rotation angle wrapped in a 1-dimensional vector, so this is
+    /// the same as `Self::from_scaled_axis(tangent)`. The inverse map is [`Self::log`].
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::{Rotation2, Vector1};
+    /// let rot = Rotation2::exp(Vector1::new(0.7));
+    /// assert_relative_eq!(rot, Rotation2::new(0.7));
+    /// assert_relative_eq!(rot.log(), Vector1::new(0.7));
+    /// ```
+    #[inline]
+    pub fn exp<SB: Storage<T, U1>>(tangent: Vector<T, U1, SB>) -> Self {
+        Self::from_scaled_axis(tangent)
+    }
 }
 
 /// # Construction from an existing 2D matrix or rotations
@@ -71,6 +89,38 @@ impl<T: SimdRealField> Rotation2<T> {
         Self::from_matrix_unchecked(mat)
     }
 
+    /// Builds a rotation from a basis, checking that it is orthonormal and right-handed.
+    ///
+    /// The basis vectors become the columns of the rotation matrix. Returns `None` if the
+    /// resulting matrix is not a rotation matrix, up to `eps`, i.e. if the basis is not
+    /// orthonormal or if it is not right-handed (the result would be a reflection).
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::{Rotation2, Vector2};
+    /// let x = Vector2::new(0.0, 1.0);
+    /// let y = Vector2::new(-1.0, 0.0);
+    /// let rot = Rotation2::from_basis(&[x, y], 1.0e-7).unwrap();
+    /// assert_eq!(rot * Vector2::x(), x);
+    ///
+    /// // Not orthonormal.
+    /// assert!(Rotation2::from_basis(&[x, x], 1.0e-7).is_none());
+    /// // Orthonormal, but reflects the plane.
+    /// assert!(Rotation2::from_basis(&[x, -y], 1.0e-7).is_none());
+    /// ```
+    pub fn from_basis(basis: &[Vector2<T>; 2], eps: T) -> Option<Self>
+    where
+        T: RealField,
+    {
+        let mat = Matrix2::from_columns(&basis[..]);
+
+        if mat.is_special_orthogonal(eps) {
+            Some(Self::from_matrix_unchecked(mat))
+        } else {
+            None
+        }
+    }
+
     /// Builds a rotation matrix by extracting the rotation part of the given transformation `m`.
     ///
     /// This is an iterative method. See `.from_matrix_eps` to provide mover
@@ -209,6 +259,9 @@ impl<T: SimdRealField> Rotation2<T> {
     /// Raise the rotation to a given floating power, i.e., returns the rotation with the angle
     /// of `self` multiplied by `n`.
     ///
+    /// The angle of `self` is taken in `]-π, π]`, so the identity is returned if `self` is the
+    /// identity, and `self.powf(0.5)` is the shortest rotation `r` such that `r * r == self`.
+    ///
     /// # Example
     /// ```
     /// # #[macro_use] extern crate approx;
@@ -268,6 +321,16 @@ impl<T: SimdRealField> Rotation2<T> {
     pub fn scaled_axis(&self) -> SVector<T, 1> {
         Vector1::new(self.angle())
     }
+
+    /// The logarithm map from the rotation group `SO(2)` to its Lie algebra `so(2)`.
+    ///
+    /// This is the same as `self.scaled_axis()`: the returned tangent vector contains the
+    /// rotation angle in `[-pi; pi]`. The inverse map is [`Self::exp`].
+    #[inline]
+    #[must_use]
+    pub fn log(&self) -> SVector<T, 1> {
+        self.scaled_axis()
+    }
 }
 
 #[cfg(feature = "rand-no-std")]
@@ -360,6 +423,30 @@ where
         Self::new(axisangle)
     }
 
+    /// The exponential map from the Lie algebra `so(3)` to the rotation group `SO(3)`.
+    ///
+    /// The tangent vector is the axis-angle representation of the rotation: its direction is the
+    /// rotation axis and its norm is the rotation angle in radian. This is the same as
+    /// `Self::from_scaled_axis(tangent)` and is computed with the Rodrigues formula, without going
+    /// through a quaternion. The inverse map is [`Self::log`].
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::{Rotation3, Vector3};
+    /// let tangent = Vector3::new(0.1, 0.2, 0.3);
+    /// let rot = Rotation3::exp(tangent);
+    /// assert_relative_eq!(rot, Rotation3::from_scaled_axis(tangent));
+    /// assert_relative_eq!(rot.log(), tangent, epsilon = 1.0e-6);
+    ///
+    /// // The exponential of a zero vector is the identity.
+    /// assert_eq!(Rotation3::exp(Vector3::<f32>::zeros()), Rotation3::identity());
+    /// ```
+    #[inline]
+    pub fn exp<SB: Storage<T, U3>>(tangent: Vector<T, U3, SB>) -> Self {
+        Self::from_scaled_axis(tangent)
+    }
+
     /// Builds a 3D rotation matrix from an axis and a rotation angle.
     ///
     /// # Example
@@ -444,6 +531,42 @@ where
             cp * cr,
         ))
     }
+
+    /// Creates a new rotation from Euler angles following the given convention.
+    ///
+    /// The angles `a1`, `a2`, and `a3` are the angles of the elementary rotations about the
+    /// first, second, and third axes of the sequence of `order`. See [`EulerOrder`] for the way
+    /// they are composed.
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::{EulerAxes, EulerOrder, Rotation3, Vector3};
+    /// let rot = Rotation3::from_euler_angles_ordered(0.1, 0.2, 0.3, EulerOrder::Intrinsic(EulerAxes::ZXZ));
+    /// let expected = Rotation3::from_axis_angle(&Vector3::z_axis(), 0.1)
+    ///     * Rotation3::from_axis_angle(&Vector3::x_axis(), 0.2)
+    ///     * Rotation3::from_axis_angle(&Vector3::z_axis(), 0.3);
+    /// assert_relative_eq!(rot, expected, epsilon = 1.0e-7);
+    ///
+    /// // The convention of `from_euler_angles`.
+    /// let rot = Rotation3::from_euler_angles_ordered(0.1, 0.2, 0.3, EulerOrder::Extrinsic(EulerAxes::XYZ));
+    /// assert_relative_eq!(rot, Rotation3::from_euler_angles(0.1, 0.2, 0.3), epsilon = 1.0e-7);
+    /// ```
+    pub fn from_euler_angles_ordered(a1: T, a2: T, a3: T, order: EulerOrder) -> Self {
+        let elementary =
+            |axis: usize, angle: T| Self::from_axis_angle(&Vector3::ith_axis(axis), angle);
+
+        match order {
+            EulerOrder::Intrinsic(axes) => {
+                let [e1, e2, e3] = axes.indices();
+                elementary(e1, a1) * elementary(e2, a2) * elementary(e3, a3)
+            }
+            EulerOrder::Extrinsic(axes) => {
+                let [e1, e2, e3] = axes.indices();
+                elementary(e3, a3) * elementary(e2, a2) * elementary(e1, a1)
+            }
+        }
+    }
 }
 
 /// # Construction from a 3D eye position and target point
@@ -665,9 +788,14 @@ where
     /// Raise the rotation to a given floating power, i.e., returns the rotation with the same
     /// axis as `self` and an angle equal to `self.angle()` multiplied by `n`.
     ///
+    /// The identity is returned if `self` is the identity. If `self` is a rotation by an angle
+    /// of π, its axis is only defined up to its sign: one of the two possible axes is picked,
+    /// so non-integer powers of such a rotation may rotate in either direction around it.
+    ///
     /// # Example
     /// ```
     /// # #[macro_use] extern crate approx;
+    /// # use std::f64;
     /// # use nalgebra::{Rotation3, Vector3, Unit};
     /// let axis = Unit::new_normalize(Vector3::new(1.0, 2.0, 3.0));
     /// let angle = 1.2;
@@ -675,6 +803,12 @@ where
     /// let pow = rot.powf(2.0);
     /// assert_relative_eq!(pow.axis().unwrap(), axis, epsilon = 1.0e-6);
     /// assert_eq!(pow.angle(), 2.4);
+    ///
+    /// // Half of a half-turn is a quarter-turn around the same (possibly flipped) axis.
+    /// let half_turn = Rotation3::from_axis_angle(&Vector3::x_axis(), f64::consts::PI);
+    /// let quarter_turn = half_turn.powf(0.5);
+    /// assert_relative_eq!(quarter_turn.angle(), f64::consts::FRAC_PI_2, epsilon = 1.0e-6);
+    /// assert_relative_eq!(quarter_turn.powf(2.0), half_turn, epsilon = 1.0e-6);
     /// ```
     #[inline]
     #[must_use]
@@ -682,11 +816,32 @@ where
     where
         T: RealField,
     {
-        if let Some(axis) = self.axis() {
+        let rotmat = self.matrix();
+        let cos = (rotmat.trace() - T::one()) / crate::convert(2.0);
+
+        if cos < T::zero() {
+            // The rotation angle is larger than π/2, so the skew-symmetric part of the matrix
+            // (proportional to the angle's sine) gives an inaccurate axis, and even vanishes
+            // for an angle of π. Its symmetric part minus `cos * I` is however proportional
+            // to `axis * axis^T`, so its column with the largest diagonal element is
+            // collinear to the axis. The skew-symmetric part only determines its sign.
+            let sym = (rotmat + rotmat.transpose()) * crate::convert::<_, T>(0.5)
+                - SMatrix::<T, 3, 3>::from_diagonal_element(cos.clone());
+            let mut axis = sym.column(sym.diagonal().imax()).into_owned();
+            let skew = SVector::<T, 3>::new(
+                rotmat[(2, 1)].clone() - rotmat[(1, 2)].clone(),
+                rotmat[(0, 2)].clone() - rotmat[(2, 0)].clone(),
+                rotmat[(1, 0)].clone() - rotmat[(0, 1)].clone(),
+            );
+
+            if axis.dot(&skew) < T::zero() {
+                axis = -axis;
+            }
+
+            let angle = cos.max(-T::one()).acos();
+            Self::from_axis_angle(&Unit::new_normalize(axis), angle * n)
+        } else if let Some(axis) = self.axis() {
             Self::from_axis_angle(&axis, self.angle() * n)
-        } else if self.matrix()[(0, 0)] < T::zero() {
-            let minus_id = SMatrix::<T, 3, 3>::from_diagonal_element(-T::one());
-            Self::from_matrix_unchecked(minus_id)
         } else {
             Self::identity()
         }
@@ -703,6 +858,39 @@ where
         Self::from_matrix_unchecked(mat)
     }
 
+    /// Builds a rotation from a basis, checking that it is orthonormal and right-handed.
+    ///
+    /// The basis vectors become the columns of the rotation matrix. Returns `None` if the
+    /// resulting matrix is not a rotation matrix, up to `eps`, i.e. if the basis is not
+    /// orthonormal or if it is not right-handed (the result would be a reflection).
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::{Rotation3, Vector3};
+    /// let x = Vector3::new(0.0, 1.0, 0.0);
+    /// let y = Vector3::new(0.0, 0.0, 1.0);
+    /// let z = Vector3::new(1.0, 0.0, 0.0);
+    /// let rot = Rotation3::from_basis(&[x, y, z], 1.0e-7).unwrap();
+    /// assert_eq!(rot * Vector3::z(), z);
+    ///
+    /// // Not orthonormal.
+    /// assert!(Rotation3::from_basis(&[x, y, x + y], 1.0e-7).is_none());
+    /// // Orthonormal, but left-handed.
+    /// assert!(Rotation3::from_basis(&[x, y, -z], 1.0e-7).is_none());
+    /// ```
+    pub fn from_basis(basis: &[Vector3<T>; 3], eps: T) -> Option<Self>
+    where
+        T: RealField,
+    {
+        let mat = Matrix3::from_columns(&basis[..]);
+
+        if mat.is_special_orthogonal(eps) {
+            Some(Self::from_matrix_unchecked(mat))
+        } else {
+            None
+        }
+    }
+
     /// Builds a rotation matrix by extracting the rotation part of the given transformation `m`.
     ///
     /// This is an iterative method. See `.from_matrix_eps` to provide mover
@@ -877,6 +1065,30 @@ impl<T: SimdRealField> Rotation3<T> {
         }
     }
 
+    /// The logarithm map from the rotation group `SO(3)` to its Lie algebra `so(3)`.
+    ///
+    /// The returned tangent vector is the axis-angle representation of this rotation, i.e., the
+    /// rotation axis multiplied by the rotation angle in `[0; pi]`. This is the same as
+    /// `self.scaled_axis()` and is the inverse of [`Self::exp`]. The identity maps to the zero
+    /// vector.
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::{Rotation3, Vector3};
+    /// let rot = Rotation3::from_euler_angles(0.3, -0.5, 1.1);
+    /// assert_relative_eq!(Rotation3::exp(rot.log()), rot, epsilon = 1.0e-6);
+    /// assert_eq!(Rotation3::<f64>::identity().log(), Vector3::zeros());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn log(&self) -> Vector3<T>
+    where
+        T: RealField,
+    {
+        self.scaled_axis()
+    }
+
     /// The rotation axis and angle in ]0, pi] of this rotation matrix.
     ///
     /// Returns `None` if the angle is zero.
@@ -937,7 +1149,13 @@ impl<T: SimdRealField> Rotation3<T> {
 
     /// Euler angles corresponding to this rotation from a rotation.
     ///
-    /// The angles are produced in the form (roll, pitch, yaw).
+    /// The angles are produced in the form (roll, pitch, yaw), with the same convention as
+    /// [`Rotation3::from_euler_angles`]: `roll` is in `[-pi; pi]`, `pitch` in `[-pi/2; pi/2]`
+    /// and `yaw` in `[-pi; pi]`.
+    ///
+    /// At gimbal lock, i.e., if the pitch is `±pi/2`, the roll and yaw rotate about the same axis
+    /// so only their sum (or difference) is defined. In that case, the yaw is chosen to be zero
+    /// and the whole rotation about that axis is returned as the roll.
     ///
     /// # Example
     /// ```
@@ -956,14 +1174,19 @@ impl<T: SimdRealField> Rotation3<T> {
     {
         // Implementation informed by "Computing Euler angles from a rotation matrix", by Gregory G. Slabaugh
         //  https://citeseerx.ist.psu.edu/viewdoc/summary?doi=10.1.1.371.6578
-        if self[(2, 0)].clone().abs() < T::one() {
-            let yaw = -self[(2, 0)].clone().asin();
-            let roll = (self[(2, 1)].clone() / yaw.clone().cos())
-                .atan2(self[(2, 2)].clone() / yaw.clone().cos());
-            let pitch = (self[(1, 0)].clone() / yaw.clone().cos())
-                .atan2(self[(0, 0)].clone() / yaw.clone().cos());
-            (roll, yaw, pitch)
-        } else if self[(2, 0)].clone() <= -T::one() {
+        //
+        // The pitch is computed with `atan2` instead of `asin` so it is well-defined even if
+        // rounding errors push `self[(2, 0)]` slightly out of `[-1; 1]`.
+        let cos_pitch = self[(0, 0)].clone().hypot(self[(1, 0)].clone());
+
+        // Near gimbal lock, the other entries of the first column and last row are dominated by
+        // rounding errors, so we switch to the gimbal lock formulas before they become garbage.
+        if cos_pitch > T::default_epsilon().sqrt() {
+            let pitch = (-self[(2, 0)].clone()).atan2(cos_pitch);
+            let roll = self[(2, 1)].clone().atan2(self[(2, 2)].clone());
+            let yaw = self[(1, 0)].clone().atan2(self[(0, 0)].clone());
+            (roll, pitch, yaw)
+        } else if self[(2, 0)].clone() <= T::zero() {
             (
                 self[(0, 1)].clone().atan2(self[(0, 2)].clone()),
                 T::frac_pi_2(),
@@ -977,6 +1200,87 @@ impl<T: SimdRealField> Rotation3<T> {
             )
         }
     }
+
+    /// Euler angles corresponding to this rotation, following the given convention.
+    ///
+    /// The angles are produced in the form `(a1, a2, a3)` such that this rotation is equal to
+    /// `Rotation3::from_euler_angles_ordered(a1, a2, a3, order)`. The first and third angles are
+    /// in `[-pi; pi]`. The second one is in `[-pi/2; pi/2]` for Tait-Bryan axes, and in
+    /// `[0; pi]` for proper Euler axes.
+    ///
+    /// At gimbal lock, i.e., if the first and third elementary rotations are about the same axis,
+    /// only the sum (or difference) of their angles is defined. In that case, the angle of the
+    /// last elementary rotation performed (the third one for intrinsic rotations, and the first
+    /// one for extrinsic rotations) is chosen to be zero.
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::{EulerAxes, EulerOrder, Rotation3};
+    /// let order = EulerOrder::Intrinsic(EulerAxes::YXY);
+    /// let rot = Rotation3::from_euler_angles_ordered(0.1, 0.2, 0.3, order);
+    /// let euler = rot.euler_angles_ordered(order);
+    /// assert_relative_eq!(euler.0, 0.1, epsilon = 1.0e-6);
+    /// assert_relative_eq!(euler.1, 0.2, epsilon = 1.0e-6);
+    /// assert_relative_eq!(euler.2, 0.3, epsilon = 1.0e-6);
+    /// ```
+    #[must_use]
+    pub fn euler_angles_ordered(&self, order: EulerOrder) -> (T, T, T)
+    where
+        T: RealField,
+    {
+        // Extrinsic rotations are intrinsic rotations with the axes and angles reversed.
+        let (axes, extrinsic) = match order {
+            EulerOrder::Intrinsic(axes) => (axes, false),
+            EulerOrder::Extrinsic(axes) => (axes, true),
+        };
+        let proper = axes.is_proper_euler();
+        let [e1, e2, e3] = axes.indices();
+        let (a, b) = if extrinsic { (e3, e2) } else { (e1, e2) };
+        let c = 3 - a - b;
+
+        // The sign of the permutation `(a, b, c)`.
+        let s = if b == (a + 1) % 3 {
+            T::one()
+        } else {
+            -T::one()
+        };
+        let m = |i: usize, j: usize| self[(i, j)].clone();
+
+        // `lock_dist` is `|sin(t2)|` for proper Euler axes and `|cos(t2)|` for Tait-Bryan axes, so
+        // it is zero at gimbal lock.
+        let (t2, lock_dist) = if proper {
+            let sin_t2 = m(a, b).hypot(m(a, c));
+            (sin_t2.clone().atan2(m(a, a)), sin_t2)
+        } else {
+            let cos_t2 = m(a, a).hypot(m(a, b));
+            ((s.clone() * m(a, c)).atan2(cos_t2.clone()), cos_t2)
+        };
+
+        // Near gimbal lock, the entries used to compute the first and third angles are
+        // dominated by rounding errors, so we set the third one to zero instead.
+        let (t1, t3) = if lock_dist > T::default_epsilon().sqrt() {
+            if proper {
+                (
+                    m(b, a).atan2(-s.clone() * m(c, a)),
+                    m(a, b).atan2(s * m(a, c)),
+                )
+            } else {
+                (
+                    (-s.clone() * m(b, c)).atan2(m(c, c)),
+                    (-s * m(a, b)).atan2(m(a, a)),
+                )
+            }
+        } else {
+            ((s * m(c, b)).atan2(m(b, b)), T::zero())
+        };
+
+        if extrinsic {
+            (t3, t2, t1)
+        } else {
+            (t1, t2, t3)
+        }
+    }
 }
 
 #[cfg(feature = "rand-no-std")]
@@ -246,6 +246,9 @@ where
     /// be cheaper than inverting the similarity and then transforming the
     /// given point.
     ///
+    /// This computes `R⁻¹ * (pt - t) / s` directly, where `R`, `t` and `s` are the rotational,
+    /// translational and scaling parts of this similarity, without constructing its inverse.
+    ///
     /// # Example
     /// ```
     /// # #[macro_use] extern crate approx;
@@ -307,6 +310,41 @@ impl<T: SimdRealField, R, const D: usize> Similarity<T, R, D> {
 
         res
     }
+
+    /// Writes the homogeneous transformation matrix equivalent to this similarity into `out`.
+    ///
+    /// This computes the same matrix as `self.to_homogeneous()` (up to rounding errors), but
+    /// writes its blocks directly into `out` without building any intermediate matrix. This lets
+    /// the caller reuse an existing matrix, e.g., when converting many similarities in a loop.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use std::f32;
+    /// # use nalgebra::{Matrix4, Similarity3, Vector3};
+    /// let sim = Similarity3::new(Vector3::new(1.0, 2.0, 3.0), Vector3::z() * f32::consts::FRAC_PI_4, 2.0);
+    /// let mut out = Matrix4::zeros();
+    /// sim.fill_homogeneous(&mut out);
+    ///
+    /// assert_relative_eq!(out, sim.to_homogeneous(), epsilon = 1.0e-6);
+    /// ```
+    #[inline]
+    pub fn fill_homogeneous(
+        &self,
+        out: &mut OMatrix<T, DimNameSum<Const<D>, U1>, DimNameSum<Const<D>, U1>>,
+    ) where
+        Const<D>: DimNameAdd<U1>,
+        T::Element: SimdRealField,
+        R: AbstractRotation<T, D>,
+        DefaultAllocator: Allocator<T, DimNameSum<Const<D>, U1>, DimNameSum<Const<D>, U1>>,
+    {
+        self.isometry.fill_homogeneous(out);
+
+        for e in out.fixed_slice_mut::<D, D>(0, 0).iter_mut() {
+            *e *= self.scaling.clone()
+        }
+    }
 }
 
 impl<T: SimdRealField, R, const D: usize> Eq for Similarity<T, R, D> where
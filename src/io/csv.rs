@@ -0,0 +1,145 @@
+use std::error::Error;
+use std::fmt;
+use std::io::{self, BufRead, BufReader, Read};
+use std::str::FromStr;
+
+use crate::base::{DMatrix, Scalar};
+
+/// An error encountered while reading a dense matrix from CSV data.
+#[derive(Debug)]
+pub enum CsvParseError {
+    /// Reading from the input failed.
+    Io(io::Error),
+    /// A field could not be parsed as a matrix component.
+    ///
+    /// Lines and columns are numbered from 1, and lines include the header and empty lines.
+    InvalidField {
+        /// The line of the invalid field.
+        line: usize,
+        /// The column of the invalid field.
+        column: usize,
+    },
+    /// A row does not have the same number of fields as the first row.
+    RaggedRow {
+        /// The line of the ragged row, numbered from 1.
+        line: usize,
+        /// The number of fields of the first row.
+        expected: usize,
+        /// The number of fields of this row.
+        found: usize,
+    },
+}
+
+impl fmt::Display for CsvParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CsvParseError::Io(e) => write!(f, "CSV read error: {}", e),
+            CsvParseError::InvalidField { line, column } => {
+                write!(
+                    f,
+                    "CSV parse error: invalid field at line {}, column {}",
+                    line, column
+                )
+            }
+            CsvParseError::RaggedRow {
+                line,
+                expected,
+                found,
+            } => write!(
+                f,
+                "CSV parse error: line {} has {} fields instead of {}",
+                line, found, expected
+            ),
+        }
+    }
+}
+
+impl Error for CsvParseError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            CsvParseError::Io(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for CsvParseError {
+    fn from(e: io::Error) -> Self {
+        CsvParseError::Io(e)
+    }
+}
+
+impl<T: Scalar + FromStr> DMatrix<T> {
+    /// Reads a dense matrix from CSV-like text, with one matrix row per line.
+    ///
+    /// The fields of each line are separated by `delimiter` and parsed with `T::from_str` after
+    /// trimming any surrounding whitespace. Empty lines are ignored, and the first non-empty line
+    /// is skipped if `has_header` is `true`. An input without any row results in a `0x0` matrix.
+    ///
+    /// Quoted fields are not supported.
+    ///
+    /// # Errors
+    /// Returns an error if reading from `reader` fails, if a field cannot be parsed, or if the
+    /// rows do not all have the same number of fields.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::DMatrix;
+    /// let data = "x,y,z\n1.0, 2.0, 3.0\n4.0, 5.0, 6.0\n";
+    /// let m = DMatrix::<f64>::from_csv(data.as_bytes(), ',', true).unwrap();
+    ///
+    /// assert_eq!(m, DMatrix::from_row_slice(2, 3, &[1.0, 2.0, 3.0, 4.0, 5.0, 6.0]));
+    /// ```
+    pub fn from_csv<R: Read>(
+        reader: R,
+        delimiter: char,
+        has_header: bool,
+    ) -> Result<Self, CsvParseError> {
+        let mut skip_header = has_header;
+        let mut ncols = None;
+        let mut nrows = 0;
+        let mut data = Vec::new();
+
+        for (i, line) in BufReader::new(reader).lines().enumerate() {
+            let line = line?;
+            let line = line.trim();
+
+            if line.is_empty() {
+                continue;
+            }
+
+            if skip_header {
+                skip_header = false;
+                continue;
+            }
+
+            let len_before = data.len();
+
+            for (j, field) in line.split(delimiter).enumerate() {
+                let value = field
+                    .trim()
+                    .parse()
+                    .map_err(|_| CsvParseError::InvalidField {
+                        line: i + 1,
+                        column: j + 1,
+                    })?;
+                data.push(value);
+            }
+
+            let found = data.len() - len_before;
+            let expected = *ncols.get_or_insert(found);
+
+            if found != expected {
+                return Err(CsvParseError::RaggedRow {
+                    line: i + 1,
+                    expected,
+                    found,
+                });
+            }
+
+            nrows += 1;
+        }
+
+        Ok(DMatrix::from_row_slice(nrows, ncols.unwrap_or(0), &data))
+    }
+}
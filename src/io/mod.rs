@@ -1,5 +1,9 @@
 //! Parsers for various matrix formats.
 
+pub use self::csv::CsvParseError;
 pub use self::matrix_market::{cs_matrix_from_matrix_market, cs_matrix_from_matrix_market_str};
+pub use self::npy::{NpyElement, NpyError};
 
+mod csv;
 mod matrix_market;
+mod npy;
@@ -0,0 +1,312 @@
+use std::convert::TryFrom;
+use std::error::Error;
+use std::fmt;
+use std::fs::File;
+use std::io::{self, BufReader, BufWriter, Read, Write};
+use std::mem::size_of;
+use std::path::Path;
+
+use crate::base::{DMatrix, Scalar};
+
+const MAGIC: &[u8] = b"\x93NUMPY";
+
+/// An error encountered while reading or writing a matrix in the NumPy `.npy` format.
+#[derive(Debug)]
+pub enum NpyError {
+    /// Reading from the input or writing to the output failed.
+    Io(io::Error),
+    /// The data does not follow the `.npy` format.
+    InvalidFormat(String),
+    /// The data type of the array, described by the given NumPy type string (e.g. `<f4`), is
+    /// not the one of the matrix components.
+    UnsupportedDtype(String),
+    /// The array, with the given shape, has more than two dimensions.
+    UnsupportedShape(Vec<usize>),
+}
+
+impl fmt::Display for NpyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            NpyError::Io(e) => write!(f, "NPY I/O error: {}", e),
+            NpyError::InvalidFormat(msg) => write!(f, "NPY format error: {}", msg),
+            NpyError::UnsupportedDtype(descr) => {
+                write!(f, "NPY error: unsupported or mismatched dtype '{}'", descr)
+            }
+            NpyError::UnsupportedShape(shape) => write!(
+                f,
+                "NPY error: arrays with shape {:?} cannot be read as a matrix",
+                shape
+            ),
+        }
+    }
+}
+
+impl Error for NpyError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            NpyError::Io(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for NpyError {
+    fn from(e: io::Error) -> Self {
+        NpyError::Io(e)
+    }
+}
+
+/// A matrix component type that can be stored in the NumPy `.npy` format.
+pub trait NpyElement: Scalar + Copy {
+    /// The NumPy type string of this type, without its byte-order character, e.g., `f8`.
+    const DTYPE: &'static str;
+
+    /// Decodes a value from its little-endian (if `little_endian` is `true`) or big-endian
+    /// representation.
+    fn from_npy_bytes(bytes: &[u8], little_endian: bool) -> Self;
+
+    /// Appends the little-endian representation of `self` to `out`.
+    fn write_npy_bytes(self, out: &mut Vec<u8>);
+}
+
+macro_rules! impl_npy_element(
+    ($($t: ty, $dtype: expr);* $(;)*) => {$(
+        impl NpyElement for $t {
+            const DTYPE: &'static str = $dtype;
+
+            #[inline]
+            fn from_npy_bytes(bytes: &[u8], little_endian: bool) -> Self {
+                let mut buf = [0; size_of::<$t>()];
+                buf.copy_from_slice(bytes);
+
+                if little_endian {
+                    <$t>::from_le_bytes(buf)
+                } else {
+                    <$t>::from_be_bytes(buf)
+                }
+            }
+
+            #[inline]
+            fn write_npy_bytes(self, out: &mut Vec<u8>) {
+                out.extend_from_slice(&self.to_le_bytes())
+            }
+        }
+    )*}
+);
+
+impl_npy_element!(
+    f32, "f4"; f64, "f8";
+    i8, "i1"; i16, "i2"; i32, "i4"; i64, "i8";
+    u8, "u1"; u16, "u2"; u32, "u4"; u64, "u8";
+);
+
+/// Extracts the value of the given key from the Python dictionary literal of a `.npy` header.
+fn header_value<'a>(header: &'a str, key: &str) -> Result<&'a str, NpyError> {
+    let missing = || NpyError::InvalidFormat(format!("missing '{}' in the header", key));
+    let start = header.find(&format!("'{}'", key)).ok_or_else(missing)? + key.len() + 2;
+    let value = header[start..]
+        .trim_start()
+        .strip_prefix(':')
+        .ok_or_else(missing)?;
+    let value = value.trim_start();
+
+    // The value ends at the first comma that is not enclosed in parentheses.
+    let end = if value.starts_with('(') {
+        value.find(')').map(|i| i + 1)
+    } else {
+        value.find(&[',', '}'][..])
+    };
+
+    Ok(value[..end.ok_or_else(missing)?].trim())
+}
+
+/// Reads exactly `len` bytes from `reader`.
+///
+/// The buffer grows as the bytes are read instead of being allocated upfront, so that a
+/// malformed `len` only results in an error once the end of the data is reached.
+fn read_bytes<R: Read>(reader: &mut R, len: usize) -> Result<Vec<u8>, NpyError> {
+    let mut bytes = Vec::new();
+    let read = reader.take(len as u64).read_to_end(&mut bytes)?;
+
+    if read != len {
+        return Err(
+            io::Error::new(io::ErrorKind::UnexpectedEof, "failed to fill whole buffer").into(),
+        );
+    }
+
+    Ok(bytes)
+}
+
+fn parse_shape(value: &str) -> Result<Vec<usize>, NpyError> {
+    let invalid = || NpyError::InvalidFormat(format!("invalid shape {}", value));
+    let inner = value
+        .strip_prefix('(')
+        .and_then(|v| v.strip_suffix(')'))
+        .ok_or_else(invalid)?;
+
+    inner
+        .split(',')
+        .map(str::trim)
+        .filter(|dim| !dim.is_empty())
+        .map(|dim| dim.parse().map_err(|_| invalid()))
+        .collect()
+}
+
+impl<T: NpyElement> DMatrix<T> {
+    /// Reads a matrix from the NumPy `.npy` file at the given path.
+    ///
+    /// See `Self::from_npy_reader` for details.
+    pub fn read_npy<P: AsRef<Path>>(path: P) -> Result<Self, NpyError> {
+        Self::from_npy_reader(BufReader::new(File::open(path)?))
+    }
+
+    /// Reads a matrix from data in the NumPy `.npy` format.
+    ///
+    /// Two-dimensional arrays are read as matrices with the same shape, and one-dimensional
+    /// arrays as column vectors. Both the C (row-major) and Fortran (column-major) orders, and
+    /// both byte orders, are supported. The data type of the array must be the one of `T`.
+    ///
+    /// # Errors
+    /// Returns an error if reading from `reader` fails, if the data is not a valid `.npy` file,
+    /// if the array has more than two dimensions, or if its data type does not match `T`.
+    pub fn from_npy_reader<R: Read>(mut reader: R) -> Result<Self, NpyError> {
+        let mut preamble = [0; 8];
+        reader.read_exact(&mut preamble)?;
+
+        if &preamble[..6] != MAGIC {
+            return Err(NpyError::InvalidFormat(
+                "missing NPY magic string".to_string(),
+            ));
+        }
+
+        let header_len = match preamble[6] {
+            1 => {
+                let mut len = [0; 2];
+                reader.read_exact(&mut len)?;
+                u16::from_le_bytes(len) as usize
+            }
+            2 | 3 => {
+                let mut len = [0; 4];
+                reader.read_exact(&mut len)?;
+                u32::from_le_bytes(len) as usize
+            }
+            v => {
+                return Err(NpyError::InvalidFormat(format!(
+                    "unsupported format version {}",
+                    v
+                )))
+            }
+        };
+
+        let header = read_bytes(&mut reader, header_len)?;
+        let header = String::from_utf8(header)
+            .map_err(|_| NpyError::InvalidFormat("the header is not valid text".to_string()))?;
+
+        let descr = header_value(&header, "descr")?.trim_matches(|c| c == '\'' || c == '"');
+        let fortran_order = match header_value(&header, "fortran_order")? {
+            "True" => true,
+            "False" => false,
+            v => {
+                return Err(NpyError::InvalidFormat(format!(
+                    "invalid fortran_order {}",
+                    v
+                )))
+            }
+        };
+        let shape = parse_shape(header_value(&header, "shape")?)?;
+
+        let little_endian = match descr.as_bytes().first() {
+            Some(b'<') => true,
+            Some(b'>') => false,
+            Some(b'|') | Some(b'=') => cfg!(target_endian = "little"),
+            _ => return Err(NpyError::UnsupportedDtype(descr.to_string())),
+        };
+
+        if &descr[1..] != T::DTYPE {
+            return Err(NpyError::UnsupportedDtype(descr.to_string()));
+        }
+
+        let (nrows, ncols) = match shape[..] {
+            [n] => (n, 1),
+            [r, c] => (r, c),
+            _ => return Err(NpyError::UnsupportedShape(shape)),
+        };
+
+        let elt_size = size_of::<T>();
+        let len = nrows
+            .checked_mul(ncols)
+            .and_then(|n| n.checked_mul(elt_size))
+            .ok_or_else(|| {
+                NpyError::InvalidFormat(format!("the shape {:?} is too large", shape))
+            })?;
+        let bytes = read_bytes(&mut reader, len)?;
+        let values = bytes
+            .chunks_exact(elt_size)
+            .map(|b| T::from_npy_bytes(b, little_endian));
+
+        if fortran_order {
+            Ok(Self::from_iterator(nrows, ncols, values))
+        } else {
+            Ok(Self::from_row_iterator(nrows, ncols, values))
+        }
+    }
+
+    /// Writes this matrix to a NumPy `.npy` file at the given path.
+    ///
+    /// See `Self::write_npy_to` for details.
+    pub fn write_npy<P: AsRef<Path>>(&self, path: P) -> Result<(), NpyError> {
+        let mut writer = BufWriter::new(File::create(path)?);
+        self.write_npy_to(&mut writer)?;
+        writer.flush()?;
+        Ok(())
+    }
+
+    /// Writes this matrix in the NumPy `.npy` format.
+    ///
+    /// The matrix is written as a two-dimensional array in Fortran (column-major) order and
+    /// little-endian byte order, so that its data does not need to be reordered.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::DMatrix;
+    /// let m = DMatrix::from_row_slice(2, 3, &[1.0f32, 2.0, 3.0, 4.0, 5.0, 6.0]);
+    ///
+    /// let mut data = Vec::new();
+    /// m.write_npy_to(&mut data).unwrap();
+    /// assert_eq!(DMatrix::<f32>::from_npy_reader(&data[..]).unwrap(), m);
+    /// ```
+    pub fn write_npy_to<W: Write>(&self, mut writer: W) -> Result<(), NpyError> {
+        let descr_order = if T::DTYPE.ends_with('1') { '|' } else { '<' };
+        let mut header = format!(
+            "{{'descr': '{}{}', 'fortran_order': True, 'shape': ({}, {}), }}",
+            descr_order,
+            T::DTYPE,
+            self.nrows(),
+            self.ncols()
+        );
+
+        // The total header length, including the preamble and the final newline, must be a
+        // multiple of 64 bytes.
+        let preamble_len = MAGIC.len() + 4;
+        let padding = (64 - (preamble_len + header.len() + 1) % 64) % 64;
+        header.push_str(&" ".repeat(padding));
+        header.push('\n');
+
+        let header_len = u16::try_from(header.len())
+            .map_err(|_| NpyError::InvalidFormat("the header is too long".to_string()))?;
+
+        writer.write_all(MAGIC)?;
+        writer.write_all(&[1, 0])?;
+        writer.write_all(&header_len.to_le_bytes())?;
+        writer.write_all(header.as_bytes())?;
+
+        let mut bytes = Vec::with_capacity(self.len() * size_of::<T>());
+
+        for e in self.iter() {
+            e.write_npy_bytes(&mut bytes);
+        }
+
+        writer.write_all(&bytes)?;
+        Ok(())
+    }
+}
@@ -94,6 +94,9 @@ an optimized set of tools for computer graphics and physics. Those features incl
 #[cfg(feature = "rand-no-std")]
 extern crate rand_package as rand;
 
+#[cfg(feature = "rayon")]
+extern crate rayon_package as rayon;
+
 #[cfg(feature = "serde-serialize-no-std")]
 #[macro_use]
 extern crate serde;
@@ -11,6 +11,30 @@ use crate::linalg::householder;
 use std::mem::MaybeUninit;
 
 /// The bidiagonalization of a general matrix.
+///
+/// A matrix `M` with `R` rows and `C` columns is decomposed as `M = U * D * V^t` where `D` is
+/// a square bidiagonal matrix of dimension `min(R, C)`, and `U`, `V^t` have orthonormal
+/// columns and rows respectively. `D` is upper-bidiagonal if `R >= C` and lower-bidiagonal
+/// otherwise. The diagonal and off-diagonal elements of `D` are real and non-negative.
+///
+/// This is the first step of the computation of the [`SVD`](crate::linalg::SVD).
+///
+/// # Example
+/// ```
+/// # #[macro_use] extern crate approx;
+/// # use nalgebra::Matrix4x3;
+/// let m = Matrix4x3::new(1.0, 2.0, 3.0,
+///                        4.0, 5.0, 6.0,
+///                        7.0, 8.0, 9.0,
+///                        1.0, 0.0, 2.0);
+/// let bidiag = m.bidiagonalize();
+/// assert!(bidiag.is_upper_diagonal());
+///
+/// let (u, d, v_t) = bidiag.unpack();
+/// assert_relative_eq!(u * d * v_t, m, epsilon = 1.0e-7);
+/// assert_eq!(d[(2, 0)], 0.0);
+/// assert_eq!(d[(0, 2)], 0.0);
+/// ```
 #[cfg_attr(feature = "serde-serialize-no-std", derive(Serialize, Deserialize))]
 #[cfg_attr(
     feature = "serde-serialize-no-std",
@@ -148,7 +172,10 @@ where
         }
     }
 
-    /// Indicates whether this decomposition contains an upper-diagonal matrix.
+    /// Indicates whether this decomposition contains an upper-bidiagonal matrix.
+    ///
+    /// This is the case if, and only if, the decomposed matrix has at least as many rows as
+    /// columns.
     #[inline]
     #[must_use]
     pub fn is_upper_diagonal(&self) -> bool {
@@ -184,7 +211,10 @@ where
         (self.u(), self.d(), self.v_t())
     }
 
-    /// Retrieves the upper trapezoidal submatrix `R` of this decomposition.
+    /// Retrieves the bidiagonal matrix `D` of this `U * D * V^t` decomposition.
+    ///
+    /// Its only nonzero elements are its diagonal, and its first superdiagonal (if
+    /// `self.is_upper_diagonal()`) or subdiagonal (otherwise).
     #[inline]
     #[must_use]
     pub fn d(&self) -> OMatrix<T, DimMinimum<R, C>, DimMinimum<R, C>>
@@ -211,7 +241,7 @@ where
         res
     }
 
-    /// Computes the orthogonal matrix `U` of this `U * D * V` decomposition.
+    /// Computes the orthogonal matrix `U` of this `U * D * V^t` decomposition.
     // TODO: code duplication with householder::assemble_q.
     // Except that we are returning a rectangular matrix here.
     #[must_use]
@@ -244,7 +274,7 @@ where
         res
     }
 
-    /// Computes the orthogonal matrix `V_t` of this `U * D * V_t` decomposition.
+    /// Computes the orthogonal matrix `V^t` of this `U * D * V^t` decomposition.
     #[must_use]
     pub fn v_t(&self) -> OMatrix<T, DimMinimum<R, C>, C>
     where
@@ -280,7 +310,7 @@ where
         res
     }
 
-    /// The diagonal part of this decomposed matrix.
+    /// The diagonal part of this decomposed matrix, i.e., the diagonal of `self.d()`.
     #[must_use]
     pub fn diagonal(&self) -> OVector<T::RealField, DimMinimum<R, C>>
     where
@@ -289,7 +319,8 @@ where
         self.diagonal.map(|e| e.modulus())
     }
 
-    /// The off-diagonal part of this decomposed matrix.
+    /// The off-diagonal part of this decomposed matrix, i.e., the superdiagonal (resp.
+    /// subdiagonal) of `self.d()` if it is upper-bidiagonal (resp. lower-bidiagonal).
     #[must_use]
     pub fn off_diagonal(&self) -> OVector<T::RealField, DimDiff<DimMinimum<R, C>, U1>>
     where
@@ -117,7 +117,28 @@ where
 
     /// Solves the system `self * x = b` where `self` is the decomposed matrix and `x` the unknown.
     ///
-    /// The result is stored on `b`.
+    /// The result is stored on `b`. This performs the forward and backward substitutions in-place
+    /// without allocating, so the same decomposition and right-hand-side storage can be reused
+    /// across many solves.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::{Matrix3, Matrix3x2};
+    /// let m = Matrix3::new(4.0, 2.0, 0.0,
+    ///                      2.0, 5.0, 1.0,
+    ///                      0.0, 1.0, 3.0);
+    /// let chol = m.cholesky().unwrap();
+    ///
+    /// // Each column of `x` is a right-hand side, replaced by the corresponding solution.
+    /// let b = Matrix3x2::new(1.0, 4.0,
+    ///                        2.0, 5.0,
+    ///                        3.0, 6.0);
+    /// let mut x = b;
+    /// chol.solve_mut(&mut x);
+    /// assert_relative_eq!(m * x, b, epsilon = 1.0e-10);
+    /// ```
     pub fn solve_mut<R2: Dim, C2: Dim, S2>(&self, b: &mut Matrix<T, R2, C2, S2>)
     where
         S2: StorageMut<T, R2, C2>,
@@ -129,6 +150,23 @@ where
 
     /// Returns the solution of the system `self * x = b` where `self` is the decomposed matrix and
     /// `x` the unknown.
+    ///
+    /// Since vectors are single-column matrices, `b` can be a vector, in which case the solution
+    /// is a vector too.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::{Matrix3, Vector3};
+    /// let m = Matrix3::new(4.0, 2.0, 0.0,
+    ///                      2.0, 5.0, 1.0,
+    ///                      0.0, 1.0, 3.0);
+    /// let b = Vector3::new(1.0, 2.0, 3.0);
+    ///
+    /// let x: Vector3<f64> = m.cholesky().unwrap().solve(&b);
+    /// assert_relative_eq!(m * x, b, epsilon = 1.0e-10);
+    /// ```
     #[must_use = "Did you mean to use solve_mut()?"]
     pub fn solve<R2: Dim, C2: Dim, S2>(&self, b: &Matrix<T, R2, C2, S2>) -> OMatrix<T, R2, C2>
     where
@@ -1,8 +1,8 @@
 use crate::storage::Storage;
 use crate::{
     Allocator, Bidiagonal, Cholesky, ColPivQR, ComplexField, DefaultAllocator, Dim, DimDiff,
-    DimMin, DimMinimum, DimSub, FullPivLU, Hessenberg, Matrix, OMatrix, RealField, Schur,
-    SymmetricEigen, SymmetricTridiagonal, LU, QR, SVD, U1, UDU,
+    DimMin, DimMinimum, DimSub, FullPivLU, Hessenberg, Matrix, OMatrix, PivotedLDL, RealField,
+    Schur, SymmetricEigen, SymmetricTridiagonal, LU, QR, SVD, U1, UDU,
 };
 
 /// # Rectangular matrix decomposition
@@ -255,6 +255,7 @@ impl<T: ComplexField, R: Dim, C: Dim, S: Storage<T, R, C>> Matrix<T, R, C, S> {
 /// | Hessenberg               | `Q * H * Qᵀ`             | `Q` is a unitary matrix and `H` an upper-Hessenberg matrix. |
 /// | Cholesky                 | `L * Lᵀ`                 | `L` is a lower-triangular matrix. |
 /// | UDU                      | `U * D * Uᵀ`             | `U` is a upper-triangular matrix, and `D` a diagonal matrix. |
+/// | Pivoted LDL              | `Pᵀ * L * D * Lᵀ * P`    | `L` is a lower-triangular matrix with a diagonal filled with `1`, `D` a non-negative diagonal matrix, and `P` a permutation matrix. |
 /// | Schur decomposition      | `Q * T * Qᵀ`             | `Q` is an unitary matrix and `T` a quasi-upper-triangular matrix. |
 /// | Symmetric eigendecomposition | `Q ~ Λ ~ Qᵀ`   | `Q` is an unitary matrix, and `Λ` is a real diagonal matrix. |
 /// | Symmetric tridiagonalization | `Q ~ T ~ Qᵀ`   | `Q` is an unitary matrix, and `T` is a tridiagonal matrix. |
@@ -282,6 +283,19 @@ impl<T: ComplexField, D: Dim, S: Storage<T, D, D>> Matrix<T, D, D, S> {
         UDU::new(self.into_owned())
     }
 
+    /// Computes the LDLᵀ decomposition with symmetric pivoting of this positive-semidefinite
+    /// matrix.
+    ///
+    /// Unlike `.cholesky()`, this succeeds on singular matrices and reveals their numerical rank.
+    /// The input matrix is assumed to be symmetric and only the lower-triangular part is read.
+    pub fn cholesky_pivoted(self) -> PivotedLDL<T, D>
+    where
+        T: RealField,
+        DefaultAllocator: Allocator<T, D, D> + Allocator<T, D> + Allocator<(usize, usize), D>,
+    {
+        PivotedLDL::new(self.into_owned())
+    }
+
     /// Computes the Hessenberg decomposition of this matrix using householder reflections.
     pub fn hessenberg(self) -> Hessenberg<T, D>
     where
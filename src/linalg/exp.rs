@@ -488,6 +488,29 @@ where
         + Allocator<T::RealField, D, D>,
 {
     /// Computes exponential of this matrix
+    ///
+    /// This uses a scaling-and-squaring Padé approximation and works for both real and complex
+    /// matrices.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::{Complex, Matrix2};
+    /// // Time propagator `exp(-i * H * t)` of the Hamiltonian `H = σx` (a Pauli matrix).
+    /// let t = 0.5f64;
+    /// let i = Complex::new(0.0, 1.0);
+    /// let h = Matrix2::new(0.0, 1.0, 1.0, 0.0).map(Complex::from);
+    /// let u = (h * (-i * t)).exp();
+    ///
+    /// let expected = Matrix2::new(
+    ///     Complex::new(t.cos(), 0.0), Complex::new(0.0, -t.sin()),
+    ///     Complex::new(0.0, -t.sin()), Complex::new(t.cos(), 0.0),
+    /// );
+    /// assert_relative_eq!(u, expected, epsilon = 1.0e-10);
+    /// // The propagator is unitary.
+    /// assert_relative_eq!(u.adjoint() * u, Matrix2::identity(), epsilon = 1.0e-10);
+    /// ```
     #[must_use]
     pub fn exp(&self) -> Self {
         // Simple case
@@ -6,6 +6,7 @@ use crate::base::{DefaultAllocator, Matrix, OMatrix};
 use crate::constraint::{SameNumberOfRows, ShapeConstraint};
 use crate::dimension::{Dim, DimMin, DimMinimum};
 use crate::storage::{Storage, StorageMut};
+use num::Zero;
 use simba::scalar::ComplexField;
 
 use crate::linalg::lu;
@@ -154,6 +155,26 @@ where
 
         (p, l, u, q)
     }
+
+    /// The rank of the decomposed matrix.
+    ///
+    /// Because of the full pivoting, the largest elements are selected first as the diagonal of
+    /// `U`: the rank is the number of leading diagonal elements of `U` with a modulus greater
+    /// than `eps`.
+    #[must_use]
+    pub fn rank(&self, eps: T::RealField) -> usize {
+        assert!(
+            eps >= T::RealField::zero(),
+            "FullPivLU rank: the epsilon must be non-negative."
+        );
+
+        let (nrows, ncols) = self.lu.shape_generic();
+        let dim = nrows.min(ncols).value();
+
+        (0..dim)
+            .position(|i| self.lu[(i, i)].clone().modulus() <= eps)
+            .unwrap_or(dim)
+    }
 }
 
 impl<T: ComplexField, D: DimMin<D, Output = D>> FullPivLU<T, D, D>
@@ -212,6 +233,77 @@ where
         }
     }
 
+    /// Computes a particular solution of the linear system `self * x = b`, even if the
+    /// decomposed matrix is singular.
+    ///
+    /// The decomposed matrix is considered to have rank `r = self.rank(eps)`. If the system is
+    /// consistent, i.e., if the `n - r` equations eliminated by the decomposition are satisfied
+    /// up to `eps`, this returns the solution whose components along the `n - r` non-pivot
+    /// columns are zero. Otherwise, `None` is returned. If the matrix is invertible, this is the
+    /// same as `self.solve(b)`.
+    #[must_use]
+    pub fn solve_particular<R2: Dim, C2: Dim, S2>(
+        &self,
+        b: &Matrix<T, R2, C2, S2>,
+        eps: T::RealField,
+    ) -> Option<OMatrix<T, R2, C2>>
+    where
+        S2: Storage<T, R2, C2>,
+        ShapeConstraint: SameNumberOfRows<R2, D>,
+        DefaultAllocator: Allocator<T, R2, C2>,
+    {
+        let mut res = b.clone_owned();
+        if self.solve_particular_mut(&mut res, eps) {
+            Some(res)
+        } else {
+            None
+        }
+    }
+
+    /// Computes in-place a particular solution of the linear system `self * x = b`, even if the
+    /// decomposed matrix is singular.
+    ///
+    /// See `self.solve_particular(b, eps)` for details. If the system is inconsistent, this
+    /// returns `false` and its input `b` may be overwritten with garbage.
+    pub fn solve_particular_mut<R2: Dim, C2: Dim, S2>(
+        &self,
+        b: &mut Matrix<T, R2, C2, S2>,
+        eps: T::RealField,
+    ) -> bool
+    where
+        S2: StorageMut<T, R2, C2>,
+        ShapeConstraint: SameNumberOfRows<R2, D>,
+    {
+        assert_eq!(
+            self.lu.nrows(),
+            b.nrows(),
+            "FullPivLU solve matrix dimension mismatch."
+        );
+        assert!(
+            self.lu.is_square(),
+            "FullPivLU solve: unable to solve a non-square system."
+        );
+
+        let rank = self.rank(eps.clone());
+
+        self.p.permute_rows(b);
+        let _ = self.lu.solve_lower_triangular_with_diag_mut(b, T::one());
+
+        let mut null = b.rows_range_mut(rank..);
+        if null.iter().any(|e| e.clone().modulus() > eps) {
+            return false;
+        }
+        null.fill(T::zero());
+
+        let _ = self
+            .lu
+            .slice_range(..rank, ..rank)
+            .solve_upper_triangular_mut(&mut b.rows_range_mut(..rank));
+        self.q.inv_permute_rows(b);
+
+        true
+    }
+
     /// Computes the inverse of the decomposed matrix.
     ///
     /// Returns `None` if the decomposed matrix is not invertible.
@@ -9,6 +9,11 @@ use crate::base::storage::{Storage, StorageMut};
 use crate::base::{Matrix, Vector};
 
 /// A Givens rotation.
+///
+/// This is the rotation of the plane spanned by two coordinate axes, represented by its cosine
+/// `c` and its sine `s`. When acting on two components, it is the matrix `[c, -s*; s, c]` where
+/// `s*` is the conjugate of `s`. It can be applied to two rows or two columns of any matrix with
+/// `.apply_left` and `.apply_right`.
 #[derive(Debug, Clone, Copy)]
 pub struct GivensRotation<T: ComplexField> {
     c: T::RealField,
@@ -34,15 +39,46 @@ impl<T: ComplexField> GivensRotation<T> {
     }
 
     /// Initializes a Givens rotation from its non-normalized cosine an sine components.
+    ///
+    /// Returns the rotation and `r`, the norm of `(c, s)` multiplied by the sign of `c`. The
+    /// inverse of the returned rotation maps the vector `(c, s)` to `(r, 0)`, so it can be used
+    /// to cancel `s`. If `c` and `s` are both zero, the identity and a zero `r` are returned.
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::Vector2;
+    /// # use nalgebra::linalg::givens::GivensRotation;
+    /// let (rot, r) = GivensRotation::new(3.0, -4.0);
+    /// assert_eq!(r, 5.0);
+    /// assert_relative_eq!(rot.c(), 0.6, epsilon = 1.0e-10);
+    /// assert_relative_eq!(rot.s(), -0.8, epsilon = 1.0e-10);
+    ///
+    /// let mut v = Vector2::new(3.0, -4.0);
+    /// rot.inverse().rotate(&mut v);
+    /// assert_relative_eq!(v, Vector2::new(5.0, 0.0), epsilon = 1.0e-10);
+    /// ```
     pub fn new(c: T, s: T) -> (Self, T) {
         Self::try_new(c, s, T::RealField::zero())
             .unwrap_or_else(|| (GivensRotation::identity(), T::zero()))
     }
 
     /// Initializes a Givens rotation form its non-normalized cosine an sine components.
+    ///
+    /// This is the same as `Self::new`, except that `None` is returned if the norm of `(c, s)`
+    /// is smaller than or equal to `eps`. In particular, `None` is returned if both `c` and `s`
+    /// are zero.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::linalg::givens::GivensRotation;
+    /// assert!(GivensRotation::<f64>::try_new(0.0, 0.0, 0.0).is_none());
+    /// assert!(GivensRotation::try_new(1.0e-8, 0.0, 1.0e-6).is_none());
+    /// assert_eq!(GivensRotation::try_new(0.0, 2.0, 1.0e-6).unwrap().1, 2.0);
+    /// ```
     pub fn try_new(c: T, s: T, eps: T::RealField) -> Option<(Self, T)> {
         let (mod0, sign0) = c.to_exp();
-        let denom = (mod0.clone() * mod0.clone() + s.clone().modulus_squared()).sqrt();
+        let denom = mod0.clone().hypot(s.clone().modulus());
 
         if denom > eps {
             let norm = sign0.scale(denom.clone());
@@ -61,7 +97,7 @@ impl<T: ComplexField> GivensRotation<T> {
     pub fn cancel_y<S: Storage<T, U2>>(v: &Vector<T, U2, S>) -> Option<(Self, T)> {
         if !v[1].is_zero() {
             let (mod0, sign0) = v[0].clone().to_exp();
-            let denom = (mod0.clone() * mod0.clone() + v[1].clone().modulus_squared()).sqrt();
+            let denom = mod0.clone().hypot(v[1].clone().modulus());
             let c = mod0 / denom.clone();
             let s = -v[1].clone() / sign0.clone().scale(denom.clone());
             let r = sign0.scale(denom);
@@ -78,7 +114,7 @@ impl<T: ComplexField> GivensRotation<T> {
     pub fn cancel_x<S: Storage<T, U2>>(v: &Vector<T, U2, S>) -> Option<(Self, T)> {
         if !v[0].is_zero() {
             let (mod1, sign1) = v[1].clone().to_exp();
-            let denom = (mod1.clone() * mod1.clone() + v[0].clone().modulus_squared()).sqrt();
+            let denom = mod1.clone().hypot(v[0].clone().modulus());
             let c = mod1 / denom.clone();
             let s = (v[0].clone().conjugate() * sign1.clone()).unscale(denom.clone());
             let r = sign1.scale(denom);
@@ -162,4 +198,65 @@ impl<T: ComplexField> GivensRotation<T> {
             }
         }
     }
+
+    /// Performs the multiplication `rhs = self * rhs` in-place, where `self` acts on the rows
+    /// `irow1` and `irow2` of `rhs`.
+    ///
+    /// This is equivalent to calling `self.rotate` on the `2 × ncols` matrix made of these two
+    /// rows. Panics if `irow1 == irow2` or if any of them is out of bounds.
+    pub fn apply_left<R2: Dim, C2: Dim, S2: StorageMut<T, R2, C2>>(
+        &self,
+        rhs: &mut Matrix<T, R2, C2, S2>,
+        irow1: usize,
+        irow2: usize,
+    ) {
+        assert!(
+            irow1 != irow2 && irow1 < rhs.nrows() && irow2 < rhs.nrows(),
+            "Givens rotation: invalid row indices."
+        );
+        let s = self.s.clone();
+        let c = self.c.clone();
+
+        for j in 0..rhs.ncols() {
+            unsafe {
+                let a = rhs.get_unchecked((irow1, j)).clone();
+                let b = rhs.get_unchecked((irow2, j)).clone();
+
+                *rhs.get_unchecked_mut((irow1, j)) =
+                    a.clone().scale(c.clone()) - s.clone().conjugate() * b.clone();
+                *rhs.get_unchecked_mut((irow2, j)) = s.clone() * a + b.scale(c.clone());
+            }
+        }
+    }
+
+    /// Performs the multiplication `lhs = lhs * self` in-place, where `self` acts on the columns
+    /// `icol1` and `icol2` of `lhs`.
+    ///
+    /// This is equivalent to calling `self.rotate_rows` on the `nrows × 2` matrix made of these
+    /// two columns. Panics if `icol1 == icol2` or if any of them is out of bounds.
+    pub fn apply_right<R2: Dim, C2: Dim, S2: StorageMut<T, R2, C2>>(
+        &self,
+        lhs: &mut Matrix<T, R2, C2, S2>,
+        icol1: usize,
+        icol2: usize,
+    ) {
+        assert!(
+            icol1 != icol2 && icol1 < lhs.ncols() && icol2 < lhs.ncols(),
+            "Givens rotation: invalid column indices."
+        );
+        let s = self.s.clone();
+        let c = self.c.clone();
+
+        for i in 0..lhs.nrows() {
+            unsafe {
+                let a = lhs.get_unchecked((i, icol1)).clone();
+                let b = lhs.get_unchecked((i, icol2)).clone();
+
+                *lhs.get_unchecked_mut((i, icol1)) =
+                    a.clone().scale(c.clone()) + s.clone() * b.clone();
+                *lhs.get_unchecked_mut((i, icol2)) =
+                    -s.clone().conjugate() * a + b.scale(c.clone());
+            }
+        }
+    }
 }
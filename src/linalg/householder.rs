@@ -1,9 +1,14 @@
 //! Construction of householder elementary reflections.
+//!
+//! The reflectors are exposed through `Vector::householder`, `Matrix::apply_householder_left`,
+//! and `Matrix::apply_householder_right`, so that they can be used to implement other
+//! decompositions.
 
 use crate::allocator::Allocator;
-use crate::base::{DefaultAllocator, OMatrix, OVector, Unit, Vector};
-use crate::dimension::Dim;
-use crate::storage::StorageMut;
+use crate::base::constraint::{AreMultipliable, DimEq, ShapeConstraint};
+use crate::base::{DefaultAllocator, Matrix, OMatrix, OVector, Unit, Vector};
+use crate::dimension::{Const, Dim, U1};
+use crate::storage::{Storage, StorageMut};
 use num::Zero;
 use simba::scalar::ComplexField;
 
@@ -137,3 +142,97 @@ where
 
     res
 }
+
+impl<T: ComplexField, D: Dim, S: Storage<T, D>> Vector<T, D, S> {
+    /// Computes the householder reflector that maps `self` to a multiple of the first canonical
+    /// basis vector.
+    ///
+    /// Returns `(v, beta)` such that `(I - beta * v * v.adjoint()) * self = (alpha, 0, ..., 0)`
+    /// with `|alpha| = |self|`. The first component of `v` is always one. If `self` is zero,
+    /// `beta` is zero and the reflector is the identity.
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::Vector3;
+    /// let x = Vector3::new(3.0, 4.0, 0.0);
+    /// let (v, beta) = x.householder();
+    /// let mut y = x;
+    /// y.apply_householder_left(&v, beta);
+    ///
+    /// assert_relative_eq!(y, Vector3::new(-5.0, 0.0, 0.0), epsilon = 1.0e-10);
+    /// ```
+    #[must_use]
+    pub fn householder(&self) -> (OVector<T, D>, T::RealField)
+    where
+        DefaultAllocator: Allocator<T, D>,
+    {
+        let mut v = self.clone_owned();
+
+        if v.is_empty() {
+            return (v, T::RealField::zero());
+        }
+
+        let norm = v.norm();
+
+        if norm.is_zero() {
+            v[0] = T::one();
+            return (v, T::RealField::zero());
+        }
+
+        // The reflected vector is `alpha * e1` with `alpha = -sign(x0) * |x|` so that
+        // `v = x - alpha * e1` does not suffer from cancellation.
+        let (modulus, sign) = v[0].clone().to_exp();
+        let v0 = sign.scale(modulus + norm);
+
+        for i in 1..v.len() {
+            v[i] = v[i].clone() / v0.clone();
+        }
+
+        v[0] = T::one();
+        let beta = crate::convert::<_, T::RealField>(2.0) / v.norm_squared();
+
+        (v, beta)
+    }
+}
+
+impl<T: ComplexField, R: Dim, C: Dim, S: StorageMut<T, R, C>> Matrix<T, R, C, S> {
+    /// Computes `self = (I - beta * v * v.adjoint()) * self` in-place.
+    ///
+    /// The pair `(v, beta)` is typically obtained from [`Vector::householder`].
+    pub fn apply_householder_left<D2: Dim, S2>(&mut self, v: &Vector<T, D2, S2>, beta: T::RealField)
+    where
+        S2: Storage<T, D2>,
+        DefaultAllocator: Allocator<T, C>,
+        ShapeConstraint: DimEq<R, D2> + AreMultipliable<C, R, D2, U1>,
+    {
+        if beta.is_zero() {
+            return;
+        }
+
+        let mut work = OVector::zeros_generic(self.shape_generic().1, Const::<1>);
+        work.gemv_ad(T::one(), &*self, v, T::zero());
+        self.gerc(-T::from_real(beta), v, &work, T::one());
+    }
+
+    /// Computes `self = self * (I - beta * v * v.adjoint())` in-place.
+    ///
+    /// The pair `(v, beta)` is typically obtained from [`Vector::householder`].
+    pub fn apply_householder_right<D2: Dim, S2>(
+        &mut self,
+        v: &Vector<T, D2, S2>,
+        beta: T::RealField,
+    ) where
+        S2: Storage<T, D2>,
+        DefaultAllocator: Allocator<T, R>,
+        ShapeConstraint: DimEq<C, D2> + AreMultipliable<R, C, D2, U1>,
+    {
+        if beta.is_zero() {
+            return;
+        }
+
+        let mut work = OVector::zeros_generic(self.shape_generic().0, Const::<1>);
+        work.gemv(T::one(), &*self, v, T::zero());
+        self.gerc(-T::from_real(beta), &work, v, T::one());
+    }
+}
@@ -25,8 +25,28 @@ impl<T: ComplexField, D: Dim, S: Storage<T, D, D>> SquareMatrix<T, D, S> {
 }
 
 impl<T: ComplexField, D: Dim, S: StorageMut<T, D, D>> SquareMatrix<T, D, S> {
-    /// Attempts to invert this matrix in-place. Returns `false` and leaves `self` untouched if
-    /// inversion fails.
+    /// Attempts to invert this matrix in-place, reusing its storage.
+    ///
+    /// Matrices up to 4x4 are inverted with closed-form formulas, and larger ones with a
+    /// Gauss-Jordan elimination with partial pivoting.
+    ///
+    /// Returns `false` if the matrix is not invertible. In that case, matrices up to 3x3 are left
+    /// untouched, but the content of larger matrices is unspecified.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is not square.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::Matrix2;
+    /// let mut m = Matrix2::new(2.0, 0.0, 0.0, 4.0);
+    /// assert!(m.try_inverse_mut());
+    /// assert_eq!(m, Matrix2::new(0.5, 0.0, 0.0, 0.25));
+    ///
+    /// let mut singular = Matrix2::new(1.0, 2.0, 2.0, 4.0);
+    /// assert!(!singular.try_inverse_mut());
+    /// ```
     #[inline]
     pub fn try_inverse_mut(&mut self) -> bool
     where
@@ -215,7 +215,20 @@ where
 {
     /// Solves the linear system `self * x = b`, where `x` is the unknown to be determined.
     ///
-    /// Returns `None` if `self` is not invertible.
+    /// Returns `None` if `self` is not invertible. Since vectors are single-column matrices,
+    /// `b` can be a vector, in which case the solution is a vector too.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::{DMatrix, DVector};
+    /// let m = DMatrix::from_row_slice(2, 2, &[2.0, 1.0, 1.0, 3.0]);
+    /// let b = DVector::from_column_slice(&[3.0, 5.0]);
+    ///
+    /// let x: DVector<f64> = m.clone().lu().solve(&b).unwrap();
+    /// assert_relative_eq!(m * x, b, epsilon = 1.0e-10);
+    /// ```
     #[must_use = "Did you mean to use solve_mut()?"]
     pub fn solve<R2: Dim, C2: Dim, S2>(
         &self,
@@ -236,8 +249,28 @@ where
 
     /// Solves the linear system `self * x = b`, where `x` is the unknown to be determined.
     ///
-    /// If the decomposed matrix is not invertible, this returns `false` and its input `b` may
-    /// be overwritten with garbage.
+    /// The result is stored on `b`, without allocating. If the decomposed matrix is not
+    /// invertible, this returns `false` and its input `b` may be overwritten with garbage.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::{Matrix2, Matrix2x3};
+    /// let m = Matrix2::new(2.0, 1.0,
+    ///                      1.0, 3.0);
+    /// let lu = m.lu();
+    ///
+    /// // Each column of `x` is a right-hand side, replaced by the corresponding solution.
+    /// let b = Matrix2x3::new(3.0, 1.0, 0.0,
+    ///                        5.0, 0.0, 1.0);
+    /// let mut x = b;
+    /// assert!(lu.solve_mut(&mut x));
+    /// assert_relative_eq!(m * x, b, epsilon = 1.0e-10);
+    ///
+    /// // Singular matrices cannot be solved.
+    /// assert!(!Matrix2::new(1.0, 2.0, 2.0, 4.0).lu().solve_mut(&mut x));
+    /// ```
     pub fn solve_mut<R2: Dim, C2: Dim, S2>(&self, b: &mut Matrix<T, R2, C2, S2>) -> bool
     where
         S2: StorageMut<T, R2, C2>,
@@ -19,8 +19,11 @@ pub mod householder;
 mod inverse;
 mod lu;
 mod permutation_sequence;
+mod pivoted_ldl;
 mod pow;
 mod qr;
+#[cfg(any(feature = "std", feature = "alloc"))]
+mod row_echelon;
 mod schur;
 mod solve;
 mod svd;
@@ -44,6 +47,7 @@ pub use self::full_piv_lu::*;
 pub use self::hessenberg::*;
 pub use self::lu::*;
 pub use self::permutation_sequence::*;
+pub use self::pivoted_ldl::*;
 pub use self::pow::*;
 pub use self::qr::*;
 pub use self::schur::*;
@@ -0,0 +1,270 @@
+#[cfg(feature = "serde-serialize-no-std")]
+use serde::{Deserialize, Serialize};
+
+use crate::allocator::Allocator;
+use crate::base::{Const, DefaultAllocator, Matrix, OMatrix, OVector};
+use crate::constraint::{SameNumberOfRows, ShapeConstraint};
+use crate::dimension::Dim;
+use crate::storage::{Storage, StorageMut};
+use simba::scalar::RealField;
+
+use crate::linalg::PermutationSequence;
+
+/// LDLᵀ decomposition with symmetric (diagonal) pivoting of a symmetric positive-semidefinite
+/// matrix.
+///
+/// This computes a permutation `P`, a lower-triangular matrix `L` with a unit diagonal, and a
+/// diagonal matrix `D` with non-negative entries such that `P * A * Pᵀ = L * D * Lᵀ`. At each
+/// step, the largest remaining diagonal element is selected as the pivot, so the entries of `D`
+/// are sorted in decreasing order and the factorization stops as soon as the remaining pivots
+/// are negligible. The number of non-zero entries of `D` is the numerical rank of `A`.
+#[cfg_attr(feature = "serde-serialize-no-std", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serde-serialize-no-std",
+    serde(bound(serialize = "DefaultAllocator: Allocator<T, D, D> +
+                           Allocator<T, D> +
+                           Allocator<(usize, usize), D>,
+         OMatrix<T, D, D>: Serialize,
+         OVector<T, D>: Serialize,
+         PermutationSequence<D>: Serialize"))
+)]
+#[cfg_attr(
+    feature = "serde-serialize-no-std",
+    serde(bound(deserialize = "DefaultAllocator: Allocator<T, D, D> +
+                           Allocator<T, D> +
+                           Allocator<(usize, usize), D>,
+         OMatrix<T, D, D>: Deserialize<'de>,
+         OVector<T, D>: Deserialize<'de>,
+         PermutationSequence<D>: Deserialize<'de>"))
+)]
+#[derive(Clone, Debug)]
+pub struct PivotedLDL<T: RealField, D: Dim>
+where
+    DefaultAllocator: Allocator<T, D, D> + Allocator<T, D> + Allocator<(usize, usize), D>,
+{
+    ldl: OMatrix<T, D, D>,
+    d: OVector<T, D>,
+    p: PermutationSequence<D>,
+    rank: usize,
+}
+
+impl<T: RealField, D: Dim> Copy for PivotedLDL<T, D>
+where
+    DefaultAllocator: Allocator<T, D, D> + Allocator<T, D> + Allocator<(usize, usize), D>,
+    OMatrix<T, D, D>: Copy,
+    OVector<T, D>: Copy,
+    PermutationSequence<D>: Copy,
+{
+}
+
+impl<T: RealField, D: Dim> PivotedLDL<T, D>
+where
+    DefaultAllocator: Allocator<T, D, D> + Allocator<T, D> + Allocator<(usize, usize), D>,
+{
+    /// Computes the pivoted LDLᵀ decomposition of the symmetric positive-semidefinite `matrix`.
+    ///
+    /// Pivots smaller than `n * ε * max(diag(matrix))`, where `ε` is the machine epsilon, are
+    /// considered to be zero. Only the lower-triangular part (including the diagonal) of
+    /// `matrix` is read.
+    pub fn new(matrix: OMatrix<T, D, D>) -> Self {
+        let n: T = crate::convert(matrix.nrows() as f64);
+        let max_diag = matrix
+            .diagonal()
+            .iter()
+            .fold(T::zero(), |max, e| max.max(e.clone()));
+
+        Self::new_with_tolerance(matrix, n * T::default_epsilon() * max_diag)
+    }
+
+    /// Computes the pivoted LDLᵀ decomposition of the symmetric positive-semidefinite `matrix`.
+    ///
+    /// The factorization stops, and the remaining entries of `D` are set to zero, as soon as the
+    /// largest remaining pivot is smaller than or equal to `tol`. Only the lower-triangular part
+    /// (including the diagonal) of `matrix` is read.
+    pub fn new_with_tolerance(mut matrix: OMatrix<T, D, D>, tol: T) -> Self {
+        assert!(matrix.is_square(), "The input matrix must be square.");
+
+        let dim = matrix.shape_generic().0;
+        let n = dim.value();
+
+        let mut d = OVector::zeros_generic(dim, Const::<1>);
+        let mut p = PermutationSequence::identity_generic(dim);
+        let mut rank = n;
+
+        // The pivoting swaps rows and columns so we need both triangles.
+        matrix.fill_upper_triangle_with_lower_triangle();
+
+        for k in 0..n {
+            let mut piv = k;
+            let mut pivot = matrix[(k, k)].clone();
+
+            for i in k + 1..n {
+                if matrix[(i, i)] > pivot {
+                    piv = i;
+                    pivot = matrix[(i, i)].clone();
+                }
+            }
+
+            if pivot <= tol {
+                // The remaining Schur complement is negligible.
+                rank = k;
+                break;
+            }
+
+            if piv != k {
+                p.append_permutation(k, piv);
+                matrix.swap_rows(k, piv);
+                matrix.swap_columns(k, piv);
+            }
+
+            let mut col = matrix.slice_range_mut(k + 1.., k);
+            col /= pivot.clone();
+
+            for j in k + 1..n {
+                let factor = -matrix[(j, k)].clone() * pivot.clone();
+                let (mut col_j, col_k) = matrix.columns_range_pair_mut(j, k);
+                col_j
+                    .rows_range_mut(k + 1..)
+                    .axpy(factor, &col_k.rows_range(k + 1..), T::one());
+            }
+
+            d[k] = pivot;
+        }
+
+        matrix
+            .slice_range_mut(rank.., rank..)
+            .fill_lower_triangle(T::zero(), 1);
+
+        Self {
+            ldl: matrix,
+            d,
+            p,
+            rank,
+        }
+    }
+
+    /// The lower-triangular matrix `L` of this decomposition, with a diagonal filled with `1`.
+    #[inline]
+    #[must_use]
+    pub fn l(&self) -> OMatrix<T, D, D> {
+        let mut m = self.ldl.lower_triangle();
+        m.fill_diagonal(T::one());
+        m
+    }
+
+    /// The diagonal elements of the matrix `D` of this decomposition.
+    ///
+    /// They are sorted in decreasing order and only the first `self.rank()` of them are non-zero.
+    #[inline]
+    #[must_use]
+    pub fn d(&self) -> &OVector<T, D> {
+        &self.d
+    }
+
+    /// The diagonal matrix `D` of this decomposition.
+    #[inline]
+    #[must_use]
+    pub fn d_matrix(&self) -> OMatrix<T, D, D> {
+        OMatrix::from_diagonal(&self.d)
+    }
+
+    /// The symmetric permutation `P` of this decomposition.
+    #[inline]
+    #[must_use]
+    pub fn p(&self) -> &PermutationSequence<D> {
+        &self.p
+    }
+
+    /// The numerical rank of the decomposed matrix.
+    #[inline]
+    #[must_use]
+    pub fn rank(&self) -> usize {
+        self.rank
+    }
+
+    /// The factors, permutation and rank of this decomposition: `(L, diag(D), P, rank)`.
+    #[inline]
+    pub fn unpack(
+        self,
+    ) -> (
+        OMatrix<T, D, D>,
+        OVector<T, D>,
+        PermutationSequence<D>,
+        usize,
+    ) {
+        (self.l(), self.d, self.p, self.rank)
+    }
+
+    /// Solves the linear system `self * x = b`, where `x` is the unknown to be determined.
+    ///
+    /// If `b` lies in the range space of the decomposed matrix, the result satisfies
+    /// `self * x = b`. Otherwise, only the `self.rank()` equations selected as pivots by the
+    /// decomposition are satisfied. In both cases, the components of the solution along the
+    /// non-pivot directions are set to zero.
+    #[must_use = "Did you mean to use solve_mut()?"]
+    pub fn solve<R2: Dim, C2: Dim, S2>(&self, b: &Matrix<T, R2, C2, S2>) -> OMatrix<T, R2, C2>
+    where
+        S2: Storage<T, R2, C2>,
+        ShapeConstraint: SameNumberOfRows<R2, D>,
+        DefaultAllocator: Allocator<T, R2, C2>,
+    {
+        let mut res = b.clone_owned();
+        self.solve_mut(&mut res);
+        res
+    }
+
+    /// Solves in-place the linear system `self * x = b`, where `x` is the unknown to be
+    /// determined.
+    ///
+    /// See [`Self::solve`] for the behavior when `b` does not lie in the range space of the
+    /// decomposed matrix.
+    pub fn solve_mut<R2: Dim, C2: Dim, S2>(&self, b: &mut Matrix<T, R2, C2, S2>)
+    where
+        S2: StorageMut<T, R2, C2>,
+        ShapeConstraint: SameNumberOfRows<R2, D>,
+    {
+        assert_eq!(
+            self.ldl.nrows(),
+            b.nrows(),
+            "PivotedLDL solve matrix dimension mismatch."
+        );
+
+        let n = self.ldl.nrows();
+
+        self.p.permute_rows(b);
+
+        for k in 0..b.ncols() {
+            let mut bcol = b.column_mut(k);
+
+            // Solve `L * y = b`.
+            for i in 0..n {
+                let coeff = bcol[i].clone();
+                bcol.rows_range_mut(i + 1..).axpy(
+                    -coeff,
+                    &self.ldl.slice_range(i + 1.., i),
+                    T::one(),
+                );
+            }
+
+            // Apply the pseudo-inverse of `D`.
+            for i in 0..n {
+                if i < self.rank {
+                    bcol[i] /= self.d[i].clone();
+                } else {
+                    bcol[i] = T::zero();
+                }
+            }
+
+            // Solve `Lᵀ * x = y`.
+            for i in (0..n).rev() {
+                let dot = self
+                    .ldl
+                    .slice_range(i + 1.., i)
+                    .dot(&bcol.rows_range(i + 1..));
+                bcol[i] -= dot;
+            }
+        }
+
+        self.p.inv_permute_rows(b);
+    }
+}
@@ -102,17 +102,49 @@ where
         res
     }
 
-    /// Computes the orthogonal matrix `Q` of this decomposition.
+    /// Computes the full square orthogonal matrix `Q` of this decomposition.
+    ///
+    /// If the decomposed matrix has `R` rows, this returns an `R × R` matrix: its first
+    /// `min(R, C)` columns are equal to `self.q_economy()` and the remaining columns form an
+    /// orthonormal basis of the orthogonal complement of their span. This corresponds to NumPy's
+    /// `mode='complete'`.
+    ///
+    /// Note that prior versions returned the economy `R × min(R, C)` matrix here. Use
+    /// `self.q_economy()` to get that matrix, e.g., to compute `Q * R` for a tall matrix.
     #[must_use]
-    pub fn q(&self) -> OMatrix<T, R, DimMinimum<R, C>>
+    pub fn q(&self) -> OMatrix<T, R, R>
+    where
+        DefaultAllocator: Allocator<T, R, R>,
+    {
+        let nrows = self.qr.shape_generic().0;
+        self.q_columns(nrows)
+    }
+
+    /// Computes the economy (thin) orthogonal factor `Q` of this decomposition.
+    ///
+    /// If the decomposed matrix has `R` rows and `C` columns, this returns the `R × min(R, C)`
+    /// matrix made of the first `min(R, C)` columns of `self.q()`, such that `Q * self.r()` is
+    /// the decomposed matrix. This corresponds to NumPy's `mode='reduced'` and uses less memory
+    /// than `self.q()` for tall matrices.
+    #[must_use]
+    pub fn q_economy(&self) -> OMatrix<T, R, DimMinimum<R, C>>
     where
         DefaultAllocator: Allocator<T, R, DimMinimum<R, C>>,
     {
         let (nrows, ncols) = self.qr.shape_generic();
+        self.q_columns(nrows.min(ncols))
+    }
+
+    /// Computes the first `ncols` columns of the full orthogonal matrix `Q`.
+    fn q_columns<C2: Dim>(&self, ncols: C2) -> OMatrix<T, R, C2>
+    where
+        DefaultAllocator: Allocator<T, R, C2>,
+    {
+        let nrows = self.qr.shape_generic().0;
 
         // NOTE: we could build the identity matrix and call q_mul on it.
         // Instead we don't so that we take in account the matrix sparseness.
-        let mut res = Matrix::identity_generic(nrows, nrows.min(ncols));
+        let mut res = Matrix::identity_generic(nrows, ncols);
         let dim = self.diag.len();
 
         for i in (0..dim).rev() {
@@ -127,7 +159,7 @@ where
         res
     }
 
-    /// Unpacks this decomposition into its two matrix factors.
+    /// Unpacks this decomposition into its two matrix factors: `(self.q_economy(), self.r())`.
     pub fn unpack(
         self,
     ) -> (
@@ -139,7 +171,7 @@ where
         DefaultAllocator:
             Allocator<T, R, DimMinimum<R, C>> + Reallocator<T, R, C, DimMinimum<R, C>, C>,
     {
-        (self.q(), self.unpack_r())
+        (self.q_economy(), self.unpack_r())
     }
 
     #[doc(hidden)]
@@ -152,12 +184,49 @@ where
         &self.diag
     }
 
-    /// Multiplies the provided matrix by the transpose of the `Q` matrix of this decomposition.
+    /// Multiplies in-place the provided matrix by the transpose (adjoint) of the `Q` matrix of
+    /// this decomposition.
+    ///
+    /// This applies the Householder reflections stored by this decomposition directly to `rhs`
+    /// (like LAPACK's `ormqr`) so the orthogonal factor is never formed: the result is equal to
+    /// `self.q().adjoint() * rhs`, and its first `min(R, C)` rows are equal to
+    /// `self.q_economy().adjoint() * rhs`.
+    ///
+    /// Together with a back-substitution on `R`, this gives a least-squares solver for tall
+    /// matrices.
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::{Matrix4x2, Vector2, Vector4};
+    /// // Fit the line `y = a * x + b` to four points.
+    /// let m = Matrix4x2::new(0.0, 1.0,
+    ///                        1.0, 1.0,
+    ///                        2.0, 1.0,
+    ///                        3.0, 1.0);
+    /// let y = Vector4::new(1.0, 3.2, 4.8, 7.0);
+    ///
+    /// let qr = m.qr();
+    /// let mut qty = y;
+    /// qr.q_tr_mul(&mut qty);
+    /// let qty_head = qty.fixed_rows::<2>(0).into_owned();
+    /// assert_relative_eq!(qty_head, qr.q_economy().transpose() * y, epsilon = 1.0e-12);
+    ///
+    /// let mut coeffs = qty_head;
+    /// assert!(qr.r().solve_upper_triangular_mut(&mut coeffs));
+    /// assert_relative_eq!(coeffs, Vector2::new(1.96, 1.06), epsilon = 1.0e-12);
+    /// ```
     pub fn q_tr_mul<R2: Dim, C2: Dim, S2>(&self, rhs: &mut Matrix<T, R2, C2, S2>)
     // TODO: do we need a static constraint on the number of rows of rhs?
     where
         S2: StorageMut<T, R2, C2>,
     {
+        assert_eq!(
+            self.qr.nrows(),
+            rhs.nrows(),
+            "QR q_tr_mul: dimension mismatch."
+        );
+
         let dim = self.diag.len();
 
         for i in 0..dim {
@@ -0,0 +1,137 @@
+//! Row echelon forms of matrices, computed by Gaussian elimination.
+
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::vec::Vec;
+
+use simba::scalar::ComplexField;
+
+use crate::allocator::Allocator;
+use crate::base::{DefaultAllocator, Matrix, OMatrix};
+use crate::dimension::Dim;
+use crate::storage::Storage;
+
+/// Transforms `m` into its (reduced, if `reduced` is `true`) row echelon form in-place, and
+/// returns the indices of its pivot columns.
+fn gauss_jordan<T: ComplexField, R: Dim, C: Dim>(
+    m: &mut OMatrix<T, R, C>,
+    eps: T::RealField,
+    reduced: bool,
+) -> Vec<usize>
+where
+    DefaultAllocator: Allocator<T, R, C>,
+{
+    let (nrows, ncols) = m.shape();
+    let mut pivots = Vec::new();
+
+    for j in 0..ncols {
+        let row = pivots.len();
+
+        if row == nrows {
+            break;
+        }
+
+        let piv = m.slice_range(row.., j).icamax() + row;
+
+        if m[(piv, j)].clone().modulus() <= eps {
+            // No pivot on this column: its remaining entries are considered to be zero.
+            m.slice_range_mut(row.., j).fill(T::zero());
+            continue;
+        }
+
+        m.swap_rows(row, piv);
+
+        if reduced {
+            let inv_pivot = T::one() / m[(row, j)].clone();
+
+            for k in j + 1..ncols {
+                m[(row, k)] *= inv_pivot.clone();
+            }
+
+            m[(row, j)] = T::one();
+        }
+
+        let pivot = m[(row, j)].clone();
+        let first = if reduced { 0 } else { row + 1 };
+
+        for i in (first..nrows).filter(|i| *i != row) {
+            let coeff = m[(i, j)].clone() / pivot.clone();
+
+            if !coeff.is_zero() {
+                for k in j + 1..ncols {
+                    let delta = m[(row, k)].clone() * coeff.clone();
+                    m[(i, k)] -= delta;
+                }
+            }
+
+            m[(i, j)] = T::zero();
+        }
+
+        pivots.push(j);
+    }
+
+    pivots
+}
+
+/// # Row echelon forms
+impl<T: ComplexField, R: Dim, C: Dim, S: Storage<T, R, C>> Matrix<T, R, C, S>
+where
+    DefaultAllocator: Allocator<T, R, C>,
+{
+    /// Computes a row echelon form of this matrix, using Gaussian elimination with partial
+    /// pivoting.
+    ///
+    /// Returns the row echelon form, and the indices of its pivot columns in increasing order.
+    /// The `i`-th pivot column has a non-zero entry on the `i`-th row, and zeros on all the rows
+    /// below it, so the number of pivots is the rank of this matrix. A column is considered to
+    /// have no pivot if the moduli of all its candidate entries are smaller than or equal to
+    /// `eps`; these entries are then set to zero.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::Matrix3x4;
+    /// let m = Matrix3x4::new(1.0, 2.0, 1.0, 1.0,
+    ///                        2.0, 4.0, 0.0, 6.0,
+    ///                        1.0, 2.0, -1.0, 5.0);
+    /// let (echelon, pivots) = m.row_echelon(1.0e-10);
+    ///
+    /// assert_eq!(pivots, vec![0, 2]);
+    /// assert_eq!(echelon, Matrix3x4::new(2.0, 4.0, 0.0, 6.0,
+    ///                                    0.0, 0.0, 1.0, -2.0,
+    ///                                    0.0, 0.0, 0.0, 0.0));
+    /// ```
+    #[must_use]
+    pub fn row_echelon(&self, eps: T::RealField) -> (OMatrix<T, R, C>, Vec<usize>) {
+        let mut res = self.clone_owned();
+        let pivots = gauss_jordan(&mut res, eps, false);
+        (res, pivots)
+    }
+
+    /// Computes the reduced row echelon form of this matrix, using Gauss-Jordan elimination with
+    /// partial pivoting.
+    ///
+    /// Returns the reduced row echelon form, and the indices of its pivot columns in increasing
+    /// order. The `i`-th pivot column is equal to the `i`-th column of the identity matrix, so
+    /// the number of pivots is the rank of this matrix. A column is considered to have no pivot
+    /// if the moduli of all its candidate entries are smaller than or equal to `eps`; these
+    /// entries are then set to zero.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::Matrix3x4;
+    /// let m = Matrix3x4::new(1.0, 2.0, 1.0, 1.0,
+    ///                        2.0, 4.0, 0.0, 6.0,
+    ///                        1.0, 2.0, -1.0, 5.0);
+    /// let (rref, pivots) = m.reduced_row_echelon(1.0e-10);
+    ///
+    /// assert_eq!(pivots, vec![0, 2]);
+    /// assert_eq!(rref, Matrix3x4::new(1.0, 2.0, 0.0, 3.0,
+    ///                                0.0, 0.0, 1.0, -2.0,
+    ///                                0.0, 0.0, 0.0, 0.0));
+    /// ```
+    #[must_use]
+    pub fn reduced_row_echelon(&self, eps: T::RealField) -> (OMatrix<T, R, C>, Vec<usize>) {
+        let mut res = self.clone_owned();
+        let pivots = gauss_jordan(&mut res, eps, true);
+        (res, pivots)
+    }
+}
@@ -99,10 +99,12 @@ impl<T: ComplexField, D: Dim, S: Storage<T, D, D>> SquareMatrix<T, D, S> {
         true
     }
 
-    // TODO: add the same but for solving upper-triangular.
     /// Solves the linear system `self . x = b` where `x` is the unknown and only the
     /// lower-triangular part of `self` is considered not-zero. The diagonal is never read as it is
     /// assumed to be equal to `diag`. Returns `false` and does not modify its inputs if `diag` is zero.
+    ///
+    /// With `diag = T::one()`, this solves systems with a unit lower-triangular matrix, like the
+    /// `L` factor of an LU decomposition.
     pub fn solve_lower_triangular_with_diag_mut<R2: Dim, C2: Dim, S2>(
         &self,
         b: &mut Matrix<T, R2, C2, S2>,
@@ -122,8 +124,14 @@ impl<T: ComplexField, D: Dim, S: Storage<T, D, D>> SquareMatrix<T, D, S> {
         for k in 0..cols {
             let mut bcol = b.column_mut(k);
 
-            for i in 0..dim - 1 {
-                let coeff = unsafe { bcol.vget_unchecked(i).clone() } / diag.clone();
+            for i in 0..dim {
+                let coeff;
+
+                unsafe {
+                    coeff = bcol.vget_unchecked(i).clone() / diag.clone();
+                    *bcol.vget_unchecked_mut(i) = coeff.clone();
+                }
+
                 bcol.rows_range_mut(i + 1..)
                     .axpy(-coeff, &self.slice_range(i + 1.., i), T::one());
             }
@@ -132,6 +140,47 @@ impl<T: ComplexField, D: Dim, S: Storage<T, D, D>> SquareMatrix<T, D, S> {
         true
     }
 
+    /// Computes the solution of the linear system `self . x = b` where `x` is the unknown and only
+    /// the lower-triangular part of `self` is considered not-zero. The diagonal is never read as
+    /// it is assumed to be equal to `diag`. Returns `None` if `diag` is zero.
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::{Matrix3, Vector3};
+    /// // Only the strictly lower-triangular part of `m` is read.
+    /// let m = Matrix3::new(0.0, 9.0, 9.0,
+    ///                      2.0, 0.0, 9.0,
+    ///                      3.0, 4.0, 0.0);
+    /// let b = Vector3::new(1.0, 4.0, 13.0);
+    /// let x = m.solve_lower_triangular_with_diag(&b, 1.0).unwrap();
+    ///
+    /// let l = Matrix3::new(1.0, 0.0, 0.0,
+    ///                      2.0, 1.0, 0.0,
+    ///                      3.0, 4.0, 1.0);
+    /// assert_relative_eq!(l * x, b, epsilon = 1.0e-10);
+    /// assert!(m.solve_lower_triangular_with_diag(&b, 0.0).is_none());
+    /// ```
+    #[must_use = "Did you mean to use solve_lower_triangular_with_diag_mut()?"]
+    #[inline]
+    pub fn solve_lower_triangular_with_diag<R2: Dim, C2: Dim, S2>(
+        &self,
+        b: &Matrix<T, R2, C2, S2>,
+        diag: T,
+    ) -> Option<OMatrix<T, R2, C2>>
+    where
+        S2: Storage<T, R2, C2>,
+        DefaultAllocator: Allocator<T, R2, C2>,
+        ShapeConstraint: SameNumberOfRows<R2, D>,
+    {
+        let mut res = b.clone_owned();
+        if self.solve_lower_triangular_with_diag_mut(&mut res, diag) {
+            Some(res)
+        } else {
+            None
+        }
+    }
+
     /// Solves the linear system `self . x = b` where `x` is the unknown and only the
     /// upper-triangular part of `self` (including the diagonal) is considered not-zero.
     pub fn solve_upper_triangular_mut<R2: Dim, C2: Dim, S2>(
@@ -181,6 +230,86 @@ impl<T: ComplexField, D: Dim, S: Storage<T, D, D>> SquareMatrix<T, D, S> {
         true
     }
 
+    /// Solves the linear system `self . x = b` where `x` is the unknown and only the
+    /// upper-triangular part of `self` is considered not-zero. The diagonal is never read as it is
+    /// assumed to be equal to `diag`. Returns `false` and does not modify its inputs if `diag` is zero.
+    ///
+    /// With `diag = T::one()`, this solves systems with a unit upper-triangular matrix.
+    pub fn solve_upper_triangular_with_diag_mut<R2: Dim, C2: Dim, S2>(
+        &self,
+        b: &mut Matrix<T, R2, C2, S2>,
+        diag: T,
+    ) -> bool
+    where
+        S2: StorageMut<T, R2, C2>,
+        ShapeConstraint: SameNumberOfRows<R2, D>,
+    {
+        if diag.is_zero() {
+            return false;
+        }
+
+        let dim = self.nrows();
+        let cols = b.ncols();
+
+        for k in 0..cols {
+            let mut bcol = b.column_mut(k);
+
+            for i in (0..dim).rev() {
+                let coeff;
+
+                unsafe {
+                    coeff = bcol.vget_unchecked(i).clone() / diag.clone();
+                    *bcol.vget_unchecked_mut(i) = coeff.clone();
+                }
+
+                bcol.rows_range_mut(..i)
+                    .axpy(-coeff, &self.slice_range(..i, i), T::one());
+            }
+        }
+
+        true
+    }
+
+    /// Computes the solution of the linear system `self . x = b` where `x` is the unknown and only
+    /// the upper-triangular part of `self` is considered not-zero. The diagonal is never read as
+    /// it is assumed to be equal to `diag`. Returns `None` if `diag` is zero.
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::{Matrix3, Vector3};
+    /// // Only the strictly upper-triangular part of `m` is read.
+    /// let m = Matrix3::new(9.0, 2.0, 3.0,
+    ///                      9.0, 9.0, 4.0,
+    ///                      9.0, 9.0, 9.0);
+    /// let b = Vector3::new(4.0, 2.0, 1.0);
+    /// let x = m.solve_upper_triangular_with_diag(&b, 2.0).unwrap();
+    ///
+    /// let u = Matrix3::new(2.0, 2.0, 3.0,
+    ///                      0.0, 2.0, 4.0,
+    ///                      0.0, 0.0, 2.0);
+    /// assert_relative_eq!(u * x, b, epsilon = 1.0e-10);
+    /// ```
+    #[must_use = "Did you mean to use solve_upper_triangular_with_diag_mut()?"]
+    #[inline]
+    pub fn solve_upper_triangular_with_diag<R2: Dim, C2: Dim, S2>(
+        &self,
+        b: &Matrix<T, R2, C2, S2>,
+        diag: T,
+    ) -> Option<OMatrix<T, R2, C2>>
+    where
+        S2: Storage<T, R2, C2>,
+        DefaultAllocator: Allocator<T, R2, C2>,
+        ShapeConstraint: SameNumberOfRows<R2, D>,
+    {
+        let mut res = b.clone_owned();
+        if self.solve_upper_triangular_with_diag_mut(&mut res, diag) {
+            Some(res)
+        } else {
+            None
+        }
+    }
+
     /*
      *
      * Transpose and adjoint versions
@@ -518,10 +647,9 @@ impl<T: SimdComplexField, D: Dim, S: Storage<T, D, D>> SquareMatrix<T, D, S> {
         }
     }
 
-    // TODO: add the same but for solving upper-triangular.
     /// Solves the linear system `self . x = b` where `x` is the unknown and only the
     /// lower-triangular part of `self` is considered not-zero. The diagonal is never read as it is
-    /// assumed to be equal to `diag`. Returns `false` and does not modify its inputs if `diag` is zero.
+    /// assumed to be equal to `diag`, which must not be zero.
     pub fn solve_lower_triangular_with_diag_unchecked_mut<R2: Dim, C2: Dim, S2>(
         &self,
         b: &mut Matrix<T, R2, C2, S2>,
@@ -536,8 +664,14 @@ impl<T: SimdComplexField, D: Dim, S: Storage<T, D, D>> SquareMatrix<T, D, S> {
         for k in 0..cols {
             let mut bcol = b.column_mut(k);
 
-            for i in 0..dim - 1 {
-                let coeff = unsafe { bcol.vget_unchecked(i).clone() } / diag.clone();
+            for i in 0..dim {
+                let coeff;
+
+                unsafe {
+                    coeff = bcol.vget_unchecked(i).clone() / diag.clone();
+                    *bcol.vget_unchecked_mut(i) = coeff.clone();
+                }
+
                 bcol.rows_range_mut(i + 1..)
                     .axpy(-coeff, &self.slice_range(i + 1.., i), T::one());
             }
@@ -579,6 +713,37 @@ impl<T: SimdComplexField, D: Dim, S: Storage<T, D, D>> SquareMatrix<T, D, S> {
         }
     }
 
+    /// Solves the linear system `self . x = b` where `x` is the unknown and only the
+    /// upper-triangular part of `self` is considered not-zero. The diagonal is never read as it is
+    /// assumed to be equal to `diag`, which must not be zero.
+    pub fn solve_upper_triangular_with_diag_unchecked_mut<R2: Dim, C2: Dim, S2>(
+        &self,
+        b: &mut Matrix<T, R2, C2, S2>,
+        diag: T,
+    ) where
+        S2: StorageMut<T, R2, C2>,
+        ShapeConstraint: SameNumberOfRows<R2, D>,
+    {
+        let dim = self.nrows();
+        let cols = b.ncols();
+
+        for k in 0..cols {
+            let mut bcol = b.column_mut(k);
+
+            for i in (0..dim).rev() {
+                let coeff;
+
+                unsafe {
+                    coeff = bcol.vget_unchecked(i).clone() / diag.clone();
+                    *bcol.vget_unchecked_mut(i) = coeff.clone();
+                }
+
+                bcol.rows_range_mut(..i)
+                    .axpy(-coeff, &self.slice_range(..i, i), T::one());
+            }
+        }
+    }
+
     /*
      *
      * Transpose and adjoint versions
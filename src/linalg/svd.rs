@@ -18,6 +18,29 @@ use crate::linalg::symmetric_eigen;
 use crate::linalg::Bidiagonal;
 
 /// Singular Value Decomposition of a general matrix.
+///
+/// The decomposed matrix may be real or complex. In both cases, the singular values are real,
+/// non-negative, and the matrix is recomposed as `U * Σ * Vᴴ`, where `Vᴴ` (stored in `v_t`) is the
+/// adjoint (the transpose for real matrices) of the right-singular vectors.
+///
+/// # Example
+///
+/// ```
+/// # #[macro_use] extern crate approx;
+/// # use nalgebra::{Complex, Matrix2, Matrix3x2};
+/// let m = Matrix3x2::new(
+///     Complex::new(1.0, 2.0), Complex::new(0.0, -1.0),
+///     Complex::new(3.0, 0.0), Complex::new(1.0, 1.0),
+///     Complex::new(0.0, 0.5), Complex::new(-2.0, 0.0),
+/// );
+/// let svd = m.svd(true, true);
+/// let (u, v_t) = (svd.u.unwrap(), svd.v_t.unwrap());
+/// let sigma = Matrix2::from_diagonal(&svd.singular_values.map(Complex::from));
+///
+/// assert!(svd.singular_values.iter().all(|s| *s >= 0.0));
+/// assert_relative_eq!(u.adjoint() * u, Matrix2::identity(), epsilon = 1.0e-10);
+/// assert_relative_eq!(u * sigma * v_t, m, epsilon = 1.0e-10);
+/// ```
 #[cfg_attr(feature = "serde-serialize-no-std", derive(Serialize, Deserialize))]
 #[cfg_attr(
     feature = "serde-serialize-no-std",
@@ -600,7 +623,9 @@ where
 
     /// Solves the system `self * x = b` where `self` is the decomposed matrix and `x` the unknown.
     ///
-    /// Any singular value smaller than `eps` is assumed to be zero.
+    /// The result is `pinv * b` where `pinv` is the pseudo-inverse of the decomposed matrix, i.e.,
+    /// the least-squares solution of minimal norm. Any singular value smaller than `eps` is
+    /// assumed to be zero.
     /// Returns `Err` if the singular vectors `U` and `V` have not been computed.
     // TODO: make this more generic wrt the storage types and the dimensions for `b`.
     pub fn solve<R2: Dim, C2: Dim, S2>(
@@ -813,13 +838,51 @@ where
 
     /// Computes the pseudo-inverse of this matrix.
     ///
-    /// All singular values below `eps` are considered equal to 0.
+    /// All singular values below `eps` are considered equal to 0. If only the product of the
+    /// pseudo-inverse with a matrix is needed, consider using `.solve_min_norm(b, eps)` instead.
     pub fn pseudo_inverse(self, eps: T::RealField) -> Result<OMatrix<T, C, R>, &'static str>
     where
         DefaultAllocator: Allocator<T, C, R>,
     {
         SVD::new_unordered(self.clone_owned(), true, true).pseudo_inverse(eps)
     }
+
+    /// Computes the solution of minimal norm of the system `self * x = b`.
+    ///
+    /// If the system has solutions, e.g., if it is underdetermined with a full-rank `self`, this
+    /// returns the one with the smallest 2-norm. Otherwise, this returns the least-squares
+    /// solution of minimal norm, i.e., the `x` of minimal norm among those minimizing the norm of
+    /// `self * x - b`. This is equal to `self.pseudo_inverse(eps)? * b` but does not form the
+    /// pseudo-inverse explicitly.
+    ///
+    /// All singular values below `eps` are considered equal to 0.
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::{Matrix2x3, Vector2, Vector3};
+    /// // Two equations, three unknowns.
+    /// let a = Matrix2x3::new(1.0, 1.0, 0.0,
+    ///                        0.0, 1.0, 1.0);
+    /// let b = Vector2::new(1.0, 1.0);
+    /// let x = a.solve_min_norm(&b, 1.0e-10).unwrap();
+    ///
+    /// assert_relative_eq!(a * x, b, epsilon = 1.0e-10);
+    /// // `(1, 0, 1)` is also a solution, but it has a larger norm than `x`.
+    /// assert_relative_eq!(x, Vector3::new(1.0, 2.0, 1.0) / 3.0, epsilon = 1.0e-10);
+    /// ```
+    pub fn solve_min_norm<R2: Dim, C2: Dim, S2>(
+        &self,
+        b: &Matrix<T, R2, C2, S2>,
+        eps: T::RealField,
+    ) -> Result<OMatrix<T, C, C2>, &'static str>
+    where
+        S2: Storage<T, R2, C2>,
+        DefaultAllocator: Allocator<T, C, C2> + Allocator<T, DimMinimum<R, C>, C2>,
+        ShapeConstraint: SameNumberOfRows<R, R2>,
+    {
+        SVD::new_unordered(self.clone_owned(), true, true).solve(b, eps)
+    }
 }
 
 impl<T: ComplexField, R: DimMin<C>, C: Dim, S: Storage<T, R, C>> Matrix<T, R, C, S>
@@ -295,6 +295,36 @@ where
         u_t.adjoint_mut();
         &self.eigenvectors * u_t
     }
+
+    /// Sorts the eigenvalues of this decomposition, and permutes the columns of the eigenvectors
+    /// matrix accordingly.
+    ///
+    /// The eigenvalues are sorted in ascending order if `ascending` is `true`, and in descending
+    /// order otherwise.
+    #[must_use = "This function does not mutate self but returns the sorted decomposition."]
+    pub fn sorted(mut self, ascending: bool) -> Self {
+        let n = self.eigenvalues.len();
+
+        for i in 0..n {
+            let mut best = i;
+
+            for j in i + 1..n {
+                let val = &self.eigenvalues[j];
+                let best_val = &self.eigenvalues[best];
+
+                if (ascending && val < best_val) || (!ascending && val > best_val) {
+                    best = j;
+                }
+            }
+
+            if best != i {
+                self.eigenvalues.swap_rows(i, best);
+                self.eigenvectors.swap_columns(i, best);
+            }
+        }
+
+        self
+    }
 }
 
 /// Computes the wilkinson shift, i.e., the 2x2 symmetric matrix eigenvalue to its tailing
@@ -18,6 +18,10 @@
 //!
 //! - Using the [matrix](fn.matrix.html) function to generate matrices with constraints
 //!   on dimensions and elements.
+//! - Using the [invertible_matrix](fn.invertible_matrix.html) function to generate square
+//!   matrices that are guaranteed to be well-conditioned.
+//! - Using the [positive_definite](fn.positive_definite.html) function to generate symmetric
+//!   positive-definite matrices.
 //! - Relying on the `Arbitrary` implementation of `OMatrix`.
 //!
 //! The first variant is almost always preferred in practice. Read on to discover why.
@@ -141,7 +145,9 @@
 //! PROPTEST_MAX_SHRINK_ITERS=100000 cargo test my_failing_test
 //! ```
 use crate::allocator::Allocator;
-use crate::{Const, DefaultAllocator, Dim, DimName, Dynamic, OMatrix, Scalar, U1};
+use crate::{
+    Const, DefaultAllocator, Dim, DimName, Dynamic, OMatrix, OVector, RealField, Scalar, U1,
+};
 use proptest::arbitrary::Arbitrary;
 use proptest::collection::vec;
 use proptest::strategy::{BoxedStrategy, Just, NewTree, Strategy, ValueTree};
@@ -332,6 +338,171 @@ where
     matrix_(value_strategy, length.into(), Const::<1>.into())
 }
 
+/// Create a strategy to generate square invertible matrices with a bounded condition number.
+///
+/// Each matrix is generated as `U * Σ * Vᵀ`, where `U` and `V` are random rotations and the
+/// diagonal of `Σ` holds singular values drawn from `singular_values`. The (2-norm) condition
+/// number of every generated matrix is therefore at most
+/// `singular_values.end() / singular_values.start()`, which makes these matrices suitable for
+/// testing solvers and inverses without spurious failures caused by near-singular inputs.
+///
+/// When a failing case is shrunk, the rotations shrink towards the identity and the singular
+/// values shrink towards `1.0` (or the closest bound of `singular_values`), so that the matrices
+/// shrink towards the identity whenever `singular_values` contains `1.0`.
+///
+/// ## Examples
+/// ```
+/// use nalgebra::proptest::invertible_matrix;
+/// use nalgebra::DMatrix;
+/// use proptest::prelude::*;
+///
+/// proptest! {
+///     # /*
+///     #[test]
+///     # */
+///     fn my_test(a in invertible_matrix(1 ..= 5, 0.5 ..= 2.0)) {
+///         // The condition number of `a` is at most 4.
+///         let a: DMatrix<f64> = a;
+///         prop_assert!(a.clone().try_inverse().is_some());
+///     }
+/// }
+///
+/// # fn main() { my_test(); }
+/// ```
+///
+/// ## Panics
+/// Panics if `singular_values` is empty or if its lower bound is not strictly positive.
+pub fn invertible_matrix<T, D>(
+    dim: impl Into<DimRange<D>>,
+    singular_values: RangeInclusive<f64>,
+) -> impl Strategy<Value = OMatrix<T, D, D>>
+where
+    T: RealField,
+    D: Dim,
+    DefaultAllocator: Allocator<T, D, D> + Allocator<T, D>,
+{
+    assert!(
+        *singular_values.start() > 0.0 && singular_values.start() <= singular_values.end(),
+        "The range of singular values must be non-empty and strictly positive."
+    );
+
+    // Singular values are sampled in log-space so that they shrink towards 1.
+    let log_singular_values = singular_values.start().ln()..=singular_values.end().ln();
+    let angles = -std::f64::consts::PI..=std::f64::consts::PI;
+
+    dim.into()
+        .to_range_inclusive()
+        .prop_flat_map(move |n| {
+            let nangles = n * n.saturating_sub(1) / 2;
+            (
+                Just(n),
+                vec(angles.clone(), nangles),
+                vec(angles.clone(), nangles),
+                vec(log_singular_values.clone(), n),
+            )
+        })
+        .prop_map(|(n, u_angles, v_angles, log_singular_values)| {
+            let dim = D::from_usize(n);
+            let mut u: OMatrix<T, D, D> = rotation_from_angles(dim, &u_angles);
+            let v: OMatrix<T, D, D> = rotation_from_angles(dim, &v_angles);
+            let singular_values = OVector::<T, D>::from_iterator_generic(
+                dim,
+                Const::<1>,
+                log_singular_values.iter().map(|s| crate::convert(s.exp())),
+            );
+
+            for (mut col, s) in u.column_iter_mut().zip(singular_values.iter()) {
+                col *= s.clone();
+            }
+
+            u * v.transpose()
+        })
+}
+
+/// Create a strategy to generate symmetric positive-definite matrices.
+///
+/// Each matrix is generated as `L * Lᵀ + I`, where `L` is a random lower-triangular matrix
+/// whose strictly lower-triangular entries are drawn from `value_range`, and whose diagonal
+/// entries are the absolute values of numbers drawn from `value_range`. The eigenvalues of every
+/// generated matrix are therefore at least `1.0`, and at most `1.0 + n * (n + 1) / 2 * r²` where
+/// `n` is the dimension and `r` the largest absolute value of `value_range`. Narrower ranges
+/// thus yield better-conditioned matrices.
+///
+/// When a failing case is shrunk, the entries of `L` shrink towards `0.0` (or the closest bound
+/// of `value_range`), so that the matrices shrink towards the identity whenever `value_range`
+/// contains `0.0`.
+///
+/// ## Examples
+/// ```
+/// use nalgebra::proptest::positive_definite;
+/// use nalgebra::DMatrix;
+/// use proptest::prelude::*;
+///
+/// proptest! {
+///     # /*
+///     #[test]
+///     # */
+///     fn my_test(a in positive_definite(1 ..= 5, -1.0 ..= 1.0)) {
+///         let a: DMatrix<f64> = a;
+///         prop_assert!(a.clone().cholesky().is_some());
+///     }
+/// }
+///
+/// # fn main() { my_test(); }
+/// ```
+pub fn positive_definite<T, D>(
+    dim: impl Into<DimRange<D>>,
+    value_range: RangeInclusive<f64>,
+) -> impl Strategy<Value = OMatrix<T, D, D>>
+where
+    T: RealField,
+    D: Dim,
+    DefaultAllocator: Allocator<T, D, D>,
+{
+    dim.into()
+        .to_range_inclusive()
+        .prop_flat_map(move |n| (Just(n), vec(value_range.clone(), n * (n + 1) / 2)))
+        .prop_map(|(n, values)| {
+            let dim = D::from_usize(n);
+            let mut l = OMatrix::<T, D, D>::zeros_generic(dim, dim);
+            let lower = (0..n).flat_map(|j| (j..n).map(move |i| (i, j)));
+
+            for ((i, j), value) in lower.zip(values) {
+                let value = if i == j { value.abs() } else { value };
+                l[(i, j)] = crate::convert(value);
+            }
+
+            &l * l.transpose() + OMatrix::identity_generic(dim, dim)
+        })
+}
+
+/// Computes the product of the Givens rotations acting on each pair of axes `(i, j)` (with
+/// `i < j`), using the given rotation angles in order.
+fn rotation_from_angles<T, D>(dim: D, angles: &[f64]) -> OMatrix<T, D, D>
+where
+    T: RealField,
+    D: Dim,
+    DefaultAllocator: Allocator<T, D, D>,
+{
+    let n = dim.value();
+    let mut rot = OMatrix::identity_generic(dim, dim);
+    let axes = (0..n).flat_map(|i| (i + 1..n).map(move |j| (i, j)));
+
+    for ((i, j), angle) in axes.zip(angles) {
+        let (s, c) = angle.sin_cos();
+        let (s, c): (T, T) = (crate::convert(s), crate::convert(c));
+
+        for k in 0..n {
+            let a = rot[(i, k)].clone();
+            let b = rot[(j, k)].clone();
+            rot[(i, k)] = c.clone() * a.clone() - s.clone() * b.clone();
+            rot[(j, k)] = s.clone() * a + c.clone() * b;
+        }
+    }
+
+    rot
+}
+
 impl<NParameters, R, C> Default for MatrixParameters<NParameters, R, C>
 where
     NParameters: Default,
@@ -91,6 +91,38 @@ mod blas_proptest {
             prop_assert!(relative_eq!(a1.lower_triangle(), a2))
         }
 
+        #[test]
+        fn ger(m in PROPTEST_MATRIX_DIM, n in PROPTEST_MATRIX_DIM, alpha in PROPTEST_F64) {
+            let a = DMatrix::<f64>::new_random(m, n);
+            let x = DVector::new_random(m);
+            let y = DVector::new_random(n);
+
+            let expected = &a + &x * y.transpose() * alpha;
+            let mut res = a.clone();
+            res.ger(alpha, &x, &y, 1.0);
+
+            prop_assert!(relative_eq!(res, expected, epsilon = 1.0e-7));
+            prop_assert!(relative_eq!(x.outer_product(&y), &x * y.transpose()))
+        }
+
+        #[test]
+        fn syrk(n in PROPTEST_MATRIX_DIM, k in PROPTEST_MATRIX_DIM, alpha in PROPTEST_F64, beta in PROPTEST_F64) {
+            let a = DMatrix::<f64>::new_random(n, n);
+            let mut a1 = &a * a.transpose();
+            let mut a2 = a1.lower_triangle();
+            let x = DMatrix::new_random(n, k);
+
+            a1.gemm(alpha, &x, &x.transpose(), beta);
+            a2.syrk(alpha, &x, beta);
+
+            prop_assert!(relative_eq!(a1.lower_triangle(), a2, epsilon = 1.0e-7));
+
+            a1.gemm(alpha, &x, &x.transpose(), 0.0);
+            a2.syrk(alpha, &x, 0.0);
+
+            prop_assert!(relative_eq!(a1.lower_triangle(), a2, epsilon = 1.0e-7))
+        }
+
         #[test]
         fn quadform(n in PROPTEST_MATRIX_DIM, alpha in PROPTEST_F64, beta in PROPTEST_F64) {
             let rhs     = DMatrix::<f64>::new_random(6, n);
@@ -0,0 +1,36 @@
+use na::io::CsvParseError;
+use na::DMatrix;
+
+#[test]
+fn from_csv() {
+    let data = "a; b\n\n 1.5 ; -2\n3;4e2\n\n5 ;6\n";
+    let m = DMatrix::<f64>::from_csv(data.as_bytes(), ';', true).unwrap();
+    let expected = DMatrix::from_row_slice(3, 2, &[1.5, -2.0, 3.0, 400.0, 5.0, 6.0]);
+    assert_eq!(m, expected);
+
+    let m = DMatrix::<i32>::from_csv("1\t2\t3\n".as_bytes(), '\t', false).unwrap();
+    assert_eq!(m, DMatrix::from_row_slice(1, 3, &[1, 2, 3]));
+
+    let m = DMatrix::<f64>::from_csv("x,y\n".as_bytes(), ',', true).unwrap();
+    assert_eq!(m.shape(), (0, 0));
+}
+
+#[test]
+fn from_csv_errors() {
+    match DMatrix::<f64>::from_csv("1,2,3\n4,5\n".as_bytes(), ',', false) {
+        Err(CsvParseError::RaggedRow {
+            line: 2,
+            expected: 3,
+            found: 2,
+        }) => {}
+        res => panic!("unexpected result: {:?}", res),
+    }
+
+    match DMatrix::<f64>::from_csv("x,y\n1,2\n\n3,abc\n".as_bytes(), ',', true) {
+        Err(CsvParseError::InvalidField { line: 4, column: 2 }) => {}
+        res => panic!("unexpected result: {:?}", res),
+    }
+
+    // Without skipping the header, it is parsed as an invalid row.
+    assert!(DMatrix::<f64>::from_csv("x,y\n1,2\n".as_bytes(), ',', false).is_err());
+}
@@ -1,8 +1,8 @@
+use na::{Const, Dynamic, U3, U5};
 use na::{
-    DMatrix, Matrix, Matrix3, Matrix3x4, Matrix3x5, Matrix4, Matrix4x3, Matrix4x5, Matrix5,
-    Matrix5x3, Matrix5x4,
+    DMatrix, DVector, Matrix, Matrix3, Matrix3x4, Matrix3x5, Matrix4, Matrix4x3, Matrix4x5,
+    Matrix5, Matrix5x3, Matrix5x4, Vector1, Vector2, Vector3,
 };
-use na::{Dynamic, U3, U5};
 
 #[test]
 #[rustfmt::skip]
@@ -170,6 +170,86 @@ fn upper_lower_triangular() {
     assert_eq!(m, expected_m);
 }
 
+#[test]
+#[rustfmt::skip]
+fn set_offset_diagonal() {
+    let mut m = Matrix3x5::zeros();
+
+    m.set_diagonal(&Vector3::new(11.0, 22.0, 33.0));
+    m.set_offset_diagonal(2, &Vector3::new(13.0, 24.0, 35.0));
+    m.set_offset_diagonal(-1, &Vector2::new(21.0, 32.0));
+    m.set_offset_diagonal(-2, &Vector1::new(31.0));
+
+    let expected = Matrix3x5::new(
+        11.0,  0.0, 13.0,  0.0,  0.0,
+        21.0, 22.0,  0.0, 24.0,  0.0,
+        31.0, 32.0, 33.0,  0.0, 35.0);
+
+    assert_eq!(m, expected);
+
+    // Diagonals past the last column or row are empty.
+    m.set_offset_diagonal(5, &DVector::zeros(0));
+    m.set_offset_diagonal(-7, &DVector::zeros(0));
+    assert_eq!(m, expected);
+}
+
+#[test]
+#[should_panic]
+fn set_offset_diagonal_wrong_length() {
+    let mut m = Matrix3x5::<f64>::zeros();
+    m.set_offset_diagonal(3, &Vector3::zeros());
+}
+
+#[test]
+fn triangles_with_offset() {
+    for &(nrows, ncols) in &[(3, 5), (5, 3), (4, 4)] {
+        let m = DMatrix::from_fn(nrows, ncols, |i, j| (10 * (i + 1) + j + 1) as f64);
+
+        for offset in -6..=6 {
+            let upper = m.upper_triangle_with_offset(offset);
+            let lower = m.lower_triangle_with_offset(offset);
+
+            for i in 0..nrows {
+                for j in 0..ncols {
+                    let diag = j as isize - i as isize;
+                    let expected_upper = if diag >= offset { m[(i, j)] } else { 0.0 };
+                    let expected_lower = if diag <= offset { m[(i, j)] } else { 0.0 };
+
+                    assert_eq!(upper[(i, j)], expected_upper);
+                    assert_eq!(lower[(i, j)], expected_lower);
+                }
+            }
+
+            // Both parts are complementary.
+            assert_eq!(&upper + m.lower_triangle_with_offset(offset - 1), m);
+
+            // In-place equivalents, when the offset is expressible as a shift.
+            if offset <= 1 {
+                let mut filled = m.clone();
+                filled.fill_lower_triangle(0.0, (1 - offset) as usize);
+                assert_eq!(filled, upper);
+            }
+
+            if offset >= -1 {
+                let mut filled = m.clone();
+                filled.fill_upper_triangle(0.0, (offset + 1) as usize);
+                assert_eq!(filled, lower);
+            }
+        }
+    }
+}
+
+#[test]
+fn triangles_with_extreme_offsets() {
+    let m = DMatrix::from_fn(3, 4, |i, j| (10 * (i + 1) + j + 1) as f64);
+    let zeros = DMatrix::zeros(3, 4);
+
+    assert_eq!(m.upper_triangle_with_offset(isize::MIN), m);
+    assert_eq!(m.upper_triangle_with_offset(isize::MAX), zeros);
+    assert_eq!(m.lower_triangle_with_offset(isize::MIN), zeros);
+    assert_eq!(m.lower_triangle_with_offset(isize::MAX), m);
+}
+
 #[test]
 #[rustfmt::skip]
 fn swap_rows() {
@@ -700,3 +780,88 @@ fn resize_empty_matrix() {
     assert_eq!(m1, m6.resize(0, 0, 42));
     assert_eq!(m1, m7.resize(0, 0, 42));
 }
+
+#[test]
+#[rustfmt::skip]
+fn sort_columns_by() {
+    let mut m = Matrix3x5::new(
+        4.0, 1.0, 3.0, 0.0, 2.0,
+        14.0, 11.0, 13.0, 10.0, 12.0,
+        24.0, 21.0, 23.0, 20.0, 22.0);
+    let original = m;
+    let keys = [4.0, 1.0, 3.0, 0.0, 2.0];
+
+    let expected = Matrix3x5::new(
+        0.0, 1.0, 2.0, 3.0, 4.0,
+        10.0, 11.0, 12.0, 13.0, 14.0,
+        20.0, 21.0, 22.0, 23.0, 24.0);
+
+    let perm = m.sort_columns_by(|a, b| a[0].partial_cmp(&b[0]).unwrap());
+    assert_eq!(m, expected);
+    assert_eq!(perm, vec![3, 1, 4, 2, 0]);
+
+    for (i, &p) in perm.iter().enumerate() {
+        assert_eq!(m.column(i), original.column(p));
+        assert_eq!(keys[p], i as f64);
+    }
+}
+
+#[test]
+#[rustfmt::skip]
+fn sort_rows_by() {
+    let mut m = DMatrix::from_row_slice(4, 2, &[
+        2, 0,
+        1, 1,
+        2, 2,
+        0, 3]);
+
+    let expected = DMatrix::from_row_slice(4, 2, &[
+        0, 3,
+        1, 1,
+        2, 0,
+        2, 2]);
+
+    // The sort is stable: rows 0 and 2 have the same key and keep their relative order.
+    let perm = m.sort_rows_by(|a, b| a[0].cmp(&b[0]));
+    assert_eq!(m, expected);
+    assert_eq!(perm, vec![3, 1, 0, 2]);
+}
+
+#[test]
+fn reshape_column_major() {
+    let m = Matrix3x4::from_fn(|i, j| i + 3 * j);
+
+    // The components are reinterpreted in column-major order.
+    let static_reshaped: Matrix4x3<usize> = m.reshape();
+    assert_eq!(static_reshaped, Matrix4x3::from_fn(|i, j| i + 4 * j));
+    assert_eq!(static_reshaped.as_slice(), m.as_slice());
+
+    let dm = DMatrix::from_fn(3, 4, |i, j| i + 3 * j);
+    let reshaped = dm.clone().reshape_generic(Dynamic::new(2), Dynamic::new(6));
+    assert_eq!(reshaped, DMatrix::from_fn(2, 6, |i, j| i + 2 * j));
+
+    let column: DVector<usize> = dm
+        .clone()
+        .try_reshape_generic(Dynamic::new(12), Const::<1>)
+        .unwrap();
+    assert_eq!(column.as_slice(), dm.as_slice());
+
+    assert!(dm
+        .clone()
+        .try_reshape_generic(Dynamic::new(5), Dynamic::new(2))
+        .is_none());
+    assert!(dm
+        .try_reshape_generic(Const::<3>, Dynamic::new(5))
+        .is_none());
+
+    // The product of the new dimensions overflows (and would wrap to zero).
+    assert!(DMatrix::<f64>::zeros(0, 3)
+        .try_reshape_generic(Dynamic::new(usize::MAX / 2 + 1), Dynamic::new(2))
+        .is_none());
+}
+
+#[test]
+#[should_panic]
+fn reshape_generic_mismatched_dimensions() {
+    let _ = DMatrix::<f64>::zeros(3, 4).reshape_generic(Dynamic::new(5), Dynamic::new(2));
+}
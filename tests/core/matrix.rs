@@ -1,11 +1,12 @@
 use num::{One, Zero};
+use num_complex::Complex;
 use std::cmp::Ordering;
 
 use na::dimension::{U15, U8};
 use na::{
     self, Const, DMatrix, DVector, Matrix2, Matrix2x3, Matrix2x4, Matrix3, Matrix3x2, Matrix3x4,
-    Matrix4, Matrix4x3, Matrix4x5, Matrix5, Matrix6, OMatrix, RowVector3, RowVector4, RowVector5,
-    Vector1, Vector2, Vector3, Vector4, Vector5, Vector6,
+    Matrix4, Matrix4x3, Matrix4x5, Matrix5, Matrix6, OMatrix, RowDVector, RowVector3, RowVector4,
+    RowVector5, Vector1, Vector2, Vector3, Vector4, Vector5, Vector6,
 };
 
 #[test]
@@ -155,6 +156,156 @@ fn identity() {
     assert!(!not_id3.is_identity(0.0));
 }
 
+#[test]
+fn symmetric_and_skew_symmetric() {
+    let m = DMatrix::from_fn(4, 4, |i, j| (i + 1) as f64 * (j + 1) as f64);
+    let skew = DMatrix::from_fn(4, 4, |i, j| i as f64 - j as f64);
+
+    assert!(m.is_symmetric(0.0));
+    assert!(!m.is_skew_symmetric(0.0));
+    assert!(skew.is_skew_symmetric(0.0));
+    assert!(!skew.is_symmetric(0.0));
+    assert!(DMatrix::<f64>::zeros(3, 3).is_symmetric(0.0));
+    assert!(DMatrix::<f64>::zeros(3, 3).is_skew_symmetric(0.0));
+    assert!(!DMatrix::<f64>::zeros(3, 2).is_symmetric(0.0));
+    assert!(!DMatrix::<f64>::zeros(3, 2).is_skew_symmetric(0.0));
+
+    let mut almost = m.clone();
+    almost[(0, 3)] += 1.0e-10;
+    assert!(!almost.is_symmetric(0.0));
+    assert!(almost.is_symmetric(1.0e-7));
+    assert!(m.symmetric_part().is_symmetric(0.0));
+    assert!((&m - m.transpose()).is_skew_symmetric(0.0));
+}
+
+#[test]
+fn symmetric_and_skew_symmetric_parts() {
+    let m = Matrix3::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0);
+    let sym = m.symmetric_part();
+    let skew = m.skew_symmetric_part();
+
+    assert_eq!(
+        sym,
+        Matrix3::new(1.0, 3.0, 5.0, 3.0, 5.0, 7.0, 5.0, 7.0, 9.0)
+    );
+    assert_eq!(
+        skew,
+        Matrix3::new(0.0, -1.0, -2.0, 1.0, 0.0, -1.0, 2.0, 1.0, 0.0)
+    );
+    assert_eq!(sym + skew, m);
+    assert!(sym.is_symmetric(0.0));
+    assert!(skew.is_skew_symmetric(0.0));
+    assert_eq!(sym.skew_symmetric_part(), Matrix3::zeros());
+    assert_eq!(skew.symmetric_part(), Matrix3::zeros());
+}
+
+#[test]
+fn symmetrize() {
+    let mut m = DMatrix::from_fn(4, 4, |i, j| (i * 4 + j) as f64 + 0.25 * (i as f64));
+    let sym = m.symmetric_part();
+
+    m.symmetrize();
+    assert_eq!(m, sym);
+    assert!(m.is_symmetric(0.0));
+
+    // Small asymmetries due to rounding errors are removed.
+    m[(1, 2)] += 1.0e-12;
+    assert!(!m.is_symmetric(0.0));
+    m.symmetrize();
+    assert!(m.is_symmetric(0.0));
+}
+
+#[test]
+#[should_panic]
+fn symmetrize_non_square() {
+    DMatrix::<f64>::zeros(2, 3).symmetrize();
+}
+
+#[test]
+#[should_panic]
+fn skew_symmetric_part_non_square() {
+    let _ = DMatrix::<f64>::zeros(2, 3).skew_symmetric_part();
+}
+
+#[test]
+fn diagonally_dominant() {
+    #[rustfmt::skip]
+    let m = DMatrix::from_row_slice(4, 4, &[
+        10.0, -2.0,  3.0,  1.0,
+        -1.0,  8.0,  2.0, -4.0,
+         2.0,  1.0,  6.0,  2.0,
+         0.0, -3.0,  1.0,  5.0,
+    ]);
+    assert!(m.is_diagonally_dominant(true));
+    assert!(m.is_diagonally_dominant(false));
+
+    // Equality on one row: only weakly dominant.
+    let mut weak = m.clone();
+    weak[(3, 3)] = 4.0;
+    assert!(!weak.is_diagonally_dominant(true));
+    assert!(weak.is_diagonally_dominant(false));
+
+    // Negative diagonal entries only count through their modulus.
+    assert!((-&m).is_diagonally_dominant(true));
+
+    weak[(3, 3)] = 3.9;
+    assert!(!weak.is_diagonally_dominant(false));
+    assert!(!DMatrix::<f64>::identity(3, 2).is_diagonally_dominant(false));
+}
+
+#[test]
+fn is_finite_and_contains_nan() {
+    let mut m = DMatrix::<f64>::from_fn(3, 4, |i, j| i as f64 - j as f64);
+    assert!(m.is_finite());
+    assert!(!m.contains_nan());
+
+    m[(1, 2)] = f64::NEG_INFINITY;
+    assert!(!m.is_finite());
+    assert!(!m.contains_nan());
+
+    m[(2, 3)] = f64::NAN;
+    assert!(!m.is_finite());
+    assert!(m.contains_nan());
+
+    let empty = DMatrix::<f64>::zeros(0, 3);
+    assert!(empty.is_finite());
+    assert!(!empty.contains_nan());
+
+    // NaN in the imaginary part only.
+    let c = Vector2::new(
+        Complex::new(1.0, 0.0),
+        Complex::new(f64::INFINITY, f64::NAN),
+    );
+    assert!(!c.is_finite());
+    assert!(c.contains_nan());
+    assert!(!Vector2::new(Complex::new(1.0, f64::INFINITY), Complex::new(0.0, 0.0)).contains_nan());
+}
+
+#[test]
+fn spectral_radius_estimate() {
+    #[rustfmt::skip]
+    let m = DMatrix::from_row_slice(4, 4, &[
+        10.0, -2.0,  3.0,  1.0,
+        -2.0,  8.0,  2.0, -4.0,
+         3.0,  2.0,  6.0,  2.0,
+         1.0, -4.0,  2.0,  5.0,
+    ]);
+    let exact = m.clone().symmetric_eigenvalues().amax();
+    assert_relative_eq!(m.spectral_radius_estimate(500), exact, epsilon = 1.0e-7);
+
+    // The dominant eigenvalue is negative.
+    let exact = (-&m).symmetric_eigenvalues().amax();
+    assert_relative_eq!((-&m).spectral_radius_estimate(500), exact, epsilon = 1.0e-7);
+
+    // Non-symmetric matrix with real eigenvalues 5, 2 and -1.
+    let m = Matrix3::new(5.0, 1.0, 0.0, 0.0, 2.0, 1.0, 0.0, 0.0, -1.0);
+    assert_relative_eq!(m.spectral_radius_estimate(100), 5.0, epsilon = 1.0e-7);
+
+    // Nilpotent matrix.
+    let m = Matrix3::new(0.0, 1.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0);
+    assert_eq!(m.spectral_radius_estimate(10), 0.0);
+}
+
 #[test]
 fn coordinates() {
     let a = Matrix3x4::new(11, 12, 13, 14, 21, 22, 23, 24, 31, 32, 33, 34);
@@ -476,6 +627,89 @@ fn map_with_location() {
     assert_eq!(computed, expected);
 }
 
+#[test]
+fn map_with_location_dynamic_and_slice() {
+    let a = DMatrix::from_fn(3, 4, |i, j| (i * 10 + j) as f64);
+
+    let expected = DMatrix::from_fn(3, 4, |i, j| (i * 10 + j) as f64 * (i as f64 - j as f64));
+    let computed = a.map_with_location(|i, j, e| e * (i as f64 - j as f64));
+    assert_eq!(computed, expected);
+
+    // Indices passed to the closure are relative to the slice.
+    let slice = a.slice((1, 2), (2, 2));
+    let computed_slice = slice.map_with_location(|i, j, _| (i, j));
+    assert_eq!(computed_slice[(0, 0)], (0, 0));
+    assert_eq!(computed_slice[(1, 1)], (1, 1));
+    assert_eq!(computed_slice.shape(), (2, 2));
+}
+
+#[test]
+fn fold() {
+    let a = Matrix2x3::new(1, 2, 3, 4, 5, 6);
+
+    assert_eq!(a.fold(0, |acc, e| acc + e), 21);
+    assert_eq!(
+        a.fold(Vec::new(), |mut acc, e| {
+            acc.push(e);
+            acc
+        }),
+        vec![1, 4, 2, 5, 3, 6]
+    );
+    assert_eq!(DMatrix::<i32>::zeros(0, 3).fold(42, |acc, e| acc + e), 42);
+}
+
+#[test]
+fn fold_with_index() {
+    let a = Matrix2x3::new(1, 2, 3, 4, 5, 6);
+
+    let weighted = a.fold_with_index(0, |acc, i, j, e| acc + (i + 1) * (j + 1) * e);
+    assert_eq!(weighted, 1 + 2 * 4 + 2 * 2 + 4 * 5 + 3 * 3 + 6 * 6);
+
+    let visited = a.fold_with_index(Vec::new(), |mut acc, i, j, e| {
+        acc.push((i, j, e));
+        acc
+    });
+    assert_eq!(
+        visited,
+        vec![
+            (0, 0, 1),
+            (1, 0, 4),
+            (0, 1, 2),
+            (1, 1, 5),
+            (0, 2, 3),
+            (1, 2, 6)
+        ]
+    );
+}
+
+#[test]
+fn zip_fold() {
+    let a = Matrix2x3::new(1.0f64, -5.0, 3.0, 2.0, 0.0, -7.0);
+    let b = Matrix2x3::new(4.0, 2.0, 6.0, -1.0, 3.0, 7.0);
+
+    let dot = a.zip_fold(&b, 0.0, |acc, ea, eb| acc + ea * eb);
+    assert_eq!(dot, a.dot(&b));
+
+    // Running maximum of the absolute differences, together with its location (in column-major order).
+    let (_, imax, max) = a.zip_fold(&b, (0, 0, 0.0f64), |(k, imax, max), ea, eb| {
+        let diff = (ea - eb).abs();
+        if diff > max {
+            (k + 1, k, diff)
+        } else {
+            (k + 1, imax, max)
+        }
+    });
+    assert_eq!((imax, max), (5, 14.0));
+}
+
+#[test]
+#[should_panic]
+fn zip_fold_dimension_mismatch() {
+    let a = DVector::<f32>::zeros(2);
+    let b = DVector::<f32>::zeros(3);
+    let _ = a.zip_fold(&b, 0.0, |acc, ea, eb| acc + ea + eb);
+}
+
 #[test]
 fn zip_map() {
     let a = Matrix3::new(11i32, 12, 13, 21, 22, 23, 31, 32, 33);
@@ -489,6 +723,37 @@ fn zip_map() {
     assert_eq!(computed, expected);
 }
 
+#[test]
+#[should_panic]
+fn zip_map_dimension_mismatch() {
+    let a = DMatrix::<f32>::zeros(2, 3);
+    let b = DMatrix::<f32>::zeros(3, 2);
+    let _ = a.zip_map(&b, |ea, eb| ea + eb);
+}
+
+#[test]
+fn zip_zip_map() {
+    let a = Matrix2x3::new(1.0f64, 5.0, -3.0, 2.0, 0.0, 7.0);
+    let b = Matrix2x3::new(4.0, 2.0, 6.0, -1.0, 3.0, 7.0);
+    let c = Matrix2x3::new(0.0, 1.0, 0.0, 1.0, 0.0, 1.0);
+
+    let expected = Matrix2x3::new(4.0, 6.0, 6.0, 3.0, 3.0, 8.0);
+    let computed = a.zip_zip_map(&b, &c, |ea, eb, ec| ea.max(eb) + ec);
+    assert_eq!(computed, expected);
+
+    let selected = a.zip_zip_map(&b, &c, |ea, eb, ec| if ec == 0.0 { ea } else { eb });
+    assert_eq!(selected, Matrix2x3::new(1.0, 2.0, -3.0, -1.0, 0.0, 7.0));
+}
+
+#[test]
+#[should_panic]
+fn zip_zip_map_dimension_mismatch() {
+    let a = DMatrix::<f32>::zeros(2, 3);
+    let b = DMatrix::<f32>::zeros(2, 3);
+    let c = DMatrix::<f32>::zeros(2, 2);
+    let _ = a.zip_zip_map(&b, &c, |ea, eb, ec| ea + eb + ec);
+}
+
 #[test]
 #[should_panic]
 fn trace_panic() {
@@ -502,6 +767,38 @@ fn trace() {
     assert_eq!(m.trace(), 5.0);
 }
 
+#[test]
+fn diagonal_sum() {
+    let m = Matrix2x3::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0);
+    assert_eq!(m.diagonal_sum(), 6.0);
+    assert_eq!(m.transpose().diagonal_sum(), 6.0);
+    assert_eq!(Matrix3::new(1, 2, 3, 4, 5, 6, 7, 8, 9).diagonal_sum(), 15);
+    assert_eq!(DMatrix::<f64>::zeros(0, 3).diagonal_sum(), 0.0);
+
+    let m = DMatrix::from_fn(7, 4, |i, j| (i * 10 + j) as f64);
+    assert_eq!(m.diagonal_sum(), 0.0 + 11.0 + 22.0 + 33.0);
+}
+
+#[test]
+fn trace_mul() {
+    let a = Matrix2x3::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0);
+    let b = Matrix3x2::new(1.0, -1.0, 2.0, 0.5, -3.0, 2.0);
+    assert_eq!(a.trace_mul(&b), (a * b).trace());
+    assert_eq!(b.trace_mul(&a), (b * a).trace());
+    assert_eq!(a.trace_mul(&b), b.trace_mul(&a));
+
+    let c = DMatrix::from_fn(4, 6, |i, j| i as f64 - 2.0 * j as f64);
+    let d = DMatrix::from_fn(6, 4, |i, j| (i * j) as f64 + 1.0);
+    assert_eq!(c.trace_mul(&d), (&c * &d).trace());
+}
+
+#[test]
+#[should_panic]
+fn trace_mul_dimension_mismatch() {
+    let a = DMatrix::<f64>::zeros(2, 3);
+    let _ = a.trace_mul(&a);
+}
+
 #[test]
 fn simple_transpose() {
     let a = Matrix2x3::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0);
@@ -731,6 +1028,83 @@ fn kronecker() {
     assert_eq!(a.kronecker(&b), expected);
 }
 
+#[test]
+fn kronecker_mixed_dimensions() {
+    let a = Matrix3::new(1, 2, 3, 4, 5, 6, 7, 8, 9);
+    let b = DMatrix::from_fn(2, 4, |i, j| (10 * i + j) as i32);
+    let a_dyn = DMatrix::from_column_slice(3, 3, a.as_slice());
+    let b_static = Matrix2x4::from_column_slice(b.as_slice());
+
+    let expected_ab = a_dyn.kronecker(&b);
+    let expected_ba = b.kronecker(&a_dyn);
+
+    // Static ⊗ dynamic and dynamic ⊗ static both result in fully dynamic matrices.
+    let ab: DMatrix<i32> = a.kronecker(&b);
+    let ba: DMatrix<i32> = b.kronecker(&a);
+    assert_eq!(ab.shape(), (6, 12));
+    assert_eq!(ab, expected_ab);
+    assert_eq!(ba, expected_ba);
+
+    // Mixing a static number of rows with a dynamic number of columns.
+    let v = Vector2::new(1, -1);
+    let r: OMatrix<i32, na::U6, na::Dynamic> =
+        Vector3::new(1, 2, 3).kronecker(&b.fixed_rows::<2>(0));
+    assert_eq!(r, DVector::from_column_slice(&[1, 2, 3]).kronecker(&b));
+    assert_eq!(
+        v.kronecker(&a_dyn),
+        DVector::from_column_slice(&[1, -1]).kronecker(&a)
+    );
+    assert_eq!(a.kronecker(&b_static), ab);
+}
+
+#[test]
+fn kronecker_sum() {
+    let a = Matrix3::new(1, 2, 3, 4, 5, 6, 7, 8, 9);
+    let b = DMatrix::from_fn(2, 2, |i, j| (10 * i + j) as i32);
+    let expected = a.kronecker(&DMatrix::<i32>::identity(2, 2)) + Matrix3::identity().kronecker(&b);
+
+    let ab: DMatrix<i32> = a.kronecker_sum(&b);
+    assert_eq!(ab.shape(), (6, 6));
+    assert_eq!(ab, expected);
+
+    // The 2D laplacian on a 3x3 grid is the kronecker sum of two 1D laplacians.
+    let lap1 = Matrix3::new(-2, 1, 0, 1, -2, 1, 0, 1, -2);
+    let lap2: OMatrix<i32, na::U9, na::U9> = lap1.kronecker_sum(&lap1);
+    assert_eq!(lap2.diagonal(), OMatrix::<i32, na::U9, na::U1>::repeat(-4));
+    assert_eq!(lap2.row(4).sum(), 0);
+    assert_eq!(lap2, lap2.transpose());
+
+    let empty = DMatrix::<i32>::zeros(0, 0);
+    assert_eq!(a.kronecker_sum(&empty).shape(), (0, 0));
+}
+
+#[test]
+#[should_panic]
+fn kronecker_sum_non_square() {
+    let _ = Matrix2::new(1, 2, 3, 4).kronecker_sum(&Matrix2x3::new(1, 2, 3, 4, 5, 6));
+}
+
+#[test]
+fn outer_product() {
+    let a = Vector3::new(1, 2, 3);
+    let b = Vector2::new(4, 5);
+    let expected = Matrix3x2::new(4, 5, 8, 10, 12, 15);
+
+    assert_eq!(a.outer_product(&b), expected);
+    assert_eq!(a.outer_product(&b), a * b.transpose());
+    assert_eq!(b.outer_product(&a), expected.transpose());
+
+    // Dynamic operands result in dynamic dimensions.
+    let a_dyn = DVector::from_column_slice(a.as_slice());
+    let b_dyn = DVector::from_column_slice(b.as_slice());
+    let ab: DMatrix<i32> = a_dyn.outer_product(&b_dyn);
+    assert_eq!(ab, DMatrix::from_column_slice(3, 2, expected.as_slice()));
+    assert_eq!(a_dyn.outer_product(&b).as_slice(), expected.as_slice());
+
+    let empty = DVector::<i32>::zeros(0);
+    assert_eq!(empty.outer_product(&a).shape(), (0, 3));
+}
+
 #[test]
 fn set_row_column() {
     let a = Matrix4x5::new(
@@ -957,6 +1331,216 @@ mod normalization_tests {
     }
 }
 
+#[test]
+#[cfg(feature = "rayon")]
+fn from_fn_par() {
+    let f = |i: usize, j: usize| (i * 7 + j * 13) as f64 / (1.0 + i as f64);
+
+    assert_eq!(DMatrix::from_fn_par(37, 53, f), DMatrix::from_fn(37, 53, f));
+    assert_eq!(DMatrix::from_fn_par(0, 5, f), DMatrix::from_fn(0, 5, f));
+    assert_eq!(DMatrix::from_fn_par(5, 0, f), DMatrix::from_fn(5, 0, f));
+}
+
+#[test]
+fn cross_matrix_uncross() {
+    let a = Vector3::new(1.0, -2.0, 0.5);
+    let b = Vector3::new(3.0, 4.0, -7.0);
+    let m = a.cross_matrix();
+
+    assert_eq!(m * b, a.cross(&b));
+    assert_eq!(m.transpose(), -m);
+    assert_eq!(m.uncross(), a);
+    assert_eq!(Matrix3::<f64>::identity().uncross(), Vector3::zeros());
+}
+
+#[test]
+fn unit_vector_try_slerp() {
+    let x = Vector3::x_axis();
+    let z = Vector3::z_axis();
+
+    let v = x.try_slerp(&z, 1.0 / 3.0, 1.0e-6).unwrap();
+    let angle = std::f64::consts::FRAC_PI_6;
+    assert_relative_eq!(
+        v.into_inner(),
+        Vector3::new(angle.cos(), 0.0, angle.sin()),
+        epsilon = 1.0e-7
+    );
+
+    assert_eq!(x.try_slerp(&z, 0.0, 1.0e-6), Some(x));
+    assert_relative_eq!(x.try_slerp(&z, 1.0, 1.0e-6).unwrap(), z, epsilon = 1.0e-7);
+    assert_eq!(x.try_slerp(&-x, 0.5, 1.0e-6), None);
+}
+
+#[test]
+fn unit_vector_slerp_antipodal() {
+    let a = na::Unit::new_normalize(Vector3::new(1.0, 2.0, -3.0));
+    let b = -a;
+
+    for i in 0..=10 {
+        let t = i as f64 / 10.0;
+        let v = a.slerp(&b, t);
+        assert_relative_eq!(v.norm(), 1.0, epsilon = 1.0e-12);
+        assert_relative_eq!(a.angle(&v), t * std::f64::consts::PI, epsilon = 1.0e-7);
+    }
+
+    assert_relative_eq!(a.slerp(&b, 0.0), a, epsilon = 1.0e-12);
+    assert_relative_eq!(a.slerp(&b, 1.0), b, epsilon = 1.0e-12);
+
+    // There is no great circle in 1D.
+    let x = na::Vector1::x_axis();
+    assert_eq!(x.slerp(&-x, 0.5), x);
+}
+
+#[test]
+fn unit_vector_try_slerp_almost_identical() {
+    let a = na::Unit::new_normalize(Vector3::new(1.0, 0.0, 0.0));
+    let b = na::Unit::new_normalize(Vector3::new(1.0, 1.0e-9, 0.0));
+
+    let v = a.try_slerp(&b, 0.5, 1.0e-6).unwrap();
+    assert_relative_eq!(v.norm(), 1.0, epsilon = 1.0e-12);
+    assert_relative_eq!(v.into_inner(), a.into_inner(), epsilon = 1.0e-8);
+}
+
+#[test]
+fn unit_vector_try_slerp_dynamic() {
+    let a = na::Unit::new_normalize(DVector::from_vec(vec![1.0, 0.0, 0.0, 0.0, 0.0]));
+    let b = na::Unit::new_normalize(DVector::from_vec(vec![0.0, 0.0, 0.0, 0.0, 1.0]));
+
+    let v = a.try_slerp(&b, 0.5, 1.0e-6).unwrap();
+    let h = std::f64::consts::FRAC_1_SQRT_2;
+    assert_relative_eq!(
+        v.into_inner(),
+        DVector::from_vec(vec![h, 0.0, 0.0, 0.0, h]),
+        epsilon = 1.0e-7
+    );
+}
+
+#[test]
+fn vector_reflect() {
+    let v = Vector3::new(1.0, -2.0, 3.0);
+    let normal = na::Unit::new_normalize(Vector3::new(0.0, 1.0, 1.0));
+
+    let r = v.reflect(&normal);
+    assert_relative_eq!(r.norm(), v.norm(), epsilon = 1.0e-12);
+    assert_relative_eq!(r.dot(&normal), -v.dot(&normal), epsilon = 1.0e-12);
+    assert_relative_eq!(r.reflect(&normal), v, epsilon = 1.0e-12);
+    assert_relative_eq!(v.reflect(&-normal), r, epsilon = 1.0e-12);
+}
+
+#[test]
+fn vector_refract() {
+    let normal = Vector2::y_axis();
+    let incident = na::Unit::new_normalize(Vector2::new(3.0, -4.0));
+
+    // Snell's law: eta * sin(theta_i) = sin(theta_t).
+    let eta = 1.0 / 1.33;
+    let t = incident.refract(&normal, eta).unwrap();
+    assert_relative_eq!(t.norm(), 1.0, epsilon = 1.0e-12);
+    assert_relative_eq!(t.x, eta * incident.x, epsilon = 1.0e-12);
+    assert!(t.y < 0.0);
+
+    // No bending if both media are the same.
+    assert_relative_eq!(
+        incident.refract(&normal, 1.0).unwrap(),
+        incident.into_inner(),
+        epsilon = 1.0e-12
+    );
+
+    // Total internal reflection.
+    assert_eq!(incident.refract(&normal, 2.0), None);
+}
+
+#[test]
+fn vector_decompose_along_zero() {
+    let v = Vector3::new(1.0, 2.0, 3.0);
+    let zero = Vector3::zeros();
+
+    assert_eq!(v.project_onto(&zero), zero);
+    assert_eq!(v.reject_from(&zero), v);
+    assert_eq!(v.decompose_along(&zero), (zero, v));
+}
+
+#[cfg(feature = "proptest-support")]
+mod projection_tests {
+    use crate::proptest::*;
+    use na::{DVector, Unit};
+    use proptest::{prop_assert, proptest};
+
+    proptest! {
+        #[test]
+        fn cross_matrix_mul_is_cross(a in vector3(), b in vector3()) {
+            prop_assert!(relative_eq!(a.cross_matrix() * b, a.cross(&b), epsilon = 1.0e-7));
+            prop_assert!(relative_eq!(a.cross_matrix().uncross(), a, epsilon = 1.0e-7));
+        }
+
+        #[test]
+        fn decompose_along_sums_to_self(v in vector3(), other in vector3()) {
+            let (parallel, perpendicular) = v.decompose_along(&other);
+            prop_assert!(relative_eq!(parallel + perpendicular, v, epsilon = 1.0e-7));
+            prop_assert!(relative_eq!(perpendicular.dot(&other), 0.0, epsilon = 1.0e-7));
+            prop_assert!(relative_eq!(parallel.dot(&other), v.dot(&other), epsilon = 1.0e-7));
+            prop_assert!(relative_eq!(v.project_onto(&other), parallel, epsilon = 1.0e-7));
+            prop_assert!(relative_eq!(v.reject_from(&other), perpendicular, epsilon = 1.0e-7));
+        }
+
+        #[test]
+        fn project_onto_unit(v in vector3(), other in vector3()) {
+            if let Some(dir) = Unit::try_new(other, 1.0e-7) {
+                let proj = v.project_onto_unit(&dir);
+                let rej = v.reject_from_unit(&dir);
+                prop_assert!(relative_eq!(proj, v.project_onto(&other), epsilon = 1.0e-7));
+                prop_assert!(relative_eq!(rej, v.reject_from(&other), epsilon = 1.0e-7));
+                prop_assert!(relative_eq!(proj + rej, v, epsilon = 1.0e-7));
+                prop_assert!(relative_eq!(rej.dot(&dir), 0.0, epsilon = 1.0e-7));
+            }
+        }
+
+        #[test]
+        fn project_onto_is_idempotent_dyn(v in dvector(), scale in PROPTEST_F64) {
+            let other = DVector::from_fn(v.len(), |i, _| (i as f64 + 1.0) * scale);
+            let proj = v.project_onto(&other);
+            prop_assert!(relative_eq!(proj.project_onto(&other), proj, epsilon = 1.0e-7));
+        }
+
+        #[test]
+        fn decompose_along_complex(v in vector3_(complex_f64()), other in vector3_(complex_f64())) {
+            let (parallel, perpendicular) = v.decompose_along(&other);
+            prop_assert!(relative_eq!(parallel + perpendicular, v, epsilon = 1.0e-7));
+            prop_assert!(relative_eq!(other.dotc(&perpendicular).norm(), 0.0, epsilon = 1.0e-7));
+        }
+    }
+}
+
+#[cfg(feature = "proptest-support")]
+mod slerp_tests {
+    use crate::proptest::*;
+    use na::Unit;
+    use proptest::{prop_assert, proptest};
+
+    proptest! {
+        #[test]
+        fn try_slerp_follows_great_circle(a in vector3(), b in vector3(), t in 0.0..1.0f64) {
+            if let (Some(a), Some(b)) = (Unit::try_new(a, 1.0e-5), Unit::try_new(b, 1.0e-5)) {
+                if let Some(v) = a.try_slerp(&b, t, 1.0e-5) {
+                    let angle = a.angle(&b);
+                    prop_assert!(relative_eq!(v.norm(), 1.0, epsilon = 1.0e-7));
+                    prop_assert!(relative_eq!(a.angle(&v), t * angle, epsilon = 1.0e-5));
+                    prop_assert!(relative_eq!(v.angle(&b), (1.0 - t) * angle, epsilon = 1.0e-5));
+                }
+            }
+        }
+
+        #[test]
+        fn slerp_stays_on_sphere(a in vector3(), b in vector3(), t in 0.0..1.0f64) {
+            if let (Some(a), Some(b)) = (Unit::try_new(a, 1.0e-5), Unit::try_new(b, 1.0e-5)) {
+                prop_assert!(relative_eq!(a.slerp(&b, t).norm(), 1.0, epsilon = 1.0e-7));
+                prop_assert!(relative_eq!(a.slerp(&b, 0.0), a, epsilon = 1.0e-7));
+                prop_assert!(relative_eq!(a.slerp(&b, 1.0), b, epsilon = 1.0e-7));
+            }
+        }
+    }
+}
+
 #[cfg(all(feature = "proptest-support", feature = "alga"))]
 // TODO: move this to alga ?
 mod finite_dim_inner_space_tests {
@@ -1136,3 +1720,364 @@ fn omatrix_to_string() {
         (svec.to_string(), smatr.to_string())
     );
 }
+
+#[test]
+fn clamp() {
+    let m = Matrix2x3::new(-5.0, 0.5, 2.0, 1.0, 7.0, -1.0);
+    let min = Matrix2x3::new(-1.0, 0.0, 0.0, 2.0, -10.0, -0.5);
+    let max = Matrix2x3::new(1.0, 1.0, 1.0, 3.0, 5.0, 0.5);
+    let expected = Matrix2x3::new(-1.0, 0.5, 1.0, 2.0, 5.0, -0.5);
+
+    assert_eq!(m.clamp(&min, &max), expected);
+
+    let mut m2 = m;
+    m2.clamp_mut(&min, &max);
+    assert_eq!(m2, expected);
+
+    // Inverted bounds yield the upper bound.
+    assert_eq!(m.clamp(&max, &min), min);
+}
+
+#[test]
+fn clamp_scalar() {
+    let m = Matrix2x3::new(-5.0, 0.5, 2.0, 1.0, 7.0, -1.0);
+    let expected = Matrix2x3::new(-1.0, 0.5, 2.0, 1.0, 2.0, -1.0);
+
+    assert_eq!(m.clamp_scalar(-1.0, 2.0), expected);
+
+    let mut m2 = m;
+    m2.clamp_scalar_mut(-1.0, 2.0);
+    assert_eq!(m2, expected);
+
+    // Clamping a view only affects the viewed components.
+    m2.column_mut(1).clamp_scalar_mut(0.0, 0.0);
+    assert_eq!(m2, Matrix2x3::new(-1.0, 0.0, 2.0, 1.0, 0.0, -1.0));
+
+    // NaN components are replaced by the lower bound, infinities are clamped.
+    let mut m3 = Matrix2x3::new(
+        f64::NAN,
+        0.5,
+        f64::INFINITY,
+        1.0,
+        f64::NEG_INFINITY,
+        f64::NAN,
+    );
+    let expected = Matrix2x3::new(-1.0, 0.5, 2.0, 1.0, -1.0, -1.0);
+    assert_eq!(m3.clamp_scalar(-1.0, 2.0), expected);
+    m3.clamp_scalar_mut(-1.0, 2.0);
+    assert_eq!(m3, expected);
+    assert_eq!(
+        Matrix2x3::repeat(f64::NAN).clamp(&m, &Matrix2x3::repeat(10.0)),
+        m
+    );
+}
+
+#[test]
+fn block_matrix_2x2() {
+    let a = DMatrix::from_fn(2, 3, |i, j| (i * 3 + j) as f64);
+    let b = DMatrix::from_fn(2, 1, |i, _| 10.0 + i as f64);
+    let c = DMatrix::from_fn(4, 3, |i, j| 20.0 + (i * 3 + j) as f64);
+    let d = DMatrix::from_fn(4, 1, |i, _| 40.0 + i as f64);
+
+    let mut expected = DMatrix::zeros(6, 4);
+    expected.slice_mut((0, 0), (2, 3)).copy_from(&a);
+    expected.slice_mut((0, 3), (2, 1)).copy_from(&b);
+    expected.slice_mut((2, 0), (4, 3)).copy_from(&c);
+    expected.slice_mut((2, 3), (4, 1)).copy_from(&d);
+
+    let top = DMatrix::hstack(&[a.clone(), b.clone()]);
+    let left = DMatrix::vstack(&[a.clone(), c.clone()]);
+    assert_eq!(top, expected.rows(0, 2));
+    assert_eq!(left, expected.columns(0, 3));
+    assert_eq!(DMatrix::block(&[&[a, b], &[c, d]]), expected);
+
+    assert_eq!(DMatrix::<f64>::hstack(&[]).shape(), (0, 0));
+    assert_eq!(DMatrix::<f64>::vstack(&[]).shape(), (0, 0));
+    assert_eq!(DMatrix::<f64>::block(&[]).shape(), (0, 0));
+}
+
+#[test]
+#[should_panic]
+fn hstack_mismatched_rows() {
+    let _ = DMatrix::hstack(&[DMatrix::<f64>::zeros(2, 3), DMatrix::zeros(3, 3)]);
+}
+
+#[test]
+#[should_panic]
+fn vstack_mismatched_columns() {
+    let _ = DMatrix::vstack(&[DMatrix::<f64>::zeros(2, 3), DMatrix::zeros(2, 2)]);
+}
+
+#[test]
+#[should_panic]
+fn block_mismatched_grid_columns() {
+    // Both rows of the grid are 4 columns wide, but the blocks are not aligned.
+    let _ = DMatrix::block(&[
+        &[DMatrix::<f64>::zeros(1, 1), DMatrix::zeros(1, 3)],
+        &[DMatrix::zeros(1, 2), DMatrix::zeros(1, 2)],
+    ]);
+}
+
+#[test]
+fn elementwise_functions() {
+    let m = Matrix2x3::new(0.5f64, 1.0, 2.0, 3.0, 4.0, 0.0);
+
+    assert_eq!(m.exp_elementwise(), m.map(|e| e.exp()));
+    assert_eq!(m.ln_elementwise(), m.map(|e| e.ln()));
+    assert_eq!(m.sqrt_elementwise(), m.map(|e| e.sqrt()));
+    assert_eq!(m.powf_elementwise(1.5), m.map(|e| e.powf(1.5)));
+
+    let v = DVector::from_vec(vec![
+        na::Complex::new(1.0, 2.0),
+        na::Complex::new(-1.0, 0.5),
+    ]);
+    assert_eq!(v.exp_elementwise(), v.map(|e| e.exp()));
+    assert_eq!(v.powf_elementwise(2.0), v.map(|e| e.powf(2.0)));
+}
+
+#[test]
+fn exp_elementwise_is_not_matrix_exp() {
+    // Both agree on diagonal matrices, except for the off-diagonal zeros.
+    let d = Matrix2::new(1.0, 0.0, 0.0, 2.0);
+    assert_relative_eq!(d.exp(), Matrix2::new(1.0f64.exp(), 0.0, 0.0, 2.0f64.exp()));
+    assert_eq!(
+        d.exp_elementwise(),
+        Matrix2::new(1.0f64.exp(), 1.0, 1.0, 2.0f64.exp())
+    );
+
+    let m = Matrix2::new(0.0, 1.0, 0.0, 0.0);
+    assert_relative_eq!(m.exp(), Matrix2::new(1.0, 1.0, 0.0, 1.0));
+    assert_relative_eq!(
+        m.exp_elementwise(),
+        Matrix2::new(1.0, 1.0f64.exp(), 1.0, 1.0)
+    );
+}
+
+#[test]
+fn linspace() {
+    let v = DVector::linspace(-1.0, 1.0, 5);
+    assert_eq!(v, DVector::from_vec(vec![-1.0, -0.5, 0.0, 0.5, 1.0]));
+
+    // The endpoints are exact even when the step is not representable.
+    for n in 2..50 {
+        let v = DVector::linspace(0.1, 0.7, n);
+        assert_eq!(v.len(), n);
+        assert_eq!(v[0], 0.1);
+        assert_eq!(v[n - 1], 0.7);
+        assert!(v.as_slice().windows(2).all(|w| w[0] < w[1]));
+        assert_relative_eq!(v[1] - v[0], 0.6 / (n - 1) as f64, epsilon = 1.0e-12);
+    }
+
+    // Decreasing ranges are supported.
+    let v = DVector::linspace(3.0f32, 1.0, 3);
+    assert_eq!(v, DVector::from_vec(vec![3.0, 2.0, 1.0]));
+
+    assert_eq!(DVector::linspace(4.0, 5.0, 1), DVector::from_vec(vec![4.0]));
+    assert_eq!(DVector::<f64>::linspace(4.0, 5.0, 0).len(), 0);
+}
+
+#[test]
+fn logspace() {
+    let v = DVector::logspace(-2.0, 2.0, 5, 10.0);
+    let expected = DVector::from_vec(vec![0.01, 0.1, 1.0, 10.0, 100.0]);
+    assert_relative_eq!(v, expected, max_relative = 1.0e-12);
+
+    let v = DVector::logspace(0.0, 4.0, 5, 2.0);
+    assert_eq!(v, DVector::from_vec(vec![1.0, 2.0, 4.0, 8.0, 16.0]));
+
+    assert_eq!(
+        DVector::logspace(3.0, 5.0, 1, 2.0),
+        DVector::from_vec(vec![8.0])
+    );
+    assert_eq!(DVector::<f64>::logspace(3.0, 5.0, 0, 2.0).len(), 0);
+}
+
+#[test]
+fn range() {
+    assert_eq!(
+        DVector::range(1.0, 4.0, 1.0),
+        DVector::from_vec(vec![1.0, 2.0, 3.0])
+    );
+    assert_eq!(
+        DVector::range(1.0, 4.5, 1.0),
+        DVector::from_vec(vec![1.0, 2.0, 3.0, 4.0])
+    );
+    assert_eq!(
+        DVector::range(1.0, -1.0, -0.5),
+        DVector::from_vec(vec![1.0, 0.5, 0.0, -0.5])
+    );
+
+    let v = DVector::range(0.0, 1.0, 0.1);
+    assert_eq!(v.len(), 10);
+    assert_relative_eq!(v[9], 0.9, epsilon = 1.0e-12);
+
+    // Empty ranges.
+    assert!(DVector::range(1.0, 1.0, 1.0).is_empty());
+    assert!(DVector::range(2.0, 1.0, 1.0).is_empty());
+    assert!(DVector::range(1.0, 2.0, -1.0).is_empty());
+    assert!(DVector::range(f64::NAN, 2.0, 1.0).is_empty());
+}
+
+#[test]
+#[should_panic]
+fn range_zero_step() {
+    let _ = DVector::range(0.0, 1.0, 0.0);
+}
+
+#[test]
+#[should_panic(expected = "range: the number of values is infinite or too large.")]
+fn range_infinite_end() {
+    let _ = DVector::range(0.0, f64::INFINITY, 1.0);
+}
+
+#[test]
+#[should_panic(expected = "range: the number of values is infinite or too large.")]
+fn range_tiny_step() {
+    let _ = DVector::range(0.0, 1.0, 1.0e-300);
+}
+
+#[test]
+fn meshgrid() {
+    let x = DVector::range(0.0, 3.0, 1.0);
+    let y = Vector2::new(-1.0, 1.0);
+    let (xx, yy) = DMatrix::meshgrid(&x, &y);
+
+    assert_eq!(xx.shape(), (2, 3));
+    assert_eq!(yy.shape(), (2, 3));
+
+    for i in 0..2 {
+        for j in 0..3 {
+            assert_eq!((xx[(i, j)], yy[(i, j)]), (x[j], y[i]));
+        }
+    }
+
+    // The 'ij' indexing convention is the transpose of the 'xy' one.
+    let (xx_ij, yy_ij) = DMatrix::meshgrid(&y, &x);
+    assert_eq!(xx_ij.transpose(), yy);
+    assert_eq!(yy_ij.transpose(), xx);
+
+    let (xx, yy) = DMatrix::meshgrid(&DVector::<f64>::zeros(0), &y);
+    assert_eq!((xx.shape(), yy.shape()), ((2, 0), (2, 0)));
+}
+
+#[test]
+fn variance_and_std_dev_along_axes() {
+    #[rustfmt::skip]
+    let m = DMatrix::from_row_slice(4, 3, &[
+        2.0, 1.0, -3.0,
+        4.0, 1.0,  5.0,
+        4.0, 1.0,  0.0,
+        6.0, 1.0,  2.0,
+    ]);
+
+    // Column means: 4, 1 and 1. Sums of squared deviations: 8, 0 and 34.
+    assert_eq!(
+        m.variance_rows(0),
+        RowDVector::from_row_slice(&[2.0, 0.0, 8.5])
+    );
+    assert_relative_eq!(
+        m.variance_rows(1),
+        RowDVector::from_row_slice(&[8.0 / 3.0, 0.0, 34.0 / 3.0])
+    );
+    assert_relative_eq!(m.variance_rows(0), m.row_variance(), epsilon = 1.0e-12);
+    assert_relative_eq!(
+        m.std_dev_rows(1),
+        RowDVector::from_row_slice(&[(8.0f64 / 3.0).sqrt(), 0.0, (34.0f64 / 3.0).sqrt()])
+    );
+
+    let mt = m.transpose();
+    assert_eq!(
+        mt.variance_columns(0),
+        DVector::from_column_slice(&[2.0, 0.0, 8.5])
+    );
+    assert_relative_eq!(
+        mt.variance_columns(2),
+        DVector::from_column_slice(&[4.0, 0.0, 17.0])
+    );
+    assert_relative_eq!(
+        mt.variance_columns(0),
+        mt.column_variance(),
+        epsilon = 1.0e-12
+    );
+    assert_relative_eq!(mt.std_dev_columns(0), m.std_dev_rows(0).transpose());
+
+    // Empty along the other axis.
+    assert_eq!(DMatrix::<f64>::zeros(0, 3).variance_columns(1).len(), 0);
+    assert_eq!(DMatrix::<f64>::zeros(3, 0).variance_rows(1).len(), 0);
+}
+
+#[test]
+fn variance_along_axes_with_large_offset() {
+    // The variance of 1e9 + [4, 7, 13, 16] is 30 (sample) and 22.5 (population).
+    // The naive sum-of-squares formula loses all precision at this magnitude.
+    let offset = 1.0e9;
+    let v = DVector::from_column_slice(&[4.0, 7.0, 13.0, 16.0]).add_scalar(offset);
+
+    assert_eq!(v.variance_rows(1)[0], 30.0);
+    assert_eq!(v.transpose().variance_columns(0)[0], 22.5);
+    assert_eq!(v.std_dev_rows(0)[0], 22.5f64.sqrt());
+}
+
+#[test]
+#[should_panic]
+fn variance_rows_not_enough_samples() {
+    let _ = DMatrix::<f64>::zeros(2, 3).variance_rows(2);
+}
+
+#[test]
+fn median() {
+    let odd = DVector::from_vec(vec![7.0, -2.0, 5.0, 3.0, 9.0, 0.0, 3.0]);
+    assert_eq!(odd.median(), Some(3.0));
+
+    let even = DVector::from_vec(vec![10.0, 2.0, 8.0, 4.0]);
+    assert_eq!(even.median(), Some(6.0));
+
+    assert_eq!(DVector::from_vec(vec![42.0]).median(), Some(42.0));
+    assert_eq!(DVector::<f64>::zeros(0).median(), None);
+    assert_eq!(DMatrix::<f64>::zeros(3, 0).median(), None);
+
+    // The median does not depend on the layout of the elements.
+    let m = Matrix2x3::new(6.0, 1.0, 5.0, 2.0, 4.0, 3.0);
+    assert_eq!(m.median(), Some(3.5));
+    assert_eq!(m.transpose().median(), Some(3.5));
+    assert_eq!(m.columns(1, 2).median(), Some(3.5));
+}
+
+#[test]
+fn percentile() {
+    let v = DVector::from_vec(vec![15.0, 20.0, 35.0, 40.0, 50.0]);
+    assert_eq!(v.percentile(0.0), Some(15.0));
+    assert_eq!(v.percentile(25.0), Some(20.0));
+    assert_eq!(v.percentile(50.0), v.median());
+    assert_eq!(v.percentile(60.0), Some(37.0));
+    assert_eq!(v.percentile(100.0), Some(50.0));
+
+    let v = Vector4::new(4.0, 1.0, 2.0, 3.0);
+    assert_eq!(v.percentile(50.0), Some(2.5));
+    assert_eq!(v.percentile(100.0 / 3.0), Some(2.0));
+    assert_eq!(DVector::<f64>::zeros(0).percentile(50.0), None);
+}
+
+#[test]
+#[should_panic]
+fn percentile_out_of_range() {
+    let _ = Vector3::new(1.0, 2.0, 3.0).percentile(100.5);
+}
+
+#[test]
+fn row_column_median() {
+    let m = Matrix3x4::new(
+        1.0, 9.0, 4.0, 0.0, //
+        3.0, 7.0, 4.0, -1.0, //
+        2.0, 8.0, 6.0, 1.0,
+    );
+    assert_eq!(m.row_median(), Some(RowVector4::new(2.0, 8.0, 4.0, 0.0)));
+    assert_eq!(m.column_median(), Some(Vector3::new(2.5, 3.5, 4.0)));
+
+    assert_eq!(DMatrix::<f64>::zeros(0, 3).row_median(), None);
+    assert_eq!(DMatrix::<f64>::zeros(3, 0).column_median(), None);
+    assert_eq!(
+        DMatrix::<f64>::zeros(0, 3).column_median(),
+        Some(DVector::zeros(0))
+    );
+}
@@ -164,6 +164,80 @@ fn col_slice_mut() {
     assert_eq!(expected_a, a.clone_owned());
 }
 
+#[test]
+#[rustfmt::skip]
+fn slices_with_step_strides() {
+    let a = DMatrix::from_fn(5, 6, |i, j| (10 * (i + 1) + j + 1) as f64);
+
+    // A step of zero is equivalent to a contiguous slice.
+    assert_eq!(a.rows_with_step(1, 3, 0), a.rows(1, 3));
+    assert_eq!(a.columns_with_step(2, 4, 0), a.columns(2, 4));
+
+    let even_rows = a.rows_with_step(0, 3, 1);
+    let odd_cols = a.columns_with_step(1, 3, 1);
+    let every_third = a.slice_with_steps((0, 0), (2, 2), (2, 2));
+
+    let expected_even_rows = DMatrix::from_row_slice(3, 6, &[
+        11.0, 12.0, 13.0, 14.0, 15.0, 16.0,
+        31.0, 32.0, 33.0, 34.0, 35.0, 36.0,
+        51.0, 52.0, 53.0, 54.0, 55.0, 56.0,
+    ]);
+    let expected_odd_cols = DMatrix::from_row_slice(5, 3, &[
+        12.0, 14.0, 16.0,
+        22.0, 24.0, 26.0,
+        32.0, 34.0, 36.0,
+        42.0, 44.0, 46.0,
+        52.0, 54.0, 56.0,
+    ]);
+    let expected_every_third = DMatrix::from_row_slice(2, 2, &[
+        11.0, 14.0,
+        41.0, 44.0,
+    ]);
+
+    assert_eq!(even_rows.strides(), (2, 5));
+    assert_eq!(odd_cols.strides(), (1, 10));
+    assert_eq!(expected_even_rows, even_rows.clone_owned());
+    assert_eq!(expected_odd_cols, odd_cols.clone_owned());
+    assert_eq!(expected_every_third, every_third.clone_owned());
+}
+
+#[test]
+#[rustfmt::skip]
+fn diagonal_mut() {
+    let mut a = DMatrix::from_fn(3, 3, |i, j| (10 * (i + 1) + j + 1) as f64);
+    a.diagonal_mut().add_scalar_mut(100.0);
+
+    let expected_a = DMatrix::from_row_slice(3, 3, &[
+        111.0,  12.0,  13.0,
+         21.0, 122.0,  23.0,
+         31.0,  32.0, 133.0,
+    ]);
+    assert_eq!(expected_a, a);
+
+    // Rectangular matrix.
+    let mut b = Matrix3x2::new(11.0, 12.0,
+                               21.0, 22.0,
+                               31.0, 32.0);
+    assert_eq!(b.diagonal_mut().len(), 2);
+    b.diagonal_mut().fill(0.0);
+
+    let expected_b = Matrix3x2::new( 0.0, 12.0,
+                                    21.0,  0.0,
+                                    31.0, 32.0);
+    assert_eq!(expected_b, b);
+
+    // Diagonal of a slice with a non-unit row stride.
+    let mut c = Matrix3x4::new(11.0, 12.0, 13.0, 14.0,
+                               21.0, 22.0, 23.0, 24.0,
+                               31.0, 32.0, 33.0, 34.0);
+    c.slice_with_steps_mut((0, 1), (2, 2), (1, 1)).diagonal_mut().fill(0.0);
+
+    let expected_c = Matrix3x4::new(11.0,  0.0, 13.0, 14.0,
+                                    21.0, 22.0, 23.0, 24.0,
+                                    31.0, 32.0, 33.0,  0.0);
+    assert_eq!(expected_c, c);
+}
+
 #[test]
 #[rustfmt::skip]
 fn rows_range_pair() {
@@ -335,3 +409,37 @@ fn slice_with_steps_out_of_bounds() {
     let a = Matrix3x4::<f32>::zeros();
     a.slice_with_steps((1, 2), (2, 2), (0, 1));
 }
+
+#[test]
+#[rustfmt::skip]
+fn try_as_slice_and_flatten_to_vec() {
+    let m = Matrix3x4::new(11, 12, 13, 14,
+                           21, 22, 23, 24,
+                           31, 32, 33, 34);
+
+    // Owned matrices are always contiguous, in column-major order.
+    assert_eq!(m.try_as_slice(), Some(m.as_slice()));
+    assert_eq!(m.flatten_to_vec(), m.as_slice().to_vec());
+
+    // Full columns are contiguous.
+    let cols = m.fixed_columns::<2>(1);
+    assert_eq!(cols.try_as_slice(), Some(&[12, 22, 32, 13, 23, 33][..]));
+    assert_eq!(m.column(3).try_as_slice(), Some(&[14, 24, 34][..]));
+    assert_eq!(m.columns(0, 0).try_as_slice(), Some(&[][..]));
+
+    // Partial columns, rows, and strided slices are not.
+    let block = m.fixed_slice::<2, 2>(1, 1);
+    assert_eq!(block.try_as_slice(), None);
+    assert_eq!(block.flatten_to_vec(), vec![22, 32, 23, 33]);
+    assert_eq!(m.row(1).try_as_slice(), None);
+    assert_eq!(m.row(1).flatten_to_vec(), vec![21, 22, 23, 24]);
+    assert_eq!(m.columns_with_step(0, 2, 1).try_as_slice(), None);
+
+    // Consecutive components of a row vector are contiguous.
+    let row = RowVector4::new(1, 2, 3, 4);
+    assert_eq!(row.columns(1, 2).try_as_slice(), Some(&[2, 3][..]));
+
+    let dm = DMatrix::from_fn(3, 2, |i, j| i + 3 * j);
+    assert_eq!(dm.rows(0, 3).try_as_slice(), Some(&[0, 1, 2, 3, 4, 5][..]));
+    assert_eq!(dm.rows(0, 2).try_as_slice(), None);
+}
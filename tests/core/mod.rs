@@ -1,12 +1,18 @@
 mod blas;
 mod cg;
 mod conversion;
+#[cfg(feature = "io")]
+mod csv;
 mod edition;
 mod empty;
 mod matrix;
 mod matrix_slice;
 #[cfg(feature = "mint")]
 mod mint;
+#[cfg(feature = "io")]
+mod npy;
+#[cfg(feature = "rkyv-serialize")]
+mod rkyv;
 mod serde;
 
 #[cfg(feature = "compare")]
@@ -0,0 +1,122 @@
+use na::io::NpyError;
+use na::DMatrix;
+
+fn npy_bytes(descr: &str, fortran_order: bool, shape: &str, data: &[u8]) -> Vec<u8> {
+    let order = if fortran_order { "True" } else { "False" };
+    let header = format!(
+        "{{'descr': '{}', 'fortran_order': {}, 'shape': {}, }}\n",
+        descr, order, shape
+    );
+    let mut bytes = b"\x93NUMPY\x01\x00".to_vec();
+    bytes.extend_from_slice(&(header.len() as u16).to_le_bytes());
+    bytes.extend_from_slice(header.as_bytes());
+    bytes.extend_from_slice(data);
+    bytes
+}
+
+#[test]
+fn npy_roundtrip() {
+    let m = DMatrix::from_fn(3, 4, |i, j| (i * 10 + j) as f64 - 0.5);
+    let mut data = Vec::new();
+    m.write_npy_to(&mut data).unwrap();
+    assert_eq!((data.len() - 4 * 12 * 2) % 64, 0);
+    assert_eq!(DMatrix::<f64>::from_npy_reader(&data[..]).unwrap(), m);
+
+    let m = DMatrix::from_fn(2, 5, |i, j| (i as i16 - 3) * j as i16);
+    let mut data = Vec::new();
+    m.write_npy_to(&mut data).unwrap();
+    assert_eq!(DMatrix::<i16>::from_npy_reader(&data[..]).unwrap(), m);
+
+    let m = DMatrix::<u8>::from_element(0, 3, 1);
+    let mut data = Vec::new();
+    m.write_npy_to(&mut data).unwrap();
+    assert_eq!(DMatrix::<u8>::from_npy_reader(&data[..]).unwrap(), m);
+}
+
+#[test]
+fn npy_file_roundtrip() {
+    let path = std::env::temp_dir().join(format!("nalgebra_npy_{}.npy", std::process::id()));
+    let m = DMatrix::from_row_slice(2, 2, &[1.0f32, 2.0, 3.0, 4.0]);
+    m.write_npy(&path).unwrap();
+    let read = DMatrix::<f32>::read_npy(&path);
+    std::fs::remove_file(&path).unwrap();
+    assert_eq!(read.unwrap(), m);
+}
+
+#[test]
+fn npy_c_order_and_big_endian() {
+    let data: Vec<u8> = [1i32, 2, 3, 4, 5, 6]
+        .iter()
+        .flat_map(|e| e.to_le_bytes().to_vec())
+        .collect();
+    let bytes = npy_bytes("<i4", false, "(2, 3)", &data);
+    let m = DMatrix::<i32>::from_npy_reader(&bytes[..]).unwrap();
+    assert_eq!(m, DMatrix::from_row_slice(2, 3, &[1, 2, 3, 4, 5, 6]));
+
+    let data: Vec<u8> = [1.5f64, -2.0, 3.0]
+        .iter()
+        .flat_map(|e| e.to_be_bytes().to_vec())
+        .collect();
+    let bytes = npy_bytes(">f8", false, "(3,)", &data);
+    let m = DMatrix::<f64>::from_npy_reader(&bytes[..]).unwrap();
+    assert_eq!(m, DMatrix::from_column_slice(3, 1, &[1.5, -2.0, 3.0]));
+}
+
+#[test]
+fn npy_errors() {
+    let bytes = npy_bytes("<f4", true, "(1, 1)", &1.0f32.to_le_bytes());
+    match DMatrix::<f64>::from_npy_reader(&bytes[..]) {
+        Err(NpyError::UnsupportedDtype(descr)) => assert_eq!(descr, "<f4"),
+        r => panic!("unexpected result: {:?}", r),
+    }
+
+    let bytes = npy_bytes("<c16", true, "(1, 1)", &[0; 16]);
+    assert!(matches!(
+        DMatrix::<f64>::from_npy_reader(&bytes[..]),
+        Err(NpyError::UnsupportedDtype(_))
+    ));
+
+    let bytes = npy_bytes("<f8", true, "(1, 1, 1)", &1.0f64.to_le_bytes());
+    match DMatrix::<f64>::from_npy_reader(&bytes[..]) {
+        Err(NpyError::UnsupportedShape(shape)) => assert_eq!(shape, vec![1, 1, 1]),
+        r => panic!("unexpected result: {:?}", r),
+    }
+
+    let bytes = npy_bytes("<f8", true, "(2, 2)", &1.0f64.to_le_bytes());
+    assert!(matches!(
+        DMatrix::<f64>::from_npy_reader(&bytes[..]),
+        Err(NpyError::Io(_))
+    ));
+
+    assert!(matches!(
+        DMatrix::<f64>::from_npy_reader(&b"not an npy file"[..]),
+        Err(NpyError::InvalidFormat(_))
+    ));
+}
+
+#[test]
+fn npy_huge_shapes() {
+    // The size of the data overflows `usize`.
+    let shape = format!("({}, {})", usize::MAX / 2, 3);
+    let bytes = npy_bytes("<f8", true, &shape, &[]);
+    assert!(matches!(
+        DMatrix::<f64>::from_npy_reader(&bytes[..]),
+        Err(NpyError::InvalidFormat(_))
+    ));
+
+    // The size of the data fits in `usize`, but the data is missing: nothing is preallocated.
+    let shape = format!("({}, 1)", usize::MAX / 16);
+    let bytes = npy_bytes("<f8", true, &shape, &1.0f64.to_le_bytes());
+    match DMatrix::<f64>::from_npy_reader(&bytes[..]) {
+        Err(NpyError::Io(e)) => assert_eq!(e.kind(), std::io::ErrorKind::UnexpectedEof),
+        r => panic!("unexpected result: {:?}", r),
+    }
+
+    // Truncated header.
+    let mut bytes = npy_bytes("<f8", true, "(1, 1)", &1.0f64.to_le_bytes());
+    bytes[8..10].copy_from_slice(&u16::MAX.to_le_bytes());
+    assert!(matches!(
+        DMatrix::<f64>::from_npy_reader(&bytes[..]),
+        Err(NpyError::Io(_))
+    ));
+}
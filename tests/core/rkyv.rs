@@ -0,0 +1,45 @@
+#![cfg(feature = "rkyv-serialize")]
+
+use na::{DMatrix, DVector, Matrix2x3, Matrix3x4, Point3, Vector3};
+use rkyv::{Deserialize, Infallible};
+
+macro_rules! test_rkyv(
+    ($($test: ident, $ty: ident);* $(;)*) => {$(
+        #[test]
+        fn $test() {
+            let v: $ty<f32> = rand::random();
+            let bytes = rkyv::to_bytes::<_, 256>(&v).unwrap();
+            let archived = unsafe { rkyv::archived_root::<$ty<f32>>(&bytes[..]) };
+            let deserialized: $ty<f32> = archived.deserialize(&mut Infallible).unwrap();
+            assert_eq!(v, deserialized);
+        }
+    )*}
+);
+
+test_rkyv!(
+    rkyv_matrix3x4, Matrix3x4;
+    rkyv_point3,    Point3;
+    rkyv_vector3,   Vector3;
+);
+
+#[test]
+fn rkyv_dmatrix() {
+    let m: DMatrix<f64> = DMatrix::new_random(30, 40);
+    let bytes = rkyv::to_bytes::<_, 256>(&m).unwrap();
+    let archived = unsafe { rkyv::archived_root::<DMatrix<f64>>(&bytes[..]) };
+    assert_eq!(archived.data.as_slice(), m.as_slice());
+
+    let deserialized: DMatrix<f64> = archived.deserialize(&mut Infallible).unwrap();
+    assert_eq!(m, deserialized);
+
+    let v = DVector::from_column_slice(&[1, 2, 3, 4]);
+    let bytes = rkyv::to_bytes::<_, 256>(&v).unwrap();
+    let archived = unsafe { rkyv::archived_root::<DVector<i32>>(&bytes[..]) };
+    let deserialized: DVector<i32> = archived.deserialize(&mut Infallible).unwrap();
+    assert_eq!(v, deserialized);
+
+    let m = Matrix2x3::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0);
+    let bytes = rkyv::to_bytes::<_, 256>(&m).unwrap();
+    let archived = unsafe { rkyv::archived_root::<Matrix2x3<f64>>(&bytes[..]) };
+    assert_eq!(archived.data.0, m.data.0);
+}
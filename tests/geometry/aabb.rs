@@ -0,0 +1,82 @@
+use na::{Aabb, Aabb2, Aabb3, Point, Point2, Point3, Vector2, Vector3};
+
+#[test]
+fn aabb_from_single_point() {
+    let p = Point3::new(1.0, -2.0, 3.0);
+    let aabb = Aabb3::from_points(&[p]);
+
+    assert_eq!(aabb.mins(), &p);
+    assert_eq!(aabb.maxs(), &p);
+    assert_eq!(aabb.center(), p);
+    assert_eq!(aabb.extents(), Vector3::zeros());
+    assert!(aabb.contains(&p));
+    assert!(!aabb.contains(&Point3::new(1.0, -2.0, 3.5)));
+}
+
+#[test]
+fn aabb_from_points() {
+    let points = [
+        Point2::new(1.0, 5.0),
+        Point2::new(-3.0, 2.0),
+        Point2::new(0.0, -1.0),
+        Point2::new(2.0, 0.0),
+    ];
+    let aabb = Aabb2::from_points(&points);
+
+    assert_eq!(
+        aabb,
+        Aabb2::new(Point2::new(-3.0, -1.0), Point2::new(2.0, 5.0))
+    );
+    assert_eq!(aabb.center(), Point2::new(-0.5, 2.0));
+    assert_eq!(aabb.extents(), Vector2::new(5.0, 6.0));
+
+    for p in &points {
+        assert!(aabb.contains(p));
+    }
+}
+
+#[test]
+fn aabb_from_corners() {
+    let a = Point3::new(1.0, -1.0, 4.0);
+    let b = Point3::new(-2.0, 3.0, 4.0);
+
+    let aabb = Aabb3::from_corners(&a, &b);
+    assert_eq!(aabb, Aabb3::from_corners(&b, &a));
+    assert_eq!(aabb, Aabb3::from_points(&[a, b]));
+    assert_eq!(aabb.mins(), &Point3::new(-2.0, -1.0, 4.0));
+    assert_eq!(aabb.maxs(), &Point3::new(1.0, 3.0, 4.0));
+}
+
+#[test]
+fn aabb_merge() {
+    let a = Aabb2::new(Point2::new(0.0, 0.0), Point2::new(1.0, 1.0));
+    let b = Aabb2::new(Point2::new(0.5, -2.0), Point2::new(0.75, 0.5));
+    let merged = a.merge(&b);
+
+    assert_eq!(merged, b.merge(&a));
+    assert_eq!(merged, a.merge(&b).merge(&a));
+    assert_eq!(
+        merged,
+        Aabb2::new(Point2::new(0.0, -2.0), Point2::new(1.0, 1.0))
+    );
+    assert_eq!(a.merge(&a), a);
+}
+
+#[test]
+fn aabb_generic_dimension() {
+    let points = [
+        Point::from([0.0, 1.0, 2.0, 3.0, 4.0]),
+        Point::from([4.0, 3.0, 2.0, 1.0, 0.0]),
+    ];
+    let aabb: Aabb<f64, 5> = Aabb::from_points(&points);
+
+    assert_eq!(aabb.center(), Point::from([2.0; 5]));
+    assert!(aabb.contains(&Point::from([1.0, 1.5, 2.0, 2.5, 3.0])));
+    assert!(!aabb.contains(&Point::from([1.0, 1.5, 2.1, 2.5, 3.0])));
+}
+
+#[test]
+#[should_panic]
+fn aabb_from_no_points() {
+    let _ = Aabb3::<f64>::from_points(&[]);
+}
@@ -0,0 +1,121 @@
+use na::{
+    BoundingSphere2, BoundingSphere3, Isometry2, Isometry3, Point2, Point3, Vector2, Vector3,
+};
+
+/// Deterministic, scattered points.
+fn points3(n: usize) -> Vec<Point3<f64>> {
+    (0..n)
+        .map(|i| {
+            let t = i as f64;
+            Point3::new(
+                (t * 1.3).sin() * 10.0 + 2.0,
+                (t * 0.7).cos() * 4.0 - 1.0,
+                (t * 2.9).sin() * (t * 0.3).cos() * 7.0,
+            )
+        })
+        .collect()
+}
+
+#[test]
+fn bounding_sphere_from_single_point() {
+    let p = Point3::new(1.0, -2.0, 3.0);
+    let sphere = BoundingSphere3::from_points(&[p]);
+
+    assert_eq!(sphere.center(), &p);
+    assert_eq!(sphere.radius(), 0.0);
+    assert!(sphere.contains(&p));
+    assert!(!sphere.contains(&Point3::new(1.0, -2.0, 3.5)));
+}
+
+#[test]
+fn bounding_sphere_contains_points() {
+    for n in 1..50 {
+        let points = points3(n);
+        let sphere = BoundingSphere3::from_points(&points);
+
+        for p in &points {
+            assert!(sphere.contains(p), "{:?} is not in {:?}", p, sphere);
+        }
+    }
+
+    let points: Vec<_> = (0..100)
+        .map(|i| Point2::new((i as f64).cos() * i as f64, (i as f64 * 0.5).sin()))
+        .collect();
+    let sphere = BoundingSphere2::from_points(&points);
+    assert!(points.iter().all(|p| sphere.contains(p)));
+}
+
+#[test]
+#[should_panic]
+fn bounding_sphere_from_no_points() {
+    let _ = BoundingSphere2::<f64>::from_points(&[]);
+}
+
+#[test]
+fn bounding_sphere_merge() {
+    let spheres = [
+        BoundingSphere3::new(Point3::new(0.0, 0.0, 0.0), 1.0),
+        BoundingSphere3::new(Point3::new(3.0, -1.0, 2.0), 0.5),
+        BoundingSphere3::new(Point3::new(0.1, 0.2, 0.0), 4.0),
+        BoundingSphere3::new(Point3::new(-7.0, 2.0, 1.0), 0.0),
+        BoundingSphere3::from_points(&points3(20)),
+    ];
+    let directions = [
+        Vector3::x(),
+        -Vector3::x(),
+        Vector3::y(),
+        -Vector3::y(),
+        Vector3::z(),
+        -Vector3::z(),
+        Vector3::new(1.0, 1.0, 1.0).normalize(),
+        Vector3::new(-1.0, 2.0, -3.0).normalize(),
+    ];
+
+    for a in &spheres {
+        for b in &spheres {
+            let merged = a.merge(b);
+            assert_relative_eq!(merged.radius(), b.merge(a).radius(), epsilon = 1.0e-10);
+
+            for s in [a, b].iter() {
+                assert!(merged.contains(s.center()));
+                assert!(merged.radius() >= s.radius());
+
+                // Allow for rounding errors on points lying on the boundary of `s`.
+                for dir in &directions {
+                    let q = s.center() + dir * s.radius();
+                    assert!(na::distance(merged.center(), &q) <= merged.radius() + 1.0e-10);
+                }
+            }
+        }
+    }
+
+    let a = BoundingSphere2::new(Point2::new(-1.0, 0.0), 1.0);
+    let b = BoundingSphere2::new(Point2::new(2.0, 0.0), 2.0);
+    assert_eq!(
+        a.merge(&b),
+        BoundingSphere2::new(Point2::new(1.0, 0.0), 3.0)
+    );
+}
+
+#[test]
+fn bounding_sphere_transform_by() {
+    let points = points3(30);
+    let sphere = BoundingSphere3::from_points(&points);
+    let iso = Isometry3::new(Vector3::new(1.0, -4.0, 2.5), Vector3::new(0.3, -1.2, 2.0));
+    let transformed = sphere.transform_by(&iso);
+
+    assert_eq!(transformed.radius(), sphere.radius());
+
+    for p in &points {
+        // Allow for rounding errors on points lying on the boundary.
+        let q = iso * p;
+        assert!(na::distance(transformed.center(), &q) <= transformed.radius() + 1.0e-10);
+    }
+
+    let sphere = BoundingSphere2::new(Point2::new(1.0, 1.0), 1.0);
+    let iso = Isometry2::new(Vector2::new(0.0, 2.0), std::f64::consts::FRAC_PI_2);
+    let transformed = sphere.transform_by(&iso);
+    assert_relative_eq!(transformed.center(), &Point2::new(-1.0, 3.0));
+    assert!(transformed.contains(&(iso * Point2::new(1.5, 1.5))));
+    assert!(!transformed.contains(&(iso * Point2::new(2.0, 2.0))));
+}
@@ -1,7 +1,10 @@
 #![cfg(feature = "proptest-support")]
 #![allow(non_snake_case)]
 
-use na::{Isometry3, Point3, Vector3};
+use na::{
+    Isometry2, Isometry3, IsometryMatrix2, IsometryMatrix3, Point3, Translation3, UnitQuaternion,
+    Vector3,
+};
 
 use crate::proptest::*;
 use proptest::{prop_assert, prop_assert_eq, proptest};
@@ -82,6 +85,72 @@ proptest!(
             ))
     }
 
+    #[test]
+    fn transform_points_matches_mul(i in isometry3(), ps in proptest::collection::vec(point3(), 0..10)) {
+        let expected: Vec<_> = ps.iter().map(|p| i * p).collect();
+
+        let mut out = vec![Point3::origin(); ps.len()];
+        i.transform_points(&ps, &mut out);
+
+        let mut in_place = ps.clone();
+        i.transform_points_mut(&mut in_place);
+
+        for ((e, o), ip) in expected.iter().zip(&out).zip(&in_place) {
+            prop_assert!(relative_eq!(e, o, epsilon = 1.0e-7));
+            prop_assert_eq!(o, ip);
+        }
+    }
+
+    #[test]
+    fn transform_vectors_matches_mul(i in isometry3(), vs in proptest::collection::vec(vector3(), 0..10)) {
+        let mut out = vec![Vector3::zeros(); vs.len()];
+        i.transform_vectors(&vs, &mut out);
+
+        for (v, o) in vs.iter().zip(&out) {
+            prop_assert!(relative_eq!(i * v, *o, epsilon = 1.0e-7));
+        }
+    }
+
+    #[test]
+    fn inverse_transform_points_matches_per_point(i in isometry3(), ps in proptest::collection::vec(point3(), 0..10)) {
+        let mut out = vec![Point3::origin(); ps.len()];
+        i.inverse_transform_points(&ps, &mut out);
+
+        let mut in_place = ps.clone();
+        i.inverse_transform_points_mut(&mut in_place);
+
+        for ((p, o), ip) in ps.iter().zip(&out).zip(&in_place) {
+            prop_assert!(relative_eq!(i.inverse_transform_point(p), *o, epsilon = 1.0e-7));
+            prop_assert_eq!(o, ip);
+        }
+
+        // Going back and forth recovers the original points.
+        i.transform_points_mut(&mut in_place);
+
+        for (p, ip) in ps.iter().zip(&in_place) {
+            prop_assert!(relative_eq!(p, ip, epsilon = 1.0e-7));
+        }
+    }
+
+    #[test]
+    fn inverse_transform_vectors_matches_per_vector(i in isometry3(), vs in proptest::collection::vec(vector3(), 0..10)) {
+        let mut out = vec![Vector3::zeros(); vs.len()];
+        i.inverse_transform_vectors(&vs, &mut out);
+
+        for (v, o) in vs.iter().zip(&out) {
+            prop_assert!(relative_eq!(i.inverse_transform_vector(v), *o, epsilon = 1.0e-7));
+            prop_assert!(relative_eq!(i * o, *v, epsilon = 1.0e-7));
+        }
+    }
+
+    #[test]
+    fn inverse_transform_roundtrip2(i in isometry2(), v in vector2(), p in point2()) {
+        prop_assert!(relative_eq!(i.inverse() * v, i.inverse_transform_vector(&v), epsilon = 1.0e-7)
+            && relative_eq!(i.inverse() * p, i.inverse_transform_point(&p), epsilon = 1.0e-7)
+            && relative_eq!(i.inverse_transform_point(&(i * p)), p, epsilon = 1.0e-7)
+            && relative_eq!(i.inverse_transform_vector(&(i * v)), v, epsilon = 1.0e-7))
+    }
+
     #[test]
     #[cfg_attr(rustfmt, rustfmt_skip)]
     fn composition2(
@@ -273,4 +342,82 @@ proptest!(
             && uqMt == uq * &t
             && uqMt == &uq * t)
     }
+
+    #[test]
+    fn fill_homogeneous3(i in isometry3()) {
+        // The previous content of the output matrix must be overwritten entirely.
+        let mut out = na::Matrix4::repeat(42.0);
+        i.fill_homogeneous(&mut out);
+        prop_assert!(relative_eq!(out, i.to_homogeneous(), epsilon = 1.0e-7));
+    }
+
+    #[test]
+    fn fill_homogeneous2(i in isometry2()) {
+        let mut out = na::Matrix3::zeros();
+        i.fill_homogeneous(&mut out);
+        prop_assert!(relative_eq!(out, i.to_homogeneous(), epsilon = 1.0e-7));
+    }
+
+    #[test]
+    fn try_interpolate_2(a in isometry2(), b in isometry2(), t in 0.0..1.0f64) {
+        let i = a.try_interpolate(&b, t, 1.0e-7).unwrap();
+        prop_assert!(relative_eq!(i, a.lerp_slerp(&b, t), epsilon = 1.0e-7));
+
+        let ma = IsometryMatrix2::from_parts(a.translation, a.rotation.to_rotation_matrix());
+        let mb = IsometryMatrix2::from_parts(b.translation, b.rotation.to_rotation_matrix());
+        let mi = ma.try_interpolate(&mb, t, 1.0e-7).unwrap();
+        prop_assert!(relative_eq!(mi.to_homogeneous(), i.to_homogeneous(), epsilon = 1.0e-7));
+    }
+
+    #[test]
+    fn try_interpolate_3(a in isometry3(), b in isometry3(), t in 0.0..1.0f64) {
+        if let Some(i) = a.try_interpolate(&b, t, 1.0e-7) {
+            prop_assert!(relative_eq!(i, a.lerp_slerp(&b, t), epsilon = 1.0e-7));
+
+            let ma = IsometryMatrix3::from_parts(a.translation, a.rotation.to_rotation_matrix());
+            let mb = IsometryMatrix3::from_parts(b.translation, b.rotation.to_rotation_matrix());
+            let mi = ma.try_interpolate(&mb, t, 1.0e-7).unwrap();
+            prop_assert!(relative_eq!(mi.to_homogeneous(), i.to_homogeneous(), epsilon = 1.0e-7));
+        }
+    }
 );
+
+#[test]
+#[should_panic]
+fn transform_points_length_mismatch() {
+    let points = [Point3::new(1.0, 2.0, 3.0); 3];
+    let mut out = [Point3::origin(); 2];
+    Isometry3::identity().transform_points(&points, &mut out);
+}
+
+#[test]
+#[should_panic]
+fn transform_vectors_length_mismatch() {
+    let vectors = [Vector3::new(1.0, 2.0, 3.0); 2];
+    let mut out = [Vector3::zeros(); 3];
+    Isometry3::identity().transform_vectors(&vectors, &mut out);
+}
+
+#[test]
+fn try_interpolate_half_turn() {
+    let a = Isometry3::identity();
+    let b = Isometry3::from_parts(
+        Translation3::new(2.0, 0.0, 0.0),
+        UnitQuaternion::from_axis_angle(&Vector3::z_axis(), std::f64::consts::PI),
+    );
+    let i = a.try_interpolate(&b, 0.5, 1.0e-7).unwrap();
+    assert_relative_eq!(i.translation.vector, Vector3::new(1.0, 0.0, 0.0));
+    assert_relative_eq!(i.rotation.angle(), std::f64::consts::FRAC_PI_2);
+
+    let ma = IsometryMatrix3::identity();
+    let mb = IsometryMatrix3::from_parts(b.translation, b.rotation.to_rotation_matrix());
+    let mi = ma.try_interpolate(&mb, 0.5, 1.0e-7).unwrap();
+    assert_relative_eq!(mi.to_homogeneous(), i.to_homogeneous(), epsilon = 1.0e-7);
+
+    let b2 = Isometry2::new(na::Vector2::new(2.0, 0.0), std::f64::consts::PI);
+    let i2 = Isometry2::identity()
+        .try_interpolate(&b2, 0.5, 1.0e-7)
+        .unwrap();
+    assert_relative_eq!(i2.translation.vector, na::Vector2::new(1.0, 0.0));
+    assert_relative_eq!(i2.rotation.angle().abs(), std::f64::consts::FRAC_PI_2);
+}
@@ -1,5 +1,8 @@
+mod aabb;
+mod bounding_sphere;
 mod dual_quaternion;
 mod isometry;
+mod plane;
 mod point;
 mod projection;
 mod quaternion;
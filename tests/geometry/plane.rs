@@ -0,0 +1,134 @@
+use na::{Plane3, Point3, Unit, Vector3};
+
+#[test]
+fn plane_signed_distance_sign() {
+    let normal = Unit::new_normalize(Vector3::new(1.0, 1.0, 0.0));
+    let plane = Plane3::from_point_normal(&Point3::new(1.0, 0.0, 0.0), normal);
+
+    let front = Point3::new(2.0, 2.0, 7.0);
+    let back = Point3::new(-1.0, 0.0, 3.0);
+    let on = Point3::new(0.0, 1.0, -4.0);
+
+    assert!(plane.signed_distance(&front) > 0.0);
+    assert!(plane.signed_distance(&back) < 0.0);
+    assert_relative_eq!(plane.signed_distance(&on), 0.0, epsilon = 1.0e-12);
+    assert_relative_eq!(
+        plane.signed_distance(&front),
+        3.0 / 2.0f64.sqrt(),
+        epsilon = 1.0e-12
+    );
+
+    // Flipping the normal flips the sign.
+    let flipped = Plane3::new(-normal, -*plane.offset());
+    assert_relative_eq!(
+        flipped.signed_distance(&front),
+        -plane.signed_distance(&front),
+        epsilon = 1.0e-12
+    );
+}
+
+#[test]
+fn plane_from_points_orientation() {
+    let a = Point3::new(1.0, 0.0, 0.0);
+    let b = Point3::new(0.0, 1.0, 0.0);
+    let c = Point3::new(0.0, 0.0, 1.0);
+
+    let plane = Plane3::from_points(&a, &b, &c).unwrap();
+    assert_relative_eq!(
+        plane.normal().into_inner(),
+        Vector3::new(1.0, 1.0, 1.0).normalize(),
+        epsilon = 1.0e-12
+    );
+    assert_relative_eq!(
+        plane.signed_distance(&Point3::origin()),
+        -1.0 / 3.0f64.sqrt()
+    );
+
+    for p in [a, b, c] {
+        assert_relative_eq!(plane.signed_distance(&p), 0.0, epsilon = 1.0e-12);
+    }
+
+    let reversed = Plane3::from_points(&a, &c, &b).unwrap();
+    assert_relative_eq!(
+        reversed.normal().into_inner(),
+        -plane.normal().into_inner(),
+        epsilon = 1.0e-12
+    );
+}
+
+#[test]
+fn plane_from_collinear_points() {
+    let a = Point3::new(1.0, 2.0, 3.0);
+    let b = Point3::new(2.0, 4.0, 6.0);
+    let c = Point3::new(-1.0, -2.0, -3.0);
+
+    assert!(Plane3::from_points(&a, &b, &c).is_none());
+    assert!(Plane3::from_points(&a, &a, &b).is_none());
+    assert!(Plane3::from_points(&a, &a, &a).is_none());
+}
+
+#[test]
+fn plane_from_tiny_points() {
+    // The tolerance is relative to the size of the triangle.
+    let a = Point3::new(1.0, 2.0, 3.0);
+    let b = a + Vector3::new(1.0e-8, 0.0, 0.0);
+    let c = a + Vector3::new(0.0, 1.0e-8, 0.0);
+    let plane = Plane3::from_points(&a, &b, &c).unwrap();
+    assert_relative_eq!(plane.normal(), &Vector3::z_axis());
+
+    // Nearly collinear points are rejected whatever their scale.
+    let d = Point3::new(1.0e8, 1.0e-10, 0.0);
+    let origin = Point3::origin();
+    assert!(Plane3::from_points(&origin, &Point3::new(1.0e8, 0.0, 0.0), &d).is_none());
+}
+
+#[test]
+fn plane_intersect_line() {
+    let plane = Plane3::new(Vector3::y_axis(), -2.0);
+    let origin = Point3::new(1.0, 3.0, 0.5);
+    let dir = Vector3::new(1.0, -1.0, 2.0);
+
+    let t = plane.intersect_line(&origin, &dir).unwrap();
+    assert_relative_eq!(t, 5.0);
+    assert_relative_eq!(plane.signed_distance(&(origin + dir * t)), 0.0);
+
+    // Parallel lines, above and on the plane.
+    assert!(plane.intersect_line(&origin, &Vector3::x()).is_none());
+    assert!(plane
+        .intersect_line(&Point3::new(0.0, -2.0, 0.0), &Vector3::z())
+        .is_none());
+
+    // The tolerance is relative to the norm of the direction.
+    let t = plane.intersect_line(&origin, &(dir * 1.0e-20)).unwrap();
+    assert_relative_eq!(t, 5.0e20);
+    assert!(plane
+        .intersect_line(&origin, &Vector3::new(1.0e10, 1.0e-10, 0.0))
+        .is_none());
+    assert!(plane.intersect_line(&origin, &Vector3::zeros()).is_none());
+}
+
+#[cfg(feature = "proptest-support")]
+mod proptest_tests {
+    use na::{Plane3, Unit};
+
+    use crate::proptest::*;
+    use proptest::{prop_assert, proptest};
+
+    proptest! {
+        #[test]
+        fn project_point_lies_on_plane(p in point3(), q in point3(), n in vector3()) {
+            if let Some(normal) = Unit::try_new(n, 1.0e-5) {
+                let plane = Plane3::from_point_normal(&q, normal);
+                let proj = plane.project_point(&p);
+
+                prop_assert!(relative_eq!(plane.signed_distance(&proj), 0.0, epsilon = 1.0e-7));
+                prop_assert!(relative_eq!(
+                    (p - proj).norm(),
+                    plane.signed_distance(&p).abs(),
+                    epsilon = 1.0e-7
+                ));
+                prop_assert!(relative_eq!(plane.project_point(&proj), proj, epsilon = 1.0e-7));
+            }
+        }
+    }
+}
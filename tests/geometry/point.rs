@@ -1,4 +1,4 @@
-use na::{Point3, Vector3, Vector4};
+use na::{Point1, Point3, Vector3, Vector4};
 use num::Zero;
 
 #[test]
@@ -92,3 +92,101 @@ fn to_homogeneous() {
 
     assert_eq!(a.to_homogeneous(), expected);
 }
+
+#[test]
+fn from_homogeneous() {
+    let v = Vector4::new(2.0, 4.0, 6.0, 2.0);
+    let expected = Point3::new(1.0, 2.0, 3.0);
+
+    assert_eq!(Point3::from_homogeneous(v), Some(expected));
+    assert_eq!(Point3::try_from_homogeneous(v, 1.0e-7), Some(expected));
+    assert_eq!(Point3::try_from_homogeneous(-v, 1.0e-7), Some(expected));
+    assert_eq!(v.xyz(), Vector3::new(2.0, 4.0, 6.0));
+}
+
+#[test]
+fn from_homogeneous_at_infinity() {
+    let v = Vector4::new(1.0, 2.0, 3.0, 0.0);
+    assert!(Point3::from_homogeneous(v).is_none());
+    assert!(Point3::try_from_homogeneous(v, 1.0e-7).is_none());
+
+    let v = Vector4::new(1.0, 2.0, 3.0, -1.0e-10);
+    assert!(Point3::from_homogeneous(v).is_some());
+    assert!(Point3::try_from_homogeneous(v, 1.0e-7).is_none());
+}
+
+#[test]
+fn centroid() {
+    let points = [
+        Point3::new(1.0, 2.0, 3.0),
+        Point3::new(-1.0, 0.0, 5.0),
+        Point3::new(3.0, 4.0, -2.0),
+        Point3::new(1.0, -2.0, 2.0),
+    ];
+
+    assert_eq!(Point3::centroid(&points), Point3::new(1.0, 1.0, 2.0));
+    assert_eq!(Point3::centroid(&points[..1]), points[0]);
+}
+
+#[test]
+fn centroid_weighted() {
+    let points = [Point1::new(1.0), Point1::new(2.0), Point1::new(6.0)];
+
+    assert_eq!(
+        Point1::centroid_weighted(&points, &[1.0, 1.0, 1.0]),
+        Point1::centroid(&points)
+    );
+    assert_eq!(
+        Point1::centroid_weighted(&points, &[2.0, 0.0, 2.0]),
+        Point1::new(3.5)
+    );
+    // Scaling all the weights doesn't change the result.
+    assert_eq!(
+        Point1::centroid_weighted(&points, &[0.5, 1.0, 0.5]),
+        Point1::centroid_weighted(&points, &[1.0, 2.0, 1.0])
+    );
+}
+
+#[test]
+#[should_panic]
+fn centroid_empty() {
+    let _ = Point3::<f64>::centroid(&[]);
+}
+
+#[test]
+#[should_panic]
+fn centroid_weighted_length_mismatch() {
+    let points = [Point3::new(1.0, 2.0, 3.0), Point3::new(4.0, 5.0, 6.0)];
+    let _ = Point3::centroid_weighted(&points, &[1.0]);
+}
+
+#[test]
+fn point_lerp() {
+    let a = Point3::new(1.0, -2.0, 4.0);
+    let b = Point3::new(3.0, 6.0, -4.0);
+
+    assert_eq!(a.lerp(&b, 0.0), a);
+    assert_eq!(a.lerp(&b, 1.0), b);
+    assert_eq!(a.lerp(&b, 0.5), na::center(&a, &b));
+    assert_eq!(a.lerp(&b, 2.0), Point3::new(5.0, 14.0, -12.0));
+}
+
+#[cfg(feature = "proptest-support")]
+mod proptest_tests {
+    use na::Point3;
+
+    use crate::proptest::*;
+    use proptest::{prop_assert, prop_assert_eq, proptest};
+
+    proptest! {
+        #[test]
+        fn homogeneous_roundtrip(p in point3(), w in 0.1f64..10.0) {
+            let h = p.to_homogeneous();
+            prop_assert_eq!(Point3::from_homogeneous(h), Some(p));
+            prop_assert_eq!(Point3::try_from_homogeneous(h, 1.0e-7), Some(p));
+
+            let scaled = Point3::try_from_homogeneous(h * w, 1.0e-7).unwrap();
+            prop_assert!(relative_eq!(scaled, p, epsilon = 1.0e-7));
+        }
+    }
+}
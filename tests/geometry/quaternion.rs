@@ -1,10 +1,10 @@
 #![cfg(feature = "proptest-support")]
 #![allow(non_snake_case)]
 
-use na::{Unit, UnitQuaternion};
+use na::{Quaternion, Unit, UnitQuaternion, Vector3};
 
 use crate::proptest::*;
-use proptest::{prop_assert, proptest};
+use proptest::{prop_assert, prop_assert_eq, proptest};
 
 proptest!(
     /*
@@ -41,6 +41,30 @@ proptest!(
         ))
     }
 
+    #[test]
+    fn euler_angles_recovers_angles(r in -3.1..3.1f64, p in -1.5..1.5f64, y in -3.1..3.1f64) {
+        let (roll, pitch, yaw) = UnitQuaternion::from_euler_angles(r, p, y).euler_angles();
+        prop_assert!(relative_eq!(roll, r, epsilon = 1.0e-7));
+        prop_assert!(relative_eq!(pitch, p, epsilon = 1.0e-7));
+        prop_assert!(relative_eq!(yaw, y, epsilon = 1.0e-7));
+    }
+
+    #[test]
+    fn euler_angles_gimbal_lock(r in PROPTEST_F64, y in PROPTEST_F64) {
+        for p in [std::f64::consts::FRAC_PI_2, -std::f64::consts::FRAC_PI_2] {
+            let rpy = UnitQuaternion::from_euler_angles(r, p, y);
+            let (roll, pitch, yaw) = rpy.euler_angles();
+
+            prop_assert!(roll.is_finite() && pitch.is_finite() && yaw.is_finite());
+            prop_assert!(relative_eq!(pitch.abs(), std::f64::consts::FRAC_PI_2, epsilon = 1.0e-3));
+            prop_assert!(relative_eq!(
+                UnitQuaternion::from_euler_angles(roll, pitch, yaw),
+                rpy,
+                epsilon = 1.0e-7
+            ));
+        }
+    }
+
     /*
      *
      * From/to rotation matrix.
@@ -55,6 +79,20 @@ proptest!(
         prop_assert!(relative_eq!(q, qq, epsilon = 1.0e-7) && relative_eq!(r, rr, epsilon = 1.0e-7))
     }
 
+    #[test]
+    fn unit_quaternion_from_basis(q in unit_quaternion()) {
+        let basis = [q * Vector3::x(), q * Vector3::y(), q * Vector3::z()];
+        let qq = UnitQuaternion::from_basis_unchecked(&basis);
+
+        prop_assert!(q.angle_to(&qq) < 1.0e-7);
+        prop_assert_eq!(UnitQuaternion::from_basis(&basis, 1.0e-7), Some(qq));
+
+        let mirrored = [basis[0], basis[1], -basis[2]];
+        prop_assert!(UnitQuaternion::from_basis(&mirrored, 1.0e-7).is_none());
+        let skewed = [basis[0], basis[1] + basis[0] * 0.1, basis[2]];
+        prop_assert!(UnitQuaternion::from_basis(&skewed, 1.0e-7).is_none());
+    }
+
     /*
      *
      * Point/Vector transformation.
@@ -100,6 +138,27 @@ proptest!(
             && relative_eq!(q * iq, UnitQuaternion::identity(), epsilon = 1.0e-7))
     }
 
+    /*
+     *
+     * Integer powers.
+     *
+     */
+    #[test]
+    fn quaternion_powu(q in quaternion()) {
+        prop_assert_eq!(q.powu(0), Quaternion::identity());
+        prop_assert_eq!(q.powu(1), q);
+        prop_assert!(relative_eq!(q.powu(3), q * q * q, max_relative = 1.0e-10));
+        prop_assert!(relative_eq!(q.powu(4), (q * q) * (q * q), max_relative = 1.0e-10));
+    }
+
+    #[test]
+    fn unit_quaternion_powi(q in unit_quaternion()) {
+        prop_assert!(relative_eq!(q.powi(-2) * q.powi(2), UnitQuaternion::identity(), epsilon = 1.0e-7));
+        prop_assert!(relative_eq!(q.powi(3), q * q * q, epsilon = 1.0e-7));
+        prop_assert!(relative_eq!(q.powi(-1), q.inverse(), epsilon = 1.0e-7));
+        prop_assert!(relative_eq!(q.powi(5).norm(), 1.0, epsilon = 1.0e-10));
+    }
+
     /*
      *
      * Quaterion * Vector == Rotation * Vector
@@ -264,3 +323,55 @@ proptest!(
             && uqMuv == &uq * uv)
     }
 );
+
+#[test]
+fn try_from_quaternion_degenerate() {
+    let zero = Quaternion::new(0.0, 0.0, 0.0, 0.0);
+    let tiny = Quaternion::new(1.0e-10, -1.0e-10, 0.0, 1.0e-10);
+
+    assert!(UnitQuaternion::try_from_quaternion(zero, 1.0e-7).is_none());
+    assert!(UnitQuaternion::try_from_quaternion(tiny, 1.0e-7).is_none());
+    // The threshold is user-defined.
+    assert!(UnitQuaternion::try_from_quaternion(tiny, 0.0).is_some());
+}
+
+#[test]
+fn try_from_quaternion_normalizes() {
+    let q = Quaternion::new(1.0, 2.0, -3.0, 4.0);
+    let uq = UnitQuaternion::try_from_quaternion(q, 1.0e-7).unwrap();
+
+    assert_relative_eq!(uq.norm(), 1.0, epsilon = 1.0e-10);
+    assert_relative_eq!(uq.into_inner(), q / q.norm(), epsilon = 1.0e-10);
+    assert_eq!(uq, UnitQuaternion::from_quaternion(q));
+}
+
+#[test]
+fn quaternion_display_precision() {
+    let q = Quaternion::new(1.0, 2.12345, -3.0, 0.5);
+
+    assert_eq!(format!("{}", q), "Quaternion 1 − (2.12345, -3, 0.5)");
+    assert_eq!(format!("{:.2}", q), "Quaternion 1.00 − (2.12, -3.00, 0.50)");
+    assert_eq!(
+        format!("{:6.1}", q),
+        "Quaternion    1.0 − (   2.1,   -3.0,    0.5)"
+    );
+}
+
+#[test]
+fn unit_quaternion_display() {
+    let axis = na::Vector3::x_axis();
+    let q = UnitQuaternion::from_axis_angle(&axis, std::f64::consts::FRAC_PI_2);
+
+    assert_eq!(
+        format!("{:.3}", q),
+        "UnitQuaternion angle: 1.571 − axis: (1.000, 0.000, 0.000)"
+    );
+    assert_eq!(
+        format!("{:#.3}", q),
+        "UnitQuaternion angle: 1.571 − axis: (1.000, 0.000, 0.000)"
+    );
+    assert_eq!(
+        format!("{:#.1}", UnitQuaternion::<f64>::identity()),
+        "UnitQuaternion angle: 0.0 − axis: (undefined)"
+    );
+}
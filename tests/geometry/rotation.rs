@@ -19,6 +19,68 @@ fn angle_3() {
     assert_eq!(a.angle(&b), 0.0);
 }
 
+#[test]
+fn angle_near_collinear() {
+    let a = Vector3::new(1.0, 0.0, 0.0);
+    let b = Vector3::new(1.0, 1.0e-9, 0.0);
+    let c = Vector3::new(-1.0, 1.0e-9, 0.0);
+
+    assert_relative_eq!(a.angle(&b), 1.0e-9, max_relative = 1.0e-6);
+    assert_relative_eq!(a.angle(&c), PI - 1.0e-9, epsilon = 1.0e-15);
+    assert_eq!(a.angle(&Vector3::zeros()), 0.0);
+}
+
+#[test]
+fn angle_signed_2() {
+    let a = Vector2::new(2.0, 0.0);
+    let b = Vector2::new(-1.0, -1.0);
+
+    assert_relative_eq!(a.angle_signed(&b), -3.0 * PI / 4.0);
+    assert_relative_eq!(b.angle_signed(&a), 3.0 * PI / 4.0);
+    assert_relative_eq!(a.angle_signed(&b).abs(), a.angle(&b));
+}
+
+#[test]
+fn angle_signed_2_quadrants() {
+    // Directions at multiples of π/4, with various magnitudes.
+    let dir = |k: i32, len: f64| {
+        Vector2::new(
+            len * (k as f64 * PI / 4.0).cos(),
+            len * (k as f64 * PI / 4.0).sin(),
+        )
+    };
+
+    for i in 0..8 {
+        for j in 0..8 {
+            let a = dir(i, 2.0);
+            let b = dir(j, 0.5);
+            let mut expected = (j - i) as f64 * PI / 4.0;
+
+            if expected > PI {
+                expected -= 2.0 * PI;
+            } else if expected <= -PI {
+                expected += 2.0 * PI;
+            }
+
+            let angle = a.angle_signed(&b);
+            assert!(angle > -PI && angle <= PI);
+            assert_relative_eq!(angle, expected, epsilon = 1.0e-10);
+            assert_relative_eq!(angle.abs(), a.angle(&b), epsilon = 1.0e-10);
+        }
+    }
+}
+
+#[test]
+fn angle_signed_about_3() {
+    let a = Vector3::new(1.0, 0.0, 0.0);
+    let b = Vector3::new(-1.0, 1.0, 0.0);
+    let axis = Vector3::new(0.0, 0.0, 5.0);
+
+    assert_relative_eq!(a.angle_signed_about(&b, &axis), 3.0 * PI / 4.0);
+    assert_relative_eq!(b.angle_signed_about(&a, &axis), -3.0 * PI / 4.0);
+    assert_relative_eq!(a.angle_signed_about(&b, &-axis), -3.0 * PI / 4.0);
+}
+
 #[test]
 fn from_rotation_matrix() {
     // Test degenerate case when from_matrix gets stuck in Identity rotation
@@ -85,17 +147,63 @@ fn quaternion_euler_angles_issue_494() {
     assert_eq!(angs.2, 0.0);
 }
 
+#[test]
+fn powf_half_turn_3() {
+    let axes = [
+        Vector3::x_axis(),
+        Vector3::y_axis(),
+        Vector3::z_axis(),
+        UnitVector3::new_normalize(Vector3::new(1.0, -2.0, 3.0)),
+    ];
+
+    for axis in axes.iter() {
+        let half_turn = Rotation3::from_axis_angle(axis, PI);
+        let quarter_turn = half_turn.powf(0.5);
+
+        assert_relative_eq!(quarter_turn.matrix().determinant(), 1.0, epsilon = 1.0e-7);
+        assert_relative_eq!(quarter_turn.angle(), PI / 2.0, epsilon = 1.0e-7);
+        assert_relative_eq!(quarter_turn.powf(2.0), half_turn, epsilon = 1.0e-7);
+        assert_relative_eq!(half_turn.powf(0.0), Rotation3::identity(), epsilon = 1.0e-7);
+    }
+
+    assert_eq!(
+        Rotation3::<f64>::identity().powf(0.5),
+        Rotation3::identity()
+    );
+}
+
 #[cfg(feature = "proptest-support")]
 mod proptest_tests {
     use approx::AbsDiffEq;
-    use na::{self, Rotation2, Rotation3, Unit};
-    use na::{UnitComplex, UnitQuaternion};
+    use na::{self, Rotation2, Rotation3, Unit, Vector1, Vector3};
+    use na::{EulerAxes, EulerOrder, UnitComplex, UnitQuaternion};
     use simba::scalar::RealField;
     use std::f64;
 
     use crate::proptest::*;
     use proptest::{prop_assert, prop_assert_eq, proptest};
 
+    const EULER_AXES: [EulerAxes; 12] = [
+        EulerAxes::XYZ,
+        EulerAxes::XZY,
+        EulerAxes::YXZ,
+        EulerAxes::YZX,
+        EulerAxes::ZXY,
+        EulerAxes::ZYX,
+        EulerAxes::XYX,
+        EulerAxes::XZX,
+        EulerAxes::YXY,
+        EulerAxes::YZY,
+        EulerAxes::ZXZ,
+        EulerAxes::ZYZ,
+    ];
+
+    fn euler_orders() -> impl Iterator<Item = EulerOrder> {
+        EULER_AXES
+            .iter()
+            .flat_map(|axes| [EulerOrder::Intrinsic(*axes), EulerOrder::Extrinsic(*axes)])
+    }
+
     proptest! {
         /*
          *
@@ -133,6 +241,64 @@ mod proptest_tests {
             prop_assert!(relative_eq!(Rotation3::from_euler_angles(neg_r, neg_p, neg_y), neg, epsilon = 1.0e-7));
         }
 
+        #[test]
+        fn from_euler_angles_ordered(a1 in PROPTEST_F64, a2 in PROPTEST_F64, a3 in PROPTEST_F64) {
+            for order in euler_orders() {
+                let (axes, extrinsic) = match order {
+                    EulerOrder::Intrinsic(axes) => (axes, false),
+                    EulerOrder::Extrinsic(axes) => (axes, true),
+                };
+                let [e1, e2, e3] = axes.indices();
+                let r1 = Rotation3::from_axis_angle(&Vector3::ith_axis(e1), a1);
+                let r2 = Rotation3::from_axis_angle(&Vector3::ith_axis(e2), a2);
+                let r3 = Rotation3::from_axis_angle(&Vector3::ith_axis(e3), a3);
+                let expected = if extrinsic { r3 * r2 * r1 } else { r1 * r2 * r3 };
+
+                let rot = Rotation3::from_euler_angles_ordered(a1, a2, a3, order);
+                prop_assert!(relative_eq!(rot, expected, epsilon = 1.0e-7));
+            }
+
+            let rpy = Rotation3::from_euler_angles(a1, a2, a3);
+            let extrinsic_xyz = EulerOrder::Extrinsic(EulerAxes::XYZ);
+            let intrinsic_zyx = EulerOrder::Intrinsic(EulerAxes::ZYX);
+            prop_assert!(relative_eq!(Rotation3::from_euler_angles_ordered(a1, a2, a3, extrinsic_xyz), rpy, epsilon = 1.0e-7));
+            prop_assert!(relative_eq!(Rotation3::from_euler_angles_ordered(a3, a2, a1, intrinsic_zyx), rpy, epsilon = 1.0e-7));
+        }
+
+        #[test]
+        fn euler_angles_ordered(r in rotation3()) {
+            for order in euler_orders() {
+                let (a1, a2, a3) = r.euler_angles_ordered(order);
+                prop_assert!(relative_eq!(Rotation3::from_euler_angles_ordered(a1, a2, a3, order), r, epsilon = 1.0e-7));
+            }
+
+            let (roll, pitch, yaw) = r.euler_angles();
+            let (a1, a2, a3) = r.euler_angles_ordered(EulerOrder::Extrinsic(EulerAxes::XYZ));
+            prop_assert!(relative_eq!(a1, roll, epsilon = 1.0e-7));
+            prop_assert!(relative_eq!(a2, pitch, epsilon = 1.0e-7));
+            prop_assert!(relative_eq!(a3, yaw, epsilon = 1.0e-7));
+        }
+
+        #[test]
+        fn euler_angles_ordered_gimble_lock(a1 in PROPTEST_F64, a3 in PROPTEST_F64) {
+            for order in euler_orders() {
+                let axes = match order {
+                    EulerOrder::Intrinsic(axes) | EulerOrder::Extrinsic(axes) => axes,
+                };
+                let locks = if axes.is_proper_euler() {
+                    [0.0, f64::pi()]
+                } else {
+                    [f64::frac_pi_2(), -f64::frac_pi_2()]
+                };
+
+                for a2 in locks {
+                    let rot = Rotation3::from_euler_angles_ordered(a1, a2, a3, order);
+                    let (b1, b2, b3) = rot.euler_angles_ordered(order);
+                    prop_assert!(relative_eq!(Rotation3::from_euler_angles_ordered(b1, b2, b3, order), rot, epsilon = 1.0e-7));
+                }
+            }
+        }
+
         /*
          *
          * Inversion is transposition.
@@ -177,6 +343,82 @@ mod proptest_tests {
             prop_assert_eq!(a.angle(&b), b.angle(&a))
         }
 
+        #[test]
+        fn angle_signed_is_antisymmetric_2(a in vector2(), b in vector2()) {
+            prop_assert!(relative_eq!(a.angle_signed(&b).abs(), a.angle(&b), epsilon = 1.0e-7));
+
+            if a.perp(&b) != 0.0 && a.angle_signed(&b).abs() < f64::pi() {
+                prop_assert_eq!(a.angle_signed(&b), -b.angle_signed(&a));
+            }
+        }
+
+        #[test]
+        fn angle_matches_naive_formula_3(a in vector3(), b in vector3()) {
+            let cos = a.dot(&b) / (a.norm() * b.norm());
+
+            // The naive formula is only well-conditioned away from collinear vectors.
+            if cos.abs() < 0.9 {
+                prop_assert!(relative_eq!(a.angle(&b), cos.acos(), epsilon = 1.0e-7));
+            }
+        }
+
+        #[test]
+        fn from_basis_recovers_rotation_3(r in rotation3()) {
+            let m = r.matrix();
+            let basis = [m.column(0).into_owned(), m.column(1).into_owned(), m.column(2).into_owned()];
+            prop_assert_eq!(Rotation3::from_basis(&basis, 1.0e-7), Some(Rotation3::from_basis_unchecked(&basis)));
+            prop_assert!(relative_eq!(Rotation3::from_basis(&basis, 1.0e-7).unwrap(), r, epsilon = 1.0e-7));
+
+            let mirrored = [basis[0], basis[1], -basis[2]];
+            prop_assert!(Rotation3::from_basis(&mirrored, 1.0e-7).is_none());
+            let scaled = [basis[0] * 2.0, basis[1], basis[2]];
+            prop_assert!(Rotation3::from_basis(&scaled, 1.0e-7).is_none());
+        }
+
+        #[test]
+        fn exp_log_roundtrip_3(r in rotation3(), v in vector3()) {
+            prop_assert!(relative_eq!(Rotation3::exp(r.log()), r, epsilon = 1.0e-7));
+
+            if v.norm() < 3.0 {
+                prop_assert!(relative_eq!(Rotation3::exp(v).log(), v, epsilon = 1.0e-7));
+                prop_assert!(relative_eq!(
+                    Rotation3::exp(v),
+                    UnitQuaternion::new(v).to_rotation_matrix(),
+                    epsilon = 1.0e-7
+                ));
+            }
+        }
+
+        #[test]
+        fn exp_log_roundtrip_2(a in -3.0f64..3.0) {
+            let tangent = Vector1::new(a);
+            prop_assert!(relative_eq!(Rotation2::exp(tangent).log(), tangent, epsilon = 1.0e-7));
+        }
+
+        #[test]
+        fn angle_signed_matches_rotation_2(a in vector2(), b in vector2()) {
+            if a.norm() > 1.0e-5 && b.norm() > 1.0e-5 {
+                let angle = a.angle_signed(&b);
+                let rotated = Rotation2::new(angle) * a;
+                prop_assert!(relative_eq!(a.angle(&b), angle.abs(), epsilon = 1.0e-7));
+                prop_assert!(relative_eq!(rotated.angle(&b), 0.0, epsilon = 1.0e-6));
+            }
+        }
+
+        #[test]
+        fn angle_signed_about_magnitude_is_angle(a in vector3(), b in vector3(), axis in vector3()) {
+            prop_assert!(relative_eq!(
+                a.angle_signed_about(&b, &axis).abs(),
+                b.angle_signed_about(&a, &axis).abs(),
+                epsilon = 1.0e-7
+            ));
+            prop_assert!(relative_eq!(
+                a.angle_signed_about(&b, &axis).abs(),
+                a.angle(&b),
+                epsilon = 1.0e-7
+            ));
+        }
+
         /*
          *
          * Rotation matrix between vectors.
@@ -287,6 +529,17 @@ mod proptest_tests {
             }
         }
 
+        #[test]
+        fn powf_half_squared_rotation_2(angle in PROPTEST_F64) {
+            let r = Rotation2::new(angle);
+            prop_assert!(relative_eq!(r.powf(0.5).powf(2.0), r, epsilon = 1.0e-7));
+        }
+
+        #[test]
+        fn powf_half_squared_rotation_3(r in rotation3()) {
+            prop_assert!(relative_eq!(r.powf(0.5).powf(2.0), r, epsilon = 1.0e-7));
+        }
+
         //
         //In general, `slerp(a,b,t)` should equal `(b/a)^t * a` even though in practice,
         //we may not use that formula directly for complex numbers or quaternions
@@ -53,6 +53,14 @@ proptest!(
             ))
     }
 
+    #[test]
+    fn inverse_transform_roundtrip(s in similarity3(), v in vector3(), p in point3()) {
+        prop_assert!(relative_eq!(s.inverse_transform_point(&(s * p)), p, epsilon = 1.0e-7)
+            && relative_eq!(s.inverse_transform_vector(&(s * v)), v, epsilon = 1.0e-7)
+            && relative_eq!(s * s.inverse_transform_point(&p), p, epsilon = 1.0e-7)
+            && relative_eq!(s * s.inverse_transform_vector(&v), v, epsilon = 1.0e-7))
+    }
+
     #[test]
     #[cfg_attr(rustfmt, rustfmt_skip)]
     fn composition(
@@ -277,4 +285,12 @@ proptest!(
             && iDs == i / &s
             && iDs == &i / s)
     }
+
+    #[test]
+    fn fill_homogeneous3(s in similarity3()) {
+        // The previous content of the output matrix must be overwritten entirely.
+        let mut out = na::Matrix4::repeat(42.0);
+        s.fill_homogeneous(&mut out);
+        prop_assert!(relative_eq!(out, s.to_homogeneous(), epsilon = 1.0e-7));
+    }
 );
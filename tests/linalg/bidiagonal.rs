@@ -6,8 +6,10 @@ macro_rules! gen_tests(
             #[allow(unused_imports)]
             use crate::core::helper::{RandScalar, RandComplex};
 
+            use na::{ComplexField, DMatrix};
+
             use crate::proptest::*;
-            use proptest::{prop_assert, proptest};
+            use proptest::{prop_assert, prop_assert_eq, proptest};
 
             proptest! {
                 #[test]
@@ -18,6 +20,40 @@ macro_rules! gen_tests(
                     prop_assert!(relative_eq!(m, &u * d * &v_t, epsilon = 1.0e-7))
                 }
 
+                #[test]
+                fn bidiagonal_structure(m in dmatrix_($scalar)) {
+                    let (nrows, ncols) = m.shape();
+                    let dim = nrows.min(ncols);
+                    let bidiagonal = m.bidiagonalize();
+                    let upper = bidiagonal.is_upper_diagonal();
+                    prop_assert_eq!(upper, nrows >= ncols);
+
+                    let diagonal = bidiagonal.diagonal();
+                    let off_diagonal = bidiagonal.off_diagonal();
+                    let (u, d, v_t) = bidiagonal.unpack();
+
+                    for i in 0..dim {
+                        for j in 0..dim {
+                            let expected = if i == j {
+                                diagonal[i]
+                            } else if upper && j == i + 1 {
+                                off_diagonal[i]
+                            } else if !upper && i == j + 1 {
+                                off_diagonal[j]
+                            } else {
+                                0.0
+                            };
+
+                            prop_assert_eq!(d[(i, j)].clone().real(), expected);
+                            prop_assert_eq!(d[(i, j)].clone().imaginary(), 0.0);
+                        }
+                    }
+
+                    let id = DMatrix::identity(dim, dim);
+                    prop_assert!(relative_eq!(u.adjoint() * &u, id, epsilon = 1.0e-7));
+                    prop_assert!(relative_eq!(&v_t * v_t.adjoint(), id, epsilon = 1.0e-7));
+                }
+
                 #[test]
                 fn bidiagonal_static_5_3(m in matrix5x3_($scalar)) {
                     let bidiagonal = m.bidiagonalize();
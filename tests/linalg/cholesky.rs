@@ -57,6 +57,23 @@ macro_rules! gen_tests(
                     prop_assert!(relative_eq!(&m * &sol2, b2, epsilon = 1.0e-7));
                 }
 
+                #[test]
+                fn cholesky_solve_mut(n in PROPTEST_MATRIX_DIM, nb in PROPTEST_MATRIX_DIM) {
+                    let m = RandomSDP::new(Dynamic::new(n), || random::<$scalar>().0).unwrap();
+                    let chol = m.clone().cholesky().unwrap();
+
+                    // Reuse the same right-hand-side storage for several solves.
+                    let mut x = DMatrix::<$scalar>::new_random(n, nb).map(|e| e.0);
+                    for _ in 0..3 {
+                        let b = DMatrix::<$scalar>::new_random(n, nb).map(|e| e.0);
+                        x.copy_from(&b);
+                        chol.solve_mut(&mut x);
+
+                        prop_assert!(relative_eq!(&m * &x, b, epsilon = 1.0e-7));
+                        prop_assert!(relative_eq!(x, chol.solve(&b), epsilon = 1.0e-7));
+                    }
+                }
+
                 #[test]
                 fn cholesky_solve_static(_n in PROPTEST_MATRIX_DIM) {
                     let m = RandomSDP::new(Const::<4>, || random::<$scalar>().0).unwrap();
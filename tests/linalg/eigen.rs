@@ -24,6 +24,24 @@ mod proptest_tests {
                         prop_assert!(relative_eq!(m.lower_triangle(), recomp.lower_triangle(), epsilon = 1.0e-5))
                     }
 
+                    #[test]
+                    fn symmetric_eigen_sorted(n in PROPTEST_MATRIX_DIM) {
+                        let n      = cmp::max(1, cmp::min(n, 10));
+                        let m      = DMatrix::<$scalar_type>::new_random(n, n).map(|e| e.0).hermitian_part();
+                        let eig    = m.clone().symmetric_eigen();
+                        let asc    = eig.clone().sorted(true);
+                        let desc   = eig.sorted(false);
+
+                        for i in 1..n {
+                            prop_assert!(asc.eigenvalues[i - 1] <= asc.eigenvalues[i]);
+                            prop_assert!(desc.eigenvalues[i - 1] >= desc.eigenvalues[i]);
+                        }
+
+                        // The recomposition only succeeds if eigenvalues and eigenvectors are still paired.
+                        prop_assert!(relative_eq!(m.lower_triangle(), asc.recompose().lower_triangle(), epsilon = 1.0e-5));
+                        prop_assert!(relative_eq!(m.lower_triangle(), desc.recompose().lower_triangle(), epsilon = 1.0e-5));
+                    }
+
                     #[test]
                     fn symmetric_eigen_singular(n in PROPTEST_MATRIX_DIM) {
                         let n      = cmp::max(1, cmp::min(n, 10));
@@ -171,5 +171,23 @@ mod tests {
 
             assert!((m.exp() - res).norm() < 1e-07);
         }
+
+        {
+            // The exponential of an anti-hermitian matrix is unitary.
+            let h = nalgebra::Matrix3::new(
+                Complex::new(2.0, 0.0),
+                Complex::new(1.0, -1.0),
+                Complex::new(0.0, 0.5),
+                Complex::new(1.0, 1.0),
+                Complex::new(-1.0, 0.0),
+                Complex::new(3.0, 2.0),
+                Complex::new(0.0, -0.5),
+                Complex::new(3.0, -2.0),
+                Complex::new(0.5, 0.0),
+            );
+            let u = (h * Complex::new(0.0, -1.0)).exp();
+
+            assert!((u.adjoint() * u - nalgebra::Matrix3::identity()).norm() < 1e-07);
+        }
     }
 }
@@ -1,4 +1,4 @@
-use na::Matrix3;
+use na::{Matrix2x3, Matrix3, Vector3};
 
 #[test]
 #[rustfmt::skip]
@@ -39,6 +39,53 @@ fn full_piv_lu_simple_with_pivot() {
     assert!(relative_eq!(m, lu, epsilon = 1.0e-7));
 }
 
+#[test]
+#[rustfmt::skip]
+fn full_piv_lu_singular_rank_and_solve() {
+    // The third row is the sum of the first two.
+    let m = Matrix3::new(
+        1.0, 2.0, 3.0,
+        4.0, 5.0, 6.0,
+        5.0, 7.0, 9.0);
+
+    let lu = m.full_piv_lu();
+    assert_eq!(lu.rank(1.0e-12), 2);
+
+    let b = Vector3::new(1.0, 2.0, 3.0);
+    let x = lu.solve_particular(&b, 1.0e-12).unwrap();
+    assert!(relative_eq!(m * x, b, epsilon = 1.0e-12));
+
+    // Inconsistent system.
+    assert!(lu.solve_particular(&Vector3::new(1.0, 2.0, 4.0), 1.0e-12).is_none());
+}
+
+#[test]
+#[rustfmt::skip]
+fn full_piv_lu_near_singular_rank() {
+    let m = Matrix3::new(
+        1.0, 2.0, 3.0,
+        4.0, 5.0, 6.0,
+        5.0, 7.0, 9.0 + 1.0e-10);
+
+    let lu = m.full_piv_lu();
+    assert!(lu.is_invertible());
+    assert_eq!(lu.rank(0.0), 3);
+    assert_eq!(lu.rank(1.0e-8), 2);
+
+    let b = Vector3::new(1.0, 2.0, 3.0);
+    assert!(relative_eq!(lu.solve_particular(&b, 0.0).unwrap(), lu.solve(&b).unwrap()));
+    let x = lu.solve_particular(&b, 1.0e-8).unwrap();
+    assert!(relative_eq!(m * x, b, epsilon = 1.0e-8));
+}
+
+#[test]
+fn full_piv_lu_rank_rectangular() {
+    let m = Matrix2x3::new(1.0, 2.0, 3.0, 2.0, 4.0, 6.0);
+    assert_eq!(m.full_piv_lu().rank(1.0e-12), 1);
+    assert_eq!(m.transpose().full_piv_lu().rank(1.0e-12), 1);
+    assert_eq!(Matrix2x3::<f64>::zeros().full_piv_lu().rank(0.0), 0);
+}
+
 #[cfg(feature = "arbitrary")]
 mod proptest_tests {
     macro_rules! gen_tests(
@@ -113,6 +160,22 @@ mod proptest_tests {
                         prop_assert!(sol2.is_none() || relative_eq!(&m * sol2.unwrap(), b2, epsilon = 1.0e-6));
                     }
 
+                    #[test]
+                    fn full_piv_lu_solve_particular(n in PROPTEST_MATRIX_DIM, nb in PROPTEST_MATRIX_DIM) {
+                        // A matrix of rank at most `n / 2` and a right-hand-side in its range.
+                        let k = n / 2;
+                        let a = DMatrix::<$scalar_type>::new_random(n, k).map(|e| e.0);
+                        let m = &a * DMatrix::<$scalar_type>::new_random(k, n).map(|e| e.0);
+                        let b = &m * DMatrix::<$scalar_type>::new_random(n, nb).map(|e| e.0);
+
+                        let lu = m.clone().full_piv_lu();
+                        prop_assert!(lu.rank(1.0e-7) <= k);
+
+                        let sol = lu.solve_particular(&b, 1.0e-7);
+                        prop_assert!(sol.is_some());
+                        prop_assert!(relative_eq!(&m * sol.unwrap(), b, epsilon = 1.0e-6));
+                    }
+
                     #[test]
                     fn full_piv_lu_solve_static(m in matrix4_($scalar)) {
                          let lu = m.full_piv_lu();
@@ -0,0 +1,100 @@
+use na::givens::GivensRotation;
+use na::{Matrix4x3, Vector2};
+
+#[test]
+fn givens_cancel_y_preserves_norm() {
+    let v = Vector2::new(3.0, -4.0);
+    let (rot, r) = GivensRotation::cancel_y(&v).unwrap();
+    let mut w = v;
+    rot.rotate(&mut w);
+
+    assert_relative_eq!(w, Vector2::new(r, 0.0), epsilon = 1.0e-10);
+    assert_relative_eq!(w.norm(), v.norm(), epsilon = 1.0e-10);
+}
+
+#[test]
+fn givens_cancel_y_does_not_overflow() {
+    let v = Vector2::new(1.0e300f64, 1.0e300);
+    let (rot, r) = GivensRotation::cancel_y(&v).unwrap();
+
+    assert!(r.is_finite());
+    assert_relative_eq!(r, 2.0f64.sqrt() * 1.0e300, max_relative = 1.0e-10);
+    assert_relative_eq!(rot.c(), 0.5f64.sqrt(), epsilon = 1.0e-10);
+}
+
+#[test]
+#[rustfmt::skip]
+fn givens_apply_left_zeros_entry() {
+    let mut m = Matrix4x3::new(
+        1.0, 2.0, 3.0,
+        4.0, 5.0, 6.0,
+        7.0, 8.0, 9.0,
+        1.0, 0.0, 1.0);
+    let original = m;
+    let (rot, r) = GivensRotation::cancel_y(&Vector2::new(m[(0, 0)], m[(2, 0)])).unwrap();
+    rot.apply_left(&mut m, 0, 2);
+
+    assert_relative_eq!(m[(0, 0)], r, epsilon = 1.0e-10);
+    assert_relative_eq!(m[(2, 0)], 0.0, epsilon = 1.0e-10);
+    assert_eq!(m.row(1), original.row(1));
+    assert_eq!(m.row(3), original.row(3));
+
+    for j in 0..3 {
+        assert_relative_eq!(m.column(j).norm(), original.column(j).norm(), epsilon = 1.0e-10);
+    }
+}
+
+#[test]
+#[rustfmt::skip]
+fn givens_apply_right_zeros_entry() {
+    let mut m = Matrix4x3::new(
+        1.0, 2.0, 3.0,
+        4.0, 5.0, 6.0,
+        7.0, 8.0, 9.0,
+        1.0, 0.0, 1.0);
+    let original = m;
+    // Rotating the columns of `m` amounts to rotating the rows of its transpose.
+    let (rot, r) = GivensRotation::cancel_y(&Vector2::new(m[(1, 0)], m[(1, 2)])).unwrap();
+    rot.inverse().apply_right(&mut m, 0, 2);
+
+    assert_relative_eq!(m[(1, 0)], r, epsilon = 1.0e-10);
+    assert_relative_eq!(m[(1, 2)], 0.0, epsilon = 1.0e-10);
+    assert_eq!(m.column(1), original.column(1));
+
+    for i in 0..4 {
+        assert_relative_eq!(m.row(i).norm(), original.row(i).norm(), epsilon = 1.0e-10);
+    }
+}
+
+#[test]
+fn givens_new_cancels_sine() {
+    for &(c, s) in &[
+        (3.0f64, 4.0),
+        (-3.0, 4.0),
+        (0.0, -2.0),
+        (1.0e-3, 1.0e3),
+        (5.0, 0.0),
+    ] {
+        let (rot, r) = GivensRotation::new(c, s);
+        let mut v = Vector2::new(c, s);
+        rot.inverse().rotate(&mut v);
+
+        assert_relative_eq!(
+            rot.c() * rot.c() + rot.s() * rot.s(),
+            1.0,
+            epsilon = 1.0e-10
+        );
+        assert_relative_eq!(r.abs(), Vector2::new(c, s).norm(), epsilon = 1.0e-10);
+        assert_relative_eq!(v, Vector2::new(r, 0.0), epsilon = 1.0e-10);
+    }
+}
+
+#[test]
+fn givens_new_of_zero_is_identity() {
+    let (rot, r) = GivensRotation::<f64>::new(0.0, 0.0);
+
+    assert_eq!((rot.c(), rot.s(), r), (1.0, 0.0, 0.0));
+    assert!(GivensRotation::<f64>::try_new(0.0, 0.0, 0.0).is_none());
+    assert!(GivensRotation::try_new(1.0e-10, -1.0e-10, 1.0e-9).is_none());
+    assert!(GivensRotation::try_new(1.0e-10, -1.0e-10, 0.0).is_some());
+}
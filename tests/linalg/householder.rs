@@ -0,0 +1,113 @@
+use na::{Matrix4, Matrix4x3, Vector4};
+
+#[test]
+fn householder_reflector_zeros_subcolumn() {
+    let x = Vector4::new(1.0f64, -2.0, 3.0, 0.5);
+    let (v, beta) = x.householder();
+    let mut y = x;
+    y.apply_householder_left(&v, beta);
+
+    assert_eq!(v[0], 1.0);
+    assert_relative_eq!(y[0].abs(), x.norm(), epsilon = 1.0e-10);
+    assert_relative_eq!(y.fixed_rows::<3>(1).norm(), 0.0, epsilon = 1.0e-10);
+}
+
+#[test]
+fn householder_of_zero_vector_is_identity() {
+    let (v, beta) = Vector4::<f64>::zeros().householder();
+
+    assert_eq!(beta, 0.0);
+    assert_eq!(v, Vector4::x());
+}
+
+#[test]
+fn householder_clears_matrix_column() {
+    let mut m = Matrix4x3::new(
+        4.0, 1.0, -2.0, 2.0, 2.0, 0.0, -1.0, 3.0, 1.0, 1.0, -1.0, 5.0,
+    );
+    let (v, beta) = m.column(0).householder();
+    m.apply_householder_left(&v, beta);
+
+    assert_relative_eq!(m.fixed_slice::<3, 1>(1, 0).norm(), 0.0, epsilon = 1.0e-10);
+}
+
+#[test]
+fn householder_reflectors_build_qr() {
+    // A textbook QR decomposition built from the public reflectors matches the library's one.
+    let m = Matrix4x3::new(
+        2.0, -1.0, 0.5, 1.0, 3.0, -2.0, -4.0, 0.0, 1.0, 0.5, 2.0, 3.0,
+    );
+    let mut r = m;
+    let mut q = Matrix4::identity();
+
+    for j in 0..3 {
+        let (v, beta) = r.slice_range(j.., j).householder();
+        r.rows_range_mut(j..).apply_householder_left(&v, beta);
+        q.columns_range_mut(j..).apply_householder_right(&v, beta);
+    }
+
+    let expected_r = m.qr().r();
+    let r = r.fixed_rows::<3>(0).into_owned();
+
+    assert_relative_eq!(r.upper_triangle(), r, epsilon = 1.0e-10);
+    assert_relative_eq!(r.map(f64::abs), expected_r.map(f64::abs), epsilon = 1.0e-10);
+    assert_relative_eq!(q.fixed_columns::<3>(0) * r, m, epsilon = 1.0e-10);
+}
+
+#[cfg(feature = "proptest-support")]
+mod proptest_tests {
+    macro_rules! gen_tests(
+        ($module: ident, $scalar: expr, $scalar_type: ty) => {
+            mod $module {
+                use na::{ComplexField, DMatrix, DVector};
+                #[allow(unused_imports)]
+                use crate::core::helper::{RandScalar, RandComplex};
+                use std::cmp;
+
+                use crate::proptest::*;
+                use proptest::{prop_assert, proptest};
+
+                proptest! {
+                    #[test]
+                    fn householder_zeros_subcolumn(n in PROPTEST_MATRIX_DIM) {
+                        let x = DVector::<$scalar_type>::new_random(n).map(|e| e.0);
+                        let (v, beta) = x.householder();
+                        let mut y = x.clone();
+                        y.apply_householder_left(&v, beta);
+
+                        prop_assert!(relative_eq!(y[0].modulus(), x.norm(), epsilon = 1.0e-7));
+                        prop_assert!(relative_eq!(y.rows_range(1..).norm(), 0.0, epsilon = 1.0e-7));
+                    }
+
+                    #[test]
+                    fn householder_is_involution(m in dmatrix_($scalar)) {
+                        let n = cmp::max(1, m.nrows());
+                        let x = DVector::<$scalar_type>::new_random(n).map(|e| e.0);
+                        let (v, beta) = x.householder();
+
+                        if m.nrows() == n {
+                            let mut left = m.clone();
+                            left.apply_householder_left(&v, beta);
+                            left.apply_householder_left(&v, beta);
+                            prop_assert!(relative_eq!(left, m, epsilon = 1.0e-7));
+                        }
+
+                        let y = DVector::<$scalar_type>::new_random(m.ncols()).map(|e| e.0);
+                        let (v, beta) = y.householder();
+                        let mut right = m.clone();
+                        right.apply_householder_right(&v, beta);
+                        right.apply_householder_right(&v, beta);
+                        prop_assert!(relative_eq!(right, m, epsilon = 1.0e-7));
+
+                        let mut id = DMatrix::identity(m.ncols(), m.ncols());
+                        id.apply_householder_left(&v, beta);
+                        prop_assert!(id.is_orthogonal(1.0e-7));
+                    }
+                }
+            }
+        }
+    );
+
+    gen_tests!(complex, complex_f64(), RandComplex<f64>);
+    gen_tests!(f64, PROPTEST_F64, RandScalar<f64>);
+}
@@ -1,4 +1,4 @@
-use na::{Matrix1, Matrix2, Matrix3, Matrix4, Matrix5};
+use na::{DMatrix, Matrix1, Matrix2, Matrix3, Matrix4, Matrix5, Matrix6};
 
 #[test]
 fn matrix1_try_inverse() {
@@ -140,3 +140,86 @@ fn matrix5_try_inverse_scaled_identity() {
 
     assert_relative_eq!(a_inv, expected_inverse);
 }
+
+#[test]
+fn try_inverse_mut_matches_try_inverse() {
+    fn check<M: Clone + std::fmt::Debug + approx::RelativeEq<Epsilon = f64>>(
+        m: M,
+        invert: fn(&mut M) -> bool,
+        inverse: fn(M) -> Option<M>,
+    ) {
+        let mut inv = m.clone();
+        assert!(invert(&mut inv));
+        assert_relative_eq!(inv, inverse(m).unwrap(), epsilon = 1.0e-12);
+    }
+
+    let f = |i: usize, j: usize| {
+        if i == j {
+            4.0
+        } else {
+            (i + 2 * j) as f64 * 0.1
+        }
+    };
+    check(
+        Matrix1::from_fn(f),
+        Matrix1::try_inverse_mut,
+        Matrix1::try_inverse,
+    );
+    check(
+        Matrix2::from_fn(f),
+        Matrix2::try_inverse_mut,
+        Matrix2::try_inverse,
+    );
+    check(
+        Matrix3::from_fn(f),
+        Matrix3::try_inverse_mut,
+        Matrix3::try_inverse,
+    );
+    check(
+        Matrix4::from_fn(f),
+        Matrix4::try_inverse_mut,
+        Matrix4::try_inverse,
+    );
+    check(
+        Matrix5::from_fn(f),
+        Matrix5::try_inverse_mut,
+        Matrix5::try_inverse,
+    );
+    check(
+        Matrix6::from_fn(f),
+        Matrix6::try_inverse_mut,
+        Matrix6::try_inverse,
+    );
+
+    for n in 0..8 {
+        let m = DMatrix::from_fn(n, n, f);
+        let mut inv = m.clone();
+        assert!(inv.try_inverse_mut());
+        assert_relative_eq!(&inv * &m, DMatrix::identity(n, n), epsilon = 1.0e-12);
+        assert_eq!(Some(inv), m.try_inverse());
+    }
+}
+
+#[test]
+fn try_inverse_mut_singular() {
+    let mut zero = Matrix1::new(0.0);
+    assert!(!zero.try_inverse_mut());
+    assert_eq!(zero, Matrix1::new(0.0));
+
+    for n in 2..8 {
+        // The last row is twice the first one.
+        let mut m = DMatrix::from_fn(n, n, |i, j| if i == j { 4.0 } else { (i + 2 * j) as f64 });
+        let first_row = m.row(0) * 2.0;
+        m.row_mut(n - 1).copy_from(&first_row);
+
+        let mut inv = m.clone();
+        assert!(!inv.try_inverse_mut(), "{}x{} matrix inverted", n, n);
+
+        // Small matrices are left untouched.
+        if n <= 3 {
+            assert_eq!(inv, m);
+        }
+
+        assert!(m.try_inverse().is_none());
+    }
+}
@@ -104,6 +104,21 @@ mod proptest_tests {
                         prop_assert!(sol2.is_none() || relative_eq!(&m * sol2.unwrap(), b2, epsilon = 1.0e-6));
                     }
 
+                    #[test]
+                    fn lu_solve_mut(n in PROPTEST_MATRIX_DIM, nb in PROPTEST_MATRIX_DIM) {
+                        let m  = DMatrix::<$scalar_type>::new_random(n, n).map(|e| e.0);
+                        let lu = m.clone().lu();
+                        let b  = DMatrix::<$scalar_type>::new_random(n, nb).map(|e| e.0);
+
+                        let mut x = b.clone();
+                        if lu.solve_mut(&mut x) {
+                            prop_assert!(relative_eq!(&m * &x, b, epsilon = 1.0e-6));
+                            prop_assert!(lu.solve(&b) == Some(x));
+                        } else {
+                            prop_assert!(lu.solve(&b).is_none());
+                        }
+                    }
+
                     #[test]
                     fn lu_solve_static(m in matrix4_($scalar)) {
                          let lu = m.lu();
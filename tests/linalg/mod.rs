@@ -6,11 +6,15 @@ mod convolution;
 mod eigen;
 mod exp;
 mod full_piv_lu;
+mod givens;
 mod hessenberg;
+mod householder;
 mod inverse;
 mod lu;
+mod pivoted_ldl;
 mod pow;
 mod qr;
+mod row_echelon;
 mod schur;
 mod solve;
 mod svd;
@@ -0,0 +1,97 @@
+use na::{DMatrix, Matrix3, Matrix4, Matrix4x2, Vector3, Vector4};
+
+#[test]
+#[rustfmt::skip]
+fn pivoted_ldl_full_rank() {
+    let m = Matrix3::new(
+        4.0, 2.0, -2.0,
+        2.0, 5.0,  1.0,
+       -2.0, 1.0,  6.0);
+
+    let ldl = m.cholesky_pivoted();
+    assert_eq!(ldl.rank(), 3);
+    assert!(ldl.d().iter().all(|d| *d > 0.0));
+
+    let mut pmp = m;
+    ldl.p().permute_rows(&mut pmp);
+    ldl.p().permute_columns(&mut pmp);
+    assert_relative_eq!(pmp, ldl.l() * ldl.d_matrix() * ldl.l().transpose(), epsilon = 1.0e-12);
+
+    let b = Vector3::new(1.0, -2.0, 3.0);
+    assert_relative_eq!(ldl.solve(&b), m.cholesky().unwrap().solve(&b), epsilon = 1.0e-12);
+}
+
+#[test]
+#[rustfmt::skip]
+fn pivoted_ldl_rank_deficient() {
+    // A 4x4 covariance matrix of rank 2.
+    let b = Matrix4x2::new(
+        1.0,  2.0,
+       -1.0,  0.5,
+        3.0, -1.0,
+        0.0,  4.0);
+    let m: Matrix4<f64> = b * b.transpose();
+
+    assert!(m.cholesky().is_none());
+
+    let ldl = m.cholesky_pivoted();
+    assert_eq!(ldl.rank(), 2);
+    assert_eq!(ldl.d()[2], 0.0);
+    assert_eq!(ldl.d()[3], 0.0);
+    assert!(ldl.d()[0] >= ldl.d()[1]);
+
+    let (l, d, p, rank) = ldl.unpack();
+    assert_eq!(rank, 2);
+
+    let mut pmp = m;
+    p.permute_rows(&mut pmp);
+    p.permute_columns(&mut pmp);
+    assert_relative_eq!(pmp, l * Matrix4::from_diagonal(&d) * l.transpose(), epsilon = 1.0e-12);
+
+    // Right-hand sides in the range space are solved exactly.
+    let rhs = m * Vector4::new(1.0, 2.0, -3.0, 0.5);
+    let x = ldl.solve(&rhs);
+    assert_relative_eq!(m * x, rhs, epsilon = 1.0e-10);
+}
+
+#[test]
+fn pivoted_ldl_zero() {
+    let ldl = DMatrix::<f64>::zeros(3, 3).cholesky_pivoted();
+    assert_eq!(ldl.rank(), 0);
+    assert_eq!(ldl.l(), DMatrix::identity(3, 3));
+    assert_eq!(
+        ldl.solve(&DMatrix::from_element(3, 1, 1.0)),
+        DMatrix::zeros(3, 1)
+    );
+}
+
+#[cfg(feature = "proptest-support")]
+mod proptest_tests {
+    use crate::proptest::*;
+    use proptest::{prop_assert, prop_assert_eq, proptest};
+
+    proptest! {
+        #[test]
+        fn pivoted_ldl(m in dmatrix()) {
+            let a = &m * m.transpose();
+            let ldl = a.clone().cholesky_pivoted();
+            prop_assert!(ldl.rank() <= m.nrows().min(m.ncols()));
+
+            let mut pap = a.clone();
+            ldl.p().permute_rows(&mut pap);
+            ldl.p().permute_columns(&mut pap);
+            let l = ldl.l();
+            prop_assert!(relative_eq!(pap, &l * ldl.d_matrix() * l.transpose(), epsilon = 1.0e-7));
+
+            let rhs = &a * m.column_sum();
+            prop_assert!(relative_eq!(&a * ldl.solve(&rhs), rhs, epsilon = 1.0e-6));
+        }
+
+        #[test]
+        fn pivoted_ldl_static(m in matrix4()) {
+            let a = m * m.transpose();
+            let ldl = a.cholesky_pivoted();
+            prop_assert_eq!(ldl.d().iter().filter(|d| **d > 0.0).count(), ldl.rank());
+        }
+    }
+}
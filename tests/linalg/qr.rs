@@ -3,18 +3,18 @@
 macro_rules! gen_tests(
     ($module: ident, $scalar: expr, $scalar_type: ty) => {
         mod $module {
-            use na::{DMatrix, DVector, Matrix4x3, Vector4};
+            use na::{DMatrix, DVector, Matrix4x3, Matrix5x3, Vector4};
             use std::cmp;
             #[allow(unused_imports)]
             use crate::core::helper::{RandScalar, RandComplex};
             use crate::proptest::*;
-            use proptest::{prop_assert, proptest};
+            use proptest::{prop_assert, prop_assert_eq, proptest};
 
             proptest! {
                 #[test]
                 fn qr(m in dmatrix_($scalar)) {
                     let qr = m.clone().qr();
-                    let q  = qr.q();
+                    let q  = qr.q_economy();
                     let r  = qr.r();
 
                     prop_assert!(relative_eq!(m, &q * r, epsilon = 1.0e-7));
@@ -24,7 +24,7 @@ macro_rules! gen_tests(
                 #[test]
                 fn qr_static_5_3(m in matrix5x3_($scalar)) {
                     let qr = m.qr();
-                    let q  = qr.q();
+                    let q  = qr.q_economy();
                     let r  = qr.r();
 
                     prop_assert!(relative_eq!(m, q * r, epsilon = 1.0e-7));
@@ -34,7 +34,7 @@ macro_rules! gen_tests(
                 #[test]
                 fn qr_static_3_5(m in matrix3x5_($scalar)) {
                     let qr = m.qr();
-                    let q  = qr.q();
+                    let q  = qr.q_economy();
                     let r  = qr.r();
 
                     prop_assert!(relative_eq!(m, q * r, epsilon = 1.0e-7));
@@ -44,13 +44,63 @@ macro_rules! gen_tests(
                 #[test]
                 fn qr_static_square(m in matrix4_($scalar)) {
                     let qr = m.qr();
-                    let q  = qr.q();
+                    let q  = qr.q_economy();
                     let r  = qr.r();
 
                     prop_assert!(relative_eq!(m, q * r, epsilon = 1.0e-7));
                     prop_assert!(q.is_orthogonal(1.0e-7));
                 }
 
+                #[test]
+                fn qr_q_tr_mul(m in dmatrix_($scalar), nb in PROPTEST_MATRIX_DIM) {
+                    let qr = m.clone().qr();
+                    let q  = qr.q_economy();
+                    let b  = DMatrix::<$scalar_type>::new_random(m.nrows(), nb).map(|e| e.0);
+
+                    let mut qtb = b.clone();
+                    qr.q_tr_mul(&mut qtb);
+
+                    // The full orthogonal factor preserves the norm.
+                    prop_assert!(relative_eq!(qtb.norm(), b.norm(), epsilon = 1.0e-7));
+                    prop_assert!(relative_eq!(qtb.rows(0, q.ncols()).into_owned(), q.adjoint() * &b, epsilon = 1.0e-7));
+                    prop_assert!(relative_eq!(qtb, qr.q().adjoint() * &b, epsilon = 1.0e-7));
+                }
+
+                #[test]
+                fn qr_full_q(m in dmatrix_($scalar)) {
+                    let qr = m.clone().qr();
+                    let q  = qr.q();
+                    let q_economy = qr.q_economy();
+
+                    prop_assert_eq!(q.shape(), (m.nrows(), m.nrows()));
+                    prop_assert!(q.is_orthogonal(1.0e-7));
+                    prop_assert!(relative_eq!(q.columns(0, q_economy.ncols()).into_owned(), q_economy, epsilon = 1.0e-7));
+                }
+
+                #[test]
+                fn qr_full_q_static_5_3(m in matrix5x3_($scalar)) {
+                    let qr = m.qr();
+                    let q  = qr.q();
+
+                    // `Q * [R; 0]` is the decomposed matrix.
+                    let mut r = Matrix5x3::zeros();
+                    r.fixed_rows_mut::<3>(0).copy_from(&qr.r());
+
+                    prop_assert!(q.is_orthogonal(1.0e-7));
+                    prop_assert!(relative_eq!(m, q * r, epsilon = 1.0e-7));
+                }
+
+                #[test]
+                fn qr_q_tr_mul_static_5_3(m in matrix5x3_($scalar)) {
+                    let qr = m.qr();
+                    let mut qtm = m;
+                    qr.q_tr_mul(&mut qtm);
+
+                    // `Qᵀ * m` is `R` padded with zeros.
+                    prop_assert!(relative_eq!(qtm.fixed_rows::<3>(0).into_owned(), qr.r(), epsilon = 1.0e-7));
+                    prop_assert!(relative_eq!(qtm.fixed_rows::<2>(3).norm(), 0.0, epsilon = 1.0e-7));
+                }
+
                 #[test]
                 fn qr_solve(n in PROPTEST_MATRIX_DIM, nb in PROPTEST_MATRIX_DIM) {
                     let m  = DMatrix::<$scalar_type>::new_random(n, n).map(|e| e.0);
@@ -0,0 +1,108 @@
+use na::{DMatrix, Matrix2x3, Matrix3, Matrix3x2, Matrix4};
+
+#[test]
+#[rustfmt::skip]
+fn reduced_row_echelon_full_rank() {
+    let m = Matrix3::new(2.0,  1.0, -1.0,
+                        -3.0, -1.0,  2.0,
+                        -2.0,  1.0,  2.0);
+    let (rref, pivots) = m.reduced_row_echelon(1.0e-10);
+
+    assert_eq!(pivots, vec![0, 1, 2]);
+    assert_relative_eq!(rref, Matrix3::identity(), epsilon = 1.0e-10);
+}
+
+#[test]
+#[rustfmt::skip]
+fn reduced_row_echelon_rank_deficient() {
+    // The third row is the sum of the first two, and the last column is the sum of the first
+    // two columns.
+    let m = Matrix4::new(1.0, 2.0, 3.0,  3.0,
+                         2.0, 4.0, 7.0,  6.0,
+                         3.0, 6.0, 10.0, 9.0,
+                         0.0, 0.0, 0.0,  0.0);
+    let (rref, pivots) = m.reduced_row_echelon(1.0e-10);
+    let expected = Matrix4::new(1.0, 2.0, 0.0, 3.0,
+                                0.0, 0.0, 1.0, 0.0,
+                                0.0, 0.0, 0.0, 0.0,
+                                0.0, 0.0, 0.0, 0.0);
+
+    assert_eq!(pivots, vec![0, 2]);
+    assert_relative_eq!(rref, expected, epsilon = 1.0e-10);
+
+    let (echelon, pivots) = m.row_echelon(1.0e-10);
+    assert_eq!(pivots, vec![0, 2]);
+    assert_relative_eq!(echelon.rows(2, 2), expected.rows(2, 2), epsilon = 1.0e-10);
+    assert_relative_eq!(echelon.column(0).rows(1, 3), expected.column(0).rows(1, 3));
+}
+
+#[test]
+#[rustfmt::skip]
+fn reduced_row_echelon_rectangular() {
+    let wide = Matrix2x3::new(1.0, 2.0, 3.0,
+                              4.0, 5.0, 6.0);
+    let (rref, pivots) = wide.reduced_row_echelon(1.0e-10);
+    assert_eq!(pivots, vec![0, 1]);
+    assert_relative_eq!(rref, Matrix2x3::new(1.0, 0.0, -1.0,
+                                             0.0, 1.0,  2.0), epsilon = 1.0e-10);
+
+    let tall = Matrix3x2::new(1.0, 2.0,
+                              2.0, 4.0,
+                              3.0, 6.0);
+    let (rref, pivots) = tall.reduced_row_echelon(1.0e-10);
+    assert_eq!(pivots, vec![0]);
+    assert_relative_eq!(rref, Matrix3x2::new(1.0, 2.0,
+                                             0.0, 0.0,
+                                             0.0, 0.0), epsilon = 1.0e-10);
+}
+
+#[test]
+fn row_echelon_tolerance() {
+    let m = Matrix3::new(1.0, 0.0, 0.0, 0.0, 1.0e-12, 0.0, 0.0, 0.0, 1.0);
+
+    let (_, pivots) = m.row_echelon(1.0e-10);
+    assert_eq!(pivots, vec![0, 2]);
+
+    let (rref, pivots) = m.reduced_row_echelon(1.0e-15);
+    assert_eq!(pivots, vec![0, 1, 2]);
+    assert_eq!(rref, Matrix3::identity());
+}
+
+#[test]
+fn row_echelon_zero_and_empty() {
+    let (echelon, pivots) = DMatrix::<f64>::zeros(2, 3).row_echelon(1.0e-10);
+    assert!(pivots.is_empty());
+    assert_eq!(echelon, DMatrix::zeros(2, 3));
+
+    let (rref, pivots) = DMatrix::<f64>::zeros(0, 3).reduced_row_echelon(1.0e-10);
+    assert!(pivots.is_empty());
+    assert_eq!(rref.shape(), (0, 3));
+}
+
+#[cfg(feature = "proptest-support")]
+mod proptest_tests {
+    use crate::proptest::*;
+    use proptest::{prop_assert, prop_assert_eq, proptest};
+
+    proptest! {
+        #[test]
+        fn row_echelon_forms(m in dmatrix()) {
+            let (echelon, pivots) = m.row_echelon(1.0e-7);
+            let (rref, rref_pivots) = m.reduced_row_echelon(1.0e-7);
+
+            prop_assert_eq!(&pivots, &rref_pivots);
+            prop_assert!(pivots.windows(2).all(|w| w[0] < w[1]));
+
+            for (i, j) in pivots.iter().copied().enumerate() {
+                prop_assert!(echelon[(i, j)] != 0.0);
+                prop_assert!(echelon.slice_range(i + 1.., ..j + 1).iter().all(|e| *e == 0.0));
+                prop_assert!(rref.slice_range(.., ..j).rows_range(i..).iter().all(|e| *e == 0.0));
+                prop_assert_eq!(rref[(i, j)], 1.0);
+                prop_assert!(rref.column(j).iter().enumerate().all(|(k, e)| k == i || *e == 0.0));
+            }
+
+            prop_assert!(echelon.rows_range(pivots.len()..).iter().all(|e| *e == 0.0));
+            prop_assert!(rref.rows_range(pivots.len()..).iter().all(|e| *e == 0.0));
+        }
+    }
+}
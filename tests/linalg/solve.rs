@@ -7,7 +7,7 @@ macro_rules! gen_tests(
             #[allow(unused_imports)]
             use crate::core::helper::{RandScalar, RandComplex};
             use crate::proptest::*;
-            use proptest::{prop_assert, proptest};
+            use proptest::{prop_assert, prop_assert_eq, proptest};
 
             fn unzero_diagonal<T: ComplexField>(a: &mut Matrix4<T>) {
                 for i in 0..4 {
@@ -57,6 +57,35 @@ macro_rules! gen_tests(
 
                     prop_assert!(relative_eq!(tri.transpose() * x, b, epsilon = 1.0e-7))
                 }
+
+                #[test]
+                fn solve_lower_triangular_with_diag(a in matrix4_($scalar), b in matrix4x5_($scalar)) {
+                    let mut tri = a.lower_triangle();
+                    tri.fill_diagonal(na::convert(2.0));
+                    let x = a.solve_lower_triangular_with_diag(&b, na::convert(2.0)).unwrap();
+
+                    prop_assert!(relative_eq!(tri * x, b, epsilon = 1.0e-7))
+                }
+
+                #[test]
+                fn solve_upper_triangular_with_diag(a in matrix4_($scalar), b in matrix4x5_($scalar)) {
+                    let mut tri = a.upper_triangle();
+                    tri.fill_diagonal(na::one());
+                    let x = a.solve_upper_triangular_with_diag(&b, na::one()).unwrap();
+
+                    prop_assert!(relative_eq!(tri * x, b, epsilon = 1.0e-7))
+                }
+
+                #[test]
+                fn solve_triangular_with_zero_diag(a in matrix4_($scalar), b in matrix4x5_($scalar)) {
+                    let mut x = b.clone();
+
+                    prop_assert!(a.solve_lower_triangular_with_diag(&b, na::zero()).is_none());
+                    prop_assert!(a.solve_upper_triangular_with_diag(&b, na::zero()).is_none());
+                    prop_assert!(!a.solve_lower_triangular_with_diag_mut(&mut x, na::zero()));
+                    prop_assert!(!a.solve_upper_triangular_with_diag_mut(&mut x, na::zero()));
+                    prop_assert_eq!(x, b);
+                }
             }
         }
     }
@@ -154,6 +154,21 @@ mod proptest_tests {
                         }
                     }
 
+                    #[test]
+                    fn svd_solve_min_norm(m in matrix3x5_($scalar), x0 in vector5_($scalar)) {
+                        // Any `x0` is a particular solution of this underdetermined system.
+                        let b = &m * &x0;
+
+                        if m.rank(1.0e-7) == 3 {
+                            let x = m.solve_min_norm(&b, 1.0e-7).unwrap();
+                            let pinv = m.clone().pseudo_inverse(1.0e-7).unwrap();
+
+                            prop_assert!(relative_eq!(&m * &x, b, epsilon = 1.0e-6));
+                            prop_assert!(x.norm() <= x0.norm() + 1.0e-7);
+                            prop_assert!(relative_eq!(x, pinv * b, epsilon = 1.0e-6));
+                        }
+                    }
+
                     #[test]
                     fn svd_polar_decomposition(m in dmatrix_($scalar)) {
                         let svd = m.clone().svd_unordered(true, true);
@@ -324,6 +339,39 @@ fn svd_identity() {
     assert_eq!(Ok(m), svd.recompose());
 }
 
+#[test]
+fn svd_complex() {
+    use na::Complex;
+
+    // Tall, wide, and square complex matrices.
+    for (nrows, ncols) in [(6, 4), (4, 6), (5, 5)] {
+        let m = DMatrix::from_fn(nrows, ncols, |i, j| {
+            Complex::new(
+                (i * ncols + j) as f64 * 0.7 - 3.0,
+                ((i + 2 * j) % 5) as f64 - 1.5,
+            )
+        });
+        let svd = m.clone().svd(true, true);
+        let (u, s, v_t) = (svd.u.unwrap(), svd.singular_values, svd.v_t.unwrap());
+        let ds = DMatrix::from_diagonal(&s.map(Complex::from));
+        let dim = nrows.min(ncols);
+
+        assert!(s.iter().all(|e| *e >= 0.0));
+        assert!(is_sorted_descending(s.as_slice()));
+        assert_relative_eq!(
+            u.adjoint() * &u,
+            DMatrix::identity(dim, dim),
+            epsilon = 1.0e-10
+        );
+        assert_relative_eq!(
+            &v_t * v_t.adjoint(),
+            DMatrix::identity(dim, dim),
+            epsilon = 1.0e-10
+        );
+        assert_relative_eq!(m, &u * ds * &v_t, epsilon = 1.0e-10);
+    }
+}
+
 #[test]
 #[rustfmt::skip]
 fn svd_with_delimited_subproblem() {
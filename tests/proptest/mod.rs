@@ -6,9 +6,10 @@ use nalgebra::allocator::Allocator;
 use nalgebra::base::dimension::*;
 use nalgebra::proptest::{DimRange, MatrixStrategy};
 use nalgebra::{
-    DMatrix, DVector, DefaultAllocator, Dim, DualQuaternion, Isometry2, Isometry3, Matrix3,
-    OMatrix, Point2, Point3, Quaternion, Rotation2, Rotation3, Scalar, Similarity3, Translation2,
-    Translation3, UnitComplex, UnitDualQuaternion, UnitQuaternion, Vector3, U3, U4,
+    Cholesky, DMatrix, DVector, DefaultAllocator, Dim, DualQuaternion, Isometry2, Isometry3,
+    Matrix3, Matrix4, OMatrix, Point2, Point3, Quaternion, Rotation2, Rotation3, Scalar,
+    Similarity3, Translation2, Translation3, UnitComplex, UnitDualQuaternion, UnitQuaternion,
+    Vector3, U3, U4,
 };
 use num_complex::Complex;
 use proptest::prelude::*;
@@ -19,7 +20,7 @@ use std::ops::RangeInclusive;
 pub const PROPTEST_MATRIX_DIM: RangeInclusive<usize> = 1..=20;
 pub const PROPTEST_F64: RangeInclusive<f64> = -100.0..=100.0;
 
-pub use nalgebra::proptest::{matrix, vector};
+pub use nalgebra::proptest::{invertible_matrix, matrix, positive_definite, vector};
 
 pub fn point2() -> impl Strategy<Value = Point2<f64>> {
     vector2().prop_map(|v| Point2::from(v))
@@ -295,6 +296,69 @@ fn matrix_shrinking_satisfies_constraints() {
     maybeprintln!("========================== (end of generation process)");
 }
 
+proptest! {
+    #[test]
+    fn invertible_matrix_is_well_conditioned(m in invertible_matrix(1..=8, 0.1..=10.0)) {
+        let m: DMatrix<f64> = m;
+        prop_assert!(m.is_square());
+        prop_assert!(m.nrows() >= 1 && m.nrows() <= 8);
+
+        let singular_values = m.singular_values();
+        let condition_number = singular_values.max() / singular_values.min();
+        prop_assert!(condition_number <= 100.0 + 1.0e-7);
+
+        let inv = m.clone().try_inverse().expect("Generated matrix must be invertible.");
+        prop_assert!(inv.iter().all(|x| x.is_finite()));
+        prop_assert!((&m * &inv).is_identity(1.0e-10));
+        prop_assert!((&inv * &m).is_identity(1.0e-10));
+    }
+
+    #[test]
+    fn positive_definite_has_cholesky(m in positive_definite(1..=8, -2.0..=2.0)) {
+        let m: DMatrix<f64> = m;
+        prop_assert!(m.is_square());
+        prop_assert!(m.nrows() <= 8);
+        prop_assert_eq!(&m, &m.transpose());
+
+        let eigenvalues = m.clone().symmetric_eigenvalues();
+        prop_assert!(eigenvalues.iter().all(|e| *e >= 1.0 - 1.0e-10));
+        prop_assert!(eigenvalues.iter().all(|e| *e <= 1.0 + 36.0 * 4.0 + 1.0e-10));
+
+        let chol = Cholesky::new(m.clone()).expect("Generated matrix must be positive-definite.");
+        prop_assert!(relative_eq!(chol.l() * chol.l().transpose(), m, epsilon = 1.0e-10));
+    }
+
+    #[test]
+    fn positive_definite_static_has_cholesky(m in positive_definite(Const::<4>, -10.0..=10.0)) {
+        let m: Matrix4<f32> = m;
+        prop_assert!(m.cholesky().is_some());
+    }
+
+    #[test]
+    fn invertible_matrix_static_is_invertible(m in invertible_matrix(Const::<3>, 0.5..=2.0)) {
+        let m: Matrix3<f32> = m;
+        let inv = m.try_inverse().expect("Generated matrix must be invertible.");
+        prop_assert!((m * inv).is_identity(1.0e-4));
+    }
+}
+
+#[test]
+fn invertible_matrix_shrinks_towards_identity() {
+    let mut runner = TestRunner::deterministic();
+    let strategy = invertible_matrix(3..=3, 0.1..=10.0);
+
+    for _ in 0..10 {
+        let mut tree = strategy
+            .new_tree(&mut runner)
+            .expect("Tree generation should not fail.");
+
+        while tree.simplify() {}
+
+        let matrix: DMatrix<f64> = tree.current();
+        assert!(matrix.is_identity(1.0e-12));
+    }
+}
+
 #[cfg(feature = "slow-tests")]
 mod slow {
     use super::*;